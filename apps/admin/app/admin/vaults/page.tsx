@@ -41,7 +41,7 @@ export default function VaultsPage() {
 
     for (const vault of vaults) {
       try {
-        const price = await client.getOraclePrice(vault.account.oraclePriceAccount);
+        const price = await client.getOraclePrice(vault.publicKey);
         if (price !== null) {
           newPrices[vault.publicKey.toString()] = price;
         }
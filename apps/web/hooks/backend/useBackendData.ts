@@ -241,7 +241,7 @@ export function useBackendOracleData(refetchInterval?: number): {
 
     try {
       const vaultTypes = await client.fetchAllVaultTypes();
-      const solVaultType = (vaultTypes as Array<{ publicKey: PublicKey; account: { collateralMint: PublicKey; oraclePriceAccount: PublicKey } }>).find(
+      const solVaultType = (vaultTypes as Array<{ publicKey: PublicKey; account: { collateralMint: PublicKey } }>).find(
         (vt) => vt.account.collateralMint.toString() === SOL_MINT.toString()
       );
 
@@ -249,7 +249,6 @@ export function useBackendOracleData(refetchInterval?: number): {
 
       return {
         vaultType: solVaultType.publicKey,
-        oraclePubkey: solVaultType.account.oraclePriceAccount,
       };
     } catch (error) {
       console.error("Error fetching vault types:", error);
@@ -274,7 +273,7 @@ export function useBackendOracleData(refetchInterval?: number): {
         return;
       }
 
-      const priceUSD = await client.getOraclePrice(oracleInfo.oraclePubkey);
+      const priceUSD = await client.getOraclePrice(oracleInfo.vaultType);
 
       if (priceUSD === null) {
         // Fallback to CoinGecko for SOL if on-chain fails
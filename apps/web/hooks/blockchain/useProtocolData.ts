@@ -58,7 +58,7 @@ export function useProtocolData() {
                     // Fetch current price for this vault
                     let price = null;
                     try {
-                        price = await client.getOraclePrice(account.oraclePriceAccount);
+                        price = await client.getOraclePrice(vt.publicKey);
                     } catch (e) {
                         console.error("Failed to fetch on-chain price", e);
                     }
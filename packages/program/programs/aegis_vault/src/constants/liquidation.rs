@@ -0,0 +1,24 @@
+// Liquidation modes
+pub const LIQUIDATION_MODE_FIXED_DISCOUNT: u8 = 0; // liquidate_position seizes collateral at a fixed penalty
+pub const LIQUIDATION_MODE_AUCTION: u8 = 1; // seized collateral is sold in a Dutch auction
+
+// Health-scaled liquidation bonus
+pub const MAX_LIQUIDATION_BONUS_BPS: u16 = 5_000; // 50% of the repaid debt
+
+// Dutch auction limits
+pub const MAX_AUCTION_START_PREMIUM_BPS: u16 = 20_000; // 200% of the oracle price
+pub const MAX_AUCTION_DURATION_SECONDS: u64 = 86_400; // 1 day
+
+// Stability pool (Liquity product/sum accounting)
+pub const STABILITY_POOL_PRECISION: u128 = 1_000_000_000_000_000_000; // 1e18 = 1.0
+pub const STABILITY_POOL_SCALE_FACTOR: u128 = 1_000_000_000; // P is rescaled by 1e9 when it drops below this
+pub const STABILITY_POOL_SUM_CAPACITY: usize = 32; // (epoch, scale) sums kept for depositor gains
+
+// Redistribution (Liquity L_coll / L_debt)
+pub const REDISTRIBUTION_PRECISION: u128 = 1_000_000_000_000_000_000; // 1e18 = 1.0 per unit of stake
+
+// Batch liquidation
+pub const MAX_BATCH_LIQUIDATIONS: usize = 16; // keeps the per-position results within the 1024-byte return data
+pub const BATCH_LIQUIDATION_LIQUIDATED: u8 = 0;
+pub const BATCH_LIQUIDATION_HEALTHY: u8 = 1; // not liquidatable, or no debt
+pub const BATCH_LIQUIDATION_SKIPPED: u8 = 2; // another vault type's position, or nothing left to repay with
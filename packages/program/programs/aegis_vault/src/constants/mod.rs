@@ -1,7 +1,11 @@
 pub mod protocol;
 pub mod roles;
 pub mod seeds;
+pub mod oracle;
+pub mod liquidation;
 
 pub use protocol::*;
 pub use roles::*;
 pub use seeds::*;
+pub use oracle::*;
+pub use liquidation::*;
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+// Primary price source of a vault type
+pub const ORACLE_SOURCE_PYTH: u8 = 0;
+pub const ORACLE_SOURCE_PUSH_FEED: u8 = 1;
+pub const ORACLE_SOURCE_STAKE_POOL: u8 = 2; // SPL stake pool exchange rate x Pyth SOL/USD
+pub const ORACLE_SOURCE_SWITCHBOARD: u8 = 3; // Secondary feed only, reported when a fallback is used
+pub const ORACLE_SOURCE_SIGNED_ATTESTATION: u8 = 4; // Ed25519-signed messages from the attestation signer set
+pub const ORACLE_SOURCE_GUARDIAN_OVERRIDE: u8 = 5; // Reported while a guardian override is active
+
+// Which price each side of the vault uses
+// - SPOT: spot price everywhere
+// - EMA_LIQUIDATION: spot for mint/withdraw limits, EMA for liquidation eligibility
+// - CONSERVATIVE: lower of spot/EMA for mint/withdraw, higher of spot/EMA for liquidation eligibility
+pub const PRICING_MODE_SPOT: u8 = 0;
+pub const PRICING_MODE_EMA_LIQUIDATION: u8 = 1;
+pub const PRICING_MODE_CONSERVATIVE: u8 = 2;
+
+// Pyth (Wormhole) verification level required for price updates
+pub const ORACLE_VERIFICATION_FULL: u8 = 0;
+pub const ORACLE_VERIFICATION_PARTIAL: u8 = 1;
+
+// Oracle prices are normalized to USD with 6 decimals (same as the stablecoin)
+pub const PRICE_DECIMALS: i32 = 6;
+
+// Pegged collateral (USDC/USDT) is never valued above $1.00
+pub const PEGGED_PRICE_CAP: u64 = 1_000_000;
+
+// Price aggregation policy between the primary and secondary oracle
+pub const ORACLE_AGGREGATION_PRIMARY_ONLY: u8 = 0;
+pub const ORACLE_AGGREGATION_FALLBACK: u8 = 1;
+pub const ORACLE_AGGREGATION_MEAN: u8 = 2;
+
+// Switchboard On-Demand pull feed (PullFeedAccountData, repr(C) after the discriminator)
+pub const SWITCHBOARD_ON_DEMAND_PROGRAM_ID: Pubkey =
+    pubkey!("SBondMDrcV3K4kxZR1HNVT7osZxAHVHgYXL5Ze1oMUv");
+pub const SWITCHBOARD_PULL_FEED_DISCRIMINATOR: [u8; 8] = [196, 27, 108, 196, 10, 215, 219, 40];
+pub const SWITCHBOARD_PULL_FEED_LEN: usize = 3208;
+pub const SWITCHBOARD_LAST_UPDATE_OFFSET: usize = 2216; // last_update_timestamp: i64
+pub const SWITCHBOARD_RESULT_VALUE_OFFSET: usize = 2264; // result.value: i128
+pub const SWITCHBOARD_RESULT_STD_DEV_OFFSET: usize = 2280; // result.std_dev: i128
+pub const SWITCHBOARD_DECIMALS: u32 = 18;
+
+// SPL stake pool (StakePool, borsh) used by the composite LST price source
+pub const SPL_STAKE_POOL_PROGRAM_ID: Pubkey =
+    pubkey!("SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy");
+pub const STAKE_POOL_ACCOUNT_TYPE: u8 = 1; // AccountType::StakePool
+pub const STAKE_POOL_POOL_MINT_OFFSET: usize = 162; // pool_mint: Pubkey
+pub const STAKE_POOL_TOTAL_LAMPORTS_OFFSET: usize = 258; // total_lamports: u64
+pub const STAKE_POOL_POOL_TOKEN_SUPPLY_OFFSET: usize = 266; // pool_token_supply: u64
+pub const STAKE_POOL_LAST_UPDATE_EPOCH_OFFSET: usize = 274; // last_update_epoch: u64
+
+// On-chain price history used for the internal TWAP
+pub const PRICE_HISTORY_CAPACITY: usize = 48;
+pub const MAX_TWAP_WINDOW_SECONDS: u64 = 86_400;
+
+// Ed25519-signed price attestations: prefix || feed_id || price u64 || conf u64 || timestamp i64 (LE),
+// with price and conf in USD (6 decimals)
+pub const MAX_ATTESTATION_SIGNERS: usize = 5;
+pub const ATTESTATION_MESSAGE_PREFIX: [u8; 8] = *b"aegis-px";
+pub const ATTESTATION_MESSAGE_LEN: usize = 64;
+
+// Guardian emergency price override
+pub const MAX_PRICE_OVERRIDE_SECONDS: i64 = 21_600; // 6 hours
+pub const MAX_PRICE_OVERRIDE_DEVIATION_BPS: u64 = 2_000; // 20% from the last good price
+pub const PRICE_OVERRIDE_LOG_CAPACITY: usize = 16;
@@ -11,12 +11,19 @@ pub const DEFAULT_ORACLE_TTL_SECONDS: i64 = 3600; // 1 hour
 pub const DEFAULT_GLOBAL_DEBT_CEILING: u64 = 1_000_000_000_000; // 1 trillion (6 decimals = 1M actual)
 pub const DEFAULT_VAULT_DEBT_CEILING: u64 = 10_000_000_000; // 10 billion (6 decimals = 10K actual)
 
+// Surplus buffer
+pub const DEFAULT_SURPLUS_BUFFER_CAP: u64 = 0; // fees go straight to the treasury once any deficit is covered
+
 // Validation limits
 pub const MAX_FEE_BPS: u16 = 10000; // 100%
 pub const MAX_COLLATERAL_RATIO_BPS: u64 = 50000; // 500%
 pub const MAX_LIQUIDATION_THRESHOLD_BPS: u64 = 50000; // 500%
 pub const MAX_LIQUIDATION_PENALTY_BPS: u64 = 10000; // 100%
 
+// Stability fee accrual
+pub const RATE_PRECISION: u128 = 1_000_000_000_000_000_000; // 1e18 = 1.0
+pub const SECONDS_PER_YEAR: u64 = 31_536_000; // 365 days
+
 // Protocol version
 pub const INITIAL_CONFIG_VERSION: u64 = 1;
 
@@ -2,3 +2,10 @@
 pub const PROTOCOL_STATE_SEED: &[u8] = b"protocol_state";
 pub const VAULT_TYPE_SEED: &[u8] = b"vault-type";
 pub const POSITION_SEED: &[u8] = b"position";
+pub const PRICE_FEED_SEED: &[u8] = b"price-feed";
+pub const PRICE_HISTORY_SEED: &[u8] = b"price-history";
+pub const ATTESTATION_CONFIG_SEED: &[u8] = b"attestation-config";
+pub const PRICE_OVERRIDE_LOG_SEED: &[u8] = b"price-override-log";
+pub const LIQUIDATION_AUCTION_SEED: &[u8] = b"liquidation-auction";
+pub const STABILITY_POOL_SEED: &[u8] = b"stability-pool";
+pub const STABILITY_DEPOSIT_SEED: &[u8] = b"stability-deposit";
@@ -12,7 +12,7 @@ pub enum AegisError {
     ProtocolPaused,
     #[msg("Protocol is shutdown.")]
     ProtocolShutdown,
-    #[msg("Oracle price is stale or invalid.")]
+    #[msg("Oracle price is stale.")]
     OracleStale,
     
     // Position-related errors
@@ -34,5 +34,108 @@ pub enum AegisError {
     MintPaused,
     #[msg("Redemption is currently paused.")]
     RedeemPaused,
-}
 
+    // Oracle errors
+    #[msg("Invalid oracle configuration.")]
+    InvalidOracleConfig,
+    #[msg("Oracle account does not match the vault type.")]
+    InvalidOracleAccount,
+    #[msg("Oracle confidence interval is too wide.")]
+    OracleConfidenceTooWide,
+
+    // Vault configuration errors
+    #[msg("Collateral decimals do not match the collateral mint.")]
+    InvalidCollateralDecimals,
+
+    // Oracle aggregation errors
+    #[msg("Primary and secondary oracle prices deviate too much.")]
+    OracleDeviationTooHigh,
+
+    // Push price feed errors
+    #[msg("Pushed price moves more than the feed allows.")]
+    PriceMoveTooLarge,
+    #[msg("Pushed price publish time is invalid.")]
+    InvalidPublishTime,
+
+    // Circuit breaker errors
+    #[msg("Price moved beyond the circuit breaker limit.")]
+    CircuitBreakerTripped,
+    #[msg("Minting is paused for this vault type.")]
+    VaultMintPaused,
+
+    // Price band errors
+    #[msg("Oracle price is outside the vault's price band.")]
+    OraclePriceOutOfBand,
+
+    // Oracle validation errors
+    #[msg("Oracle account does not carry the vault type's price feed.")]
+    OracleFeedMismatch,
+    #[msg("Oracle price is zero or negative.")]
+    OracleNegativePrice,
+    #[msg("Oracle price update is not sufficiently verified.")]
+    OracleVerificationTooLow,
+
+    // Price history errors
+    #[msg("Price history does not cover the TWAP window.")]
+    PriceHistoryInsufficient,
+    #[msg("Price observation is too close to the previous one.")]
+    PriceObservationTooSoon,
+
+    // Price attestation errors
+    #[msg("Invalid attestation signer set.")]
+    InvalidAttestationSigners,
+    #[msg("Not enough valid price attestations in the transaction.")]
+    AttestationThresholdNotMet,
+    #[msg("Attestation is older than one already consumed from its signer.")]
+    AttestationReplayed,
+
+    // Price override errors
+    #[msg("Override price is outside the bounds of the last good price.")]
+    PriceOverrideOutOfBounds,
+    #[msg("Override duration is invalid.")]
+    InvalidOverrideDuration,
+
+    // Liquidation errors
+    #[msg("Invalid liquidation configuration.")]
+    InvalidLiquidationConfig,
+    #[msg("Liquidation is not available in the vault type's liquidation mode.")]
+    LiquidationModeMismatch,
+    #[msg("Auction price is above the bid limit.")]
+    AuctionPriceTooHigh,
+    #[msg("Auction is still running and its collateral can still be sold.")]
+    AuctionStillRunning,
+
+    // Stability pool errors
+    #[msg("Stability pool has no deposits to absorb the liquidation.")]
+    StabilityPoolEmpty,
+    #[msg("Stability pool epoch and scale history is full.")]
+    StabilityPoolHistoryFull,
+
+    // Bad debt errors
+    #[msg("Position still has collateral or no debt to write off.")]
+    PositionNotInsolvent,
+
+    // Flash liquidation errors
+    #[msg("Callback program is not an executable program other than Aegis.")]
+    InvalidCallbackProgram,
+    #[msg("Flash liquidation callback did not return enough AGSUSD to repay the debt.")]
+    FlashLiquidationNotRepaid,
+
+    // Batch liquidation errors
+    #[msg("Too many positions in one liquidation batch.")]
+    BatchTooLarge,
+    #[msg("Position appears more than once in the liquidation batch.")]
+    DuplicateBatchPosition,
+
+    // Redistribution errors
+    #[msg("Position is not under water, liquidate it instead.")]
+    PositionNotUnderwater,
+    #[msg("No other positions to redistribute to.")]
+    NoStakesToRedistribute,
+    #[msg("Stability pool can absorb the position, liquidate it through the pool instead.")]
+    StabilityPoolCanAbsorb,
+
+    // Migration errors
+    #[msg("Account already uses the current layout.")]
+    AccountAlreadyMigrated,
+}
@@ -10,6 +10,7 @@ pub mod set_default_vault_debt_ceiling;
 pub mod set_oracle_ttl_seconds;
 pub mod update_oracle_authority;
 pub mod set_stablecoin_mint;
+pub mod set_surplus_buffer_cap;
 
 pub use set_mint_fee_bps::*;
 pub use set_redeem_fee_bps::*;
@@ -23,3 +24,4 @@ pub use set_default_vault_debt_ceiling::*;
 pub use set_oracle_ttl_seconds::*;
 pub use update_oracle_authority::*;
 pub use set_stablecoin_mint::*;
+pub use set_surplus_buffer_cap::*;
@@ -16,7 +16,7 @@ pub struct SetCollateralRatioBps<'info> {
     pub governance_pubkey: Signer<'info>,
 }
 
-pub fn handler(ctx: Context<SetCollateralRatioBps>, new_collateral_ratio_bps: u64) -> Result<()> {
+pub(crate) fn handler(ctx: Context<SetCollateralRatioBps>, new_collateral_ratio_bps: u64) -> Result<()> {
     require!(new_collateral_ratio_bps <= 50000, AegisError::InvalidFee); // Max 500%
 
     let protocol_state = &mut ctx.accounts.protocol_state;
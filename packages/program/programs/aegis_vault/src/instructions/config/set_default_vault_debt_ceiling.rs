@@ -16,7 +16,7 @@ pub struct SetDefaultVaultDebtCeiling<'info> {
     pub governance_pubkey: Signer<'info>,
 }
 
-pub fn handler(ctx: Context<SetDefaultVaultDebtCeiling>, new_default_vault_debt_ceiling: u64) -> Result<()> {
+pub(crate) fn handler(ctx: Context<SetDefaultVaultDebtCeiling>, new_default_vault_debt_ceiling: u64) -> Result<()> {
     let protocol_state = &mut ctx.accounts.protocol_state;
     let clock = Clock::get()?;
 
@@ -16,7 +16,7 @@ pub struct SetGlobalDebtCeiling<'info> {
     pub governance_pubkey: Signer<'info>,
 }
 
-pub fn handler(ctx: Context<SetGlobalDebtCeiling>, new_global_debt_ceiling: u64) -> Result<()> {
+pub(crate) fn handler(ctx: Context<SetGlobalDebtCeiling>, new_global_debt_ceiling: u64) -> Result<()> {
     let protocol_state = &mut ctx.accounts.protocol_state;
     let clock = Clock::get()?;
 
@@ -16,7 +16,7 @@ pub struct SetLiquidationPenaltyBps<'info> {
     pub governance_pubkey: Signer<'info>,
 }
 
-pub fn handler(ctx: Context<SetLiquidationPenaltyBps>, new_liquidation_penalty_bps: u64) -> Result<()> {
+pub(crate) fn handler(ctx: Context<SetLiquidationPenaltyBps>, new_liquidation_penalty_bps: u64) -> Result<()> {
     require!(new_liquidation_penalty_bps <= 10000, AegisError::InvalidFee);
 
     let protocol_state = &mut ctx.accounts.protocol_state;
@@ -16,7 +16,7 @@ pub struct SetMintFeeBps<'info> {
     pub governance_pubkey: Signer<'info>,
 }
 
-pub fn handler(ctx: Context<SetMintFeeBps>, new_mint_fee_bps: u16) -> Result<()> {
+pub(crate) fn handler(ctx: Context<SetMintFeeBps>, new_mint_fee_bps: u16) -> Result<()> {
     require!(new_mint_fee_bps <= MAX_FEE_BPS, AegisError::InvalidFee);
 
     let protocol_state = &mut ctx.accounts.protocol_state;
@@ -16,7 +16,7 @@ pub struct SetOracleTtlSeconds<'info> {
     pub governance_pubkey: Signer<'info>,
 }
 
-pub fn handler(ctx: Context<SetOracleTtlSeconds>, new_ttl_seconds: i64) -> Result<()> {
+pub(crate) fn handler(ctx: Context<SetOracleTtlSeconds>, new_ttl_seconds: i64) -> Result<()> {
     let protocol_state = &mut ctx.accounts.protocol_state;
     protocol_state.oracle_ttl_seconds = new_ttl_seconds;
     
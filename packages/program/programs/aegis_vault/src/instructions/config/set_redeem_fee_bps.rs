@@ -16,7 +16,7 @@ pub struct SetRedeemFeeBps<'info> {
     pub governance_pubkey: Signer<'info>,
 }
 
-pub fn handler(ctx: Context<SetRedeemFeeBps>, new_redeem_fee_bps: u16) -> Result<()> {
+pub(crate) fn handler(ctx: Context<SetRedeemFeeBps>, new_redeem_fee_bps: u16) -> Result<()> {
     require!(new_redeem_fee_bps <= 10000, AegisError::InvalidFee);
 
     let protocol_state = &mut ctx.accounts.protocol_state;
@@ -16,7 +16,7 @@ pub struct SetStabilityFeeBps<'info> {
     pub governance_pubkey: Signer<'info>,
 }
 
-pub fn handler(ctx: Context<SetStabilityFeeBps>, new_stability_fee_bps: u16) -> Result<()> {
+pub(crate) fn handler(ctx: Context<SetStabilityFeeBps>, new_stability_fee_bps: u16) -> Result<()> {
     require!(new_stability_fee_bps <= 10000, AegisError::InvalidFee);
 
     let protocol_state = &mut ctx.accounts.protocol_state;
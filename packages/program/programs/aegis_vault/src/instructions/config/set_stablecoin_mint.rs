@@ -16,7 +16,7 @@ pub struct SetStablecoinMint<'info> {
     pub admin_pubkey: Signer<'info>,
 }
 
-pub fn handler(
+pub(crate) fn handler(
     ctx: Context<SetStablecoinMint>,
     stablecoin_mint: Pubkey,
 ) -> Result<()> {
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+use crate::state::ProtocolState;
+use crate::errors::AegisError;
+use crate::constants::*;
+
+#[derive(Accounts)]
+pub struct SetSurplusBufferCap<'info> {
+    #[account(
+        mut,
+        seeds = [PROTOCOL_STATE_SEED],
+        bump,
+        has_one = governance_pubkey @ AegisError::Unauthorized
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    pub governance_pubkey: Signer<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<SetSurplusBufferCap>, new_cap: u64) -> Result<()> {
+    let protocol_state = &mut ctx.accounts.protocol_state;
+    let clock = Clock::get()?;
+
+    protocol_state.surplus_buffer_cap = new_cap;
+    protocol_state.updated_at = clock.unix_timestamp;
+
+    msg!("Surplus buffer cap updated to: {}", new_cap);
+
+    Ok(())
+}
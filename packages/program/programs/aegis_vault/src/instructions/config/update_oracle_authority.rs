@@ -16,7 +16,7 @@ pub struct UpdateOracleAuthority<'info> {
     pub admin_pubkey: Signer<'info>,
 }
 
-pub fn handler(ctx: Context<UpdateOracleAuthority>, new_authority: Pubkey) -> Result<()> {
+pub(crate) fn handler(ctx: Context<UpdateOracleAuthority>, new_authority: Pubkey) -> Result<()> {
     let protocol_state = &mut ctx.accounts.protocol_state;
     protocol_state.oracle_update_authority = new_authority;
     
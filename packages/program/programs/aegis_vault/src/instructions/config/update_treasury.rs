@@ -16,7 +16,7 @@ pub struct UpdateTreasury<'info> {
     pub governance_pubkey: Signer<'info>,
 }
 
-pub fn handler(ctx: Context<UpdateTreasury>, new_treasury: Pubkey) -> Result<()> {
+pub(crate) fn handler(ctx: Context<UpdateTreasury>, new_treasury: Pubkey) -> Result<()> {
     let protocol_state = &mut ctx.accounts.protocol_state;
     let clock = Clock::get()?;
 
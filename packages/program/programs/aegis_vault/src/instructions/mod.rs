@@ -2,8 +2,12 @@ pub mod protocol;
 pub mod config;
 pub mod vault;
 pub mod position;
+pub mod oracle;
+pub mod stability_pool;
 
 pub use protocol::*;
 pub use config::*;
 pub use vault::*;
 pub use position::*;
+pub use oracle::*;
+pub use stability_pool::*;
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::state::{PriceOverrideEntry, PriceOverrideLog, ProtocolState, VaultType};
+use crate::constants::seeds::{PRICE_OVERRIDE_LOG_SEED, PROTOCOL_STATE_SEED, VAULT_TYPE_SEED};
+use crate::errors::codes::AegisError;
+use crate::utils::price_override::log_price_override;
+
+#[derive(Accounts)]
+pub struct ClearPriceOverride<'info> {
+    #[account(
+        mut,
+        seeds = [VAULT_TYPE_SEED, vault_type.collateral_mint.key().as_ref()],
+        bump
+    )]
+    pub vault_type: Account<'info, VaultType>,
+
+    #[account(
+        mut,
+        seeds = [PRICE_OVERRIDE_LOG_SEED, vault_type.key().as_ref()],
+        bump = price_override_log.bump
+    )]
+    pub price_override_log: Box<Account<'info, PriceOverrideLog>>,
+
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED],
+        bump,
+        has_one = guardian_pubkey @ AegisError::Unauthorized
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    pub guardian_pubkey: Signer<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<ClearPriceOverride>) -> Result<()> {
+    let vault_type = &mut ctx.accounts.vault_type;
+    let clock = Clock::get()?;
+
+    // Back to the normal oracle source
+    vault_type.override_price = 0;
+    vault_type.override_expires_at = clock.unix_timestamp;
+    vault_type.updated_at = clock.unix_timestamp;
+
+    log_price_override(
+        &mut ctx.accounts.price_override_log,
+        PriceOverrideEntry {
+            guardian: ctx.accounts.guardian_pubkey.key(),
+            price: 0,
+            reference_price: vault_type.last_accepted_price,
+            set_at: clock.unix_timestamp,
+            expires_at: clock.unix_timestamp,
+        },
+    );
+
+    msg!(
+        "PriceOverrideCleared: vault={} guardian={}",
+        vault_type.collateral_mint,
+        ctx.accounts.guardian_pubkey.key()
+    );
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::state::{AttestationConfig, ProtocolState, VaultType};
+use crate::constants::oracle::MAX_ATTESTATION_SIGNERS;
+use crate::constants::seeds::{ATTESTATION_CONFIG_SEED, PROTOCOL_STATE_SEED, VAULT_TYPE_SEED};
+use crate::utils::attestation::pack_attestation_signers;
+
+#[derive(Accounts)]
+pub struct InitializeAttestationConfig<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = AttestationConfig::LEN,
+        seeds = [ATTESTATION_CONFIG_SEED, vault_type.key().as_ref()],
+        bump
+    )]
+    pub attestation_config: Account<'info, AttestationConfig>,
+
+    #[account(
+        seeds = [VAULT_TYPE_SEED, vault_type.collateral_mint.key().as_ref()],
+        bump
+    )]
+    pub vault_type: Account<'info, VaultType>,
+
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED],
+        bump,
+        constraint = protocol_state.admin_pubkey == admin.key(),
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<InitializeAttestationConfig>,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    let attestation_config = &mut ctx.accounts.attestation_config;
+    let clock = Clock::get()?;
+
+    attestation_config.vault_type = ctx.accounts.vault_type.key();
+    attestation_config.signers = pack_attestation_signers(&signers, threshold)?;
+    attestation_config.signer_count = signers.len() as u8;
+    attestation_config.threshold = threshold;
+    attestation_config.bump = ctx.bumps.attestation_config;
+    attestation_config.created_at = clock.unix_timestamp;
+    attestation_config.updated_at = clock.unix_timestamp;
+    attestation_config.last_attestation_ts = [0; MAX_ATTESTATION_SIGNERS];
+
+    msg!(
+        "Attestation Config Initialized: vault={} signers={} threshold={}",
+        attestation_config.vault_type,
+        signers.len(),
+        threshold
+    );
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::state::{PriceFeed, ProtocolState, VaultType};
+use crate::constants::seeds::{PRICE_FEED_SEED, PROTOCOL_STATE_SEED, VAULT_TYPE_SEED};
+use crate::errors::codes::AegisError;
+
+#[derive(Accounts)]
+pub struct InitializePriceFeed<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = PriceFeed::LEN,
+        seeds = [PRICE_FEED_SEED, vault_type.key().as_ref()],
+        bump
+    )]
+    pub price_feed: Account<'info, PriceFeed>,
+
+    #[account(
+        seeds = [VAULT_TYPE_SEED, vault_type.collateral_mint.key().as_ref()],
+        bump
+    )]
+    pub vault_type: Account<'info, VaultType>,
+
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED],
+        bump,
+        constraint = protocol_state.admin_pubkey == admin.key(),
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<InitializePriceFeed>, max_move_bps: u16) -> Result<()> {
+    require!(max_move_bps <= 10_000, AegisError::InvalidOracleConfig);
+
+    let price_feed = &mut ctx.accounts.price_feed;
+    let clock = Clock::get()?;
+
+    // No price until the oracle authority pushes the first one
+    price_feed.vault_type = ctx.accounts.vault_type.key();
+    price_feed.price = 0;
+    price_feed.conf = 0;
+    price_feed.publish_time = 0;
+    price_feed.max_move_bps = max_move_bps;
+    price_feed.bump = ctx.bumps.price_feed;
+    price_feed.created_at = clock.unix_timestamp;
+    price_feed.updated_at = clock.unix_timestamp;
+
+    msg!("Price Feed Initialized: vault={} max_move_bps={}", price_feed.vault_type, max_move_bps);
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::state::{PriceHistory, PriceObservation, ProtocolState, VaultType};
+use crate::constants::oracle::PRICE_HISTORY_CAPACITY;
+use crate::constants::seeds::{PRICE_HISTORY_SEED, PROTOCOL_STATE_SEED, VAULT_TYPE_SEED};
+use crate::errors::codes::AegisError;
+
+#[derive(Accounts)]
+pub struct InitializePriceHistory<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = PriceHistory::LEN,
+        seeds = [PRICE_HISTORY_SEED, vault_type.key().as_ref()],
+        bump
+    )]
+    pub price_history: Box<Account<'info, PriceHistory>>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_TYPE_SEED, vault_type.collateral_mint.key().as_ref()],
+        bump
+    )]
+    pub vault_type: Account<'info, VaultType>,
+
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED],
+        bump,
+        constraint = protocol_state.admin_pubkey == admin.key(),
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<InitializePriceHistory>, min_interval_seconds: u32) -> Result<()> {
+    // A zero interval would let anyone flush the buffer within one slot
+    require!(min_interval_seconds > 0, AegisError::InvalidOracleConfig);
+
+    let price_history = &mut ctx.accounts.price_history;
+    let vault_type = &mut ctx.accounts.vault_type;
+    let clock = Clock::get()?;
+
+    price_history.vault_type = vault_type.key();
+    price_history.min_interval_seconds = min_interval_seconds;
+    price_history.head = 0;
+    price_history.len = 0;
+    price_history.observations = [PriceObservation::default(); PRICE_HISTORY_CAPACITY];
+    price_history.bump = ctx.bumps.price_history;
+    price_history.created_at = clock.unix_timestamp;
+
+    vault_type.price_history = price_history.key();
+    vault_type.updated_at = clock.unix_timestamp;
+
+    msg!(
+        "Price History Initialized: vault={} min_interval_seconds={}",
+        price_history.vault_type,
+        min_interval_seconds
+    );
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::state::{PriceOverrideEntry, PriceOverrideLog, ProtocolState, VaultType};
+use crate::constants::oracle::PRICE_OVERRIDE_LOG_CAPACITY;
+use crate::constants::seeds::{PRICE_OVERRIDE_LOG_SEED, PROTOCOL_STATE_SEED, VAULT_TYPE_SEED};
+
+#[derive(Accounts)]
+pub struct InitializePriceOverrideLog<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = PriceOverrideLog::LEN,
+        seeds = [PRICE_OVERRIDE_LOG_SEED, vault_type.key().as_ref()],
+        bump
+    )]
+    pub price_override_log: Box<Account<'info, PriceOverrideLog>>,
+
+    #[account(
+        seeds = [VAULT_TYPE_SEED, vault_type.collateral_mint.key().as_ref()],
+        bump
+    )]
+    pub vault_type: Account<'info, VaultType>,
+
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED],
+        bump,
+        constraint = protocol_state.admin_pubkey == admin.key(),
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<InitializePriceOverrideLog>) -> Result<()> {
+    let price_override_log = &mut ctx.accounts.price_override_log;
+
+    price_override_log.vault_type = ctx.accounts.vault_type.key();
+    price_override_log.head = 0;
+    price_override_log.len = 0;
+    price_override_log.entries = [PriceOverrideEntry::default(); PRICE_OVERRIDE_LOG_CAPACITY];
+    price_override_log.bump = ctx.bumps.price_override_log;
+
+    msg!("Price Override Log Initialized: vault={}", price_override_log.vault_type);
+    Ok(())
+}
@@ -0,0 +1,25 @@
+pub mod initialize_price_feed;
+pub mod push_price;
+pub mod set_price_feed_max_move;
+pub mod refresh_price;
+pub mod reset_circuit_breaker;
+pub mod initialize_price_history;
+pub mod record_price;
+pub mod initialize_attestation_config;
+pub mod set_attestation_signers;
+pub mod initialize_price_override_log;
+pub mod set_price_override;
+pub mod clear_price_override;
+
+pub use initialize_price_feed::*;
+pub use push_price::*;
+pub use set_price_feed_max_move::*;
+pub use refresh_price::*;
+pub use reset_circuit_breaker::*;
+pub use initialize_price_history::*;
+pub use record_price::*;
+pub use initialize_attestation_config::*;
+pub use set_attestation_signers::*;
+pub use initialize_price_override_log::*;
+pub use set_price_override::*;
+pub use clear_price_override::*;
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::state::{PriceFeed, ProtocolState};
+use crate::constants::seeds::{PRICE_FEED_SEED, PROTOCOL_STATE_SEED};
+use crate::errors::codes::AegisError;
+
+#[derive(Accounts)]
+pub struct PushPrice<'info> {
+    #[account(
+        mut,
+        seeds = [PRICE_FEED_SEED, price_feed.vault_type.as_ref()],
+        bump = price_feed.bump
+    )]
+    pub price_feed: Account<'info, PriceFeed>,
+
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED],
+        bump,
+        has_one = oracle_update_authority @ AegisError::Unauthorized
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    pub oracle_update_authority: Signer<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<PushPrice>, price: u64, conf: u64, publish_time: i64) -> Result<()> {
+    require!(price > 0, AegisError::InvalidAmount);
+
+    let price_feed = &mut ctx.accounts.price_feed;
+    let clock = Clock::get()?;
+
+    // Updates must be newer than the current price and not from the future
+    require!(
+        publish_time > price_feed.publish_time && publish_time <= clock.unix_timestamp,
+        AegisError::InvalidPublishTime
+    );
+
+    // Bound the move from the previous price (the first price is unbounded)
+    if price_feed.price > 0 && price_feed.max_move_bps > 0 {
+        let move_bps = (price.abs_diff(price_feed.price) as u128)
+            .checked_mul(10_000)
+            .ok_or(AegisError::MathOverflow)?
+            .checked_div(price_feed.price as u128)
+            .ok_or(AegisError::MathOverflow)?;
+        require!(
+            move_bps <= price_feed.max_move_bps as u128,
+            AegisError::PriceMoveTooLarge
+        );
+    }
+
+    price_feed.price = price;
+    price_feed.conf = conf;
+    price_feed.publish_time = publish_time;
+    price_feed.updated_at = clock.unix_timestamp;
+
+    msg!(
+        "PricePushed: vault={} price={} conf={} publish_time={}",
+        price_feed.vault_type,
+        price,
+        conf,
+        publish_time
+    );
+    Ok(())
+}
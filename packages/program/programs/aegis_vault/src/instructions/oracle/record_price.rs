@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::state::{PriceHistory, ProtocolState, VaultType};
+use crate::constants::seeds::{PRICE_HISTORY_SEED, PROTOCOL_STATE_SEED, VAULT_TYPE_SEED};
+use crate::errors::codes::AegisError;
+use crate::utils::oracle::get_spot_oracle_price;
+use crate::utils::circuit_breaker::accept_price;
+use crate::utils::price_history::push_observation;
+
+/// Permissionless: append the current accepted price to the vault type's price history.
+#[derive(Accounts)]
+pub struct RecordPrice<'info> {
+    #[account(
+        mut,
+        seeds = [PRICE_HISTORY_SEED, vault_type.key().as_ref()],
+        bump = price_history.bump
+    )]
+    pub price_history: Box<Account<'info, PriceHistory>>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_TYPE_SEED, vault_type.collateral_mint.key().as_ref()],
+        bump
+    )]
+    pub vault_type: Account<'info, VaultType>,
+
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED],
+        bump,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    /// CHECK: Validated by Pyth SDK, or as the vault type's PriceFeed
+    #[account(
+        constraint = oracle_price_account.key() == vault_type.oracle_price_account @ AegisError::InvalidOracleAccount
+    )]
+    pub oracle_price_account: AccountInfo<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<RecordPrice>) -> Result<()> {
+    let vault_type = &mut ctx.accounts.vault_type;
+    let clock = Clock::get()?;
+
+    let oracle_price = get_spot_oracle_price(
+        &ctx.accounts.oracle_price_account,
+        ctx.remaining_accounts,
+        vault_type,
+        &ctx.accounts.protocol_state,
+    )?;
+
+    // Only prices the circuit breaker accepts make it into the history
+    accept_price(vault_type, &oracle_price, clock.unix_timestamp)?;
+
+    require!(
+        push_observation(&mut ctx.accounts.price_history, oracle_price.price, clock.unix_timestamp),
+        AegisError::PriceObservationTooSoon
+    );
+
+    msg!(
+        "PriceRecorded: vault={} price={} ts={}",
+        vault_type.collateral_mint,
+        oracle_price.price,
+        clock.unix_timestamp
+    );
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::state::{ProtocolState, VaultType};
+use crate::constants::seeds::{PROTOCOL_STATE_SEED, VAULT_TYPE_SEED};
+use crate::errors::codes::AegisError;
+use crate::utils::oracle::get_spot_oracle_price;
+use crate::utils::circuit_breaker::observe_price;
+
+/// Permissionless: run the vault type's circuit breaker against the current price.
+/// Like liquidations, and unlike mint and withdraw which reject an abnormal move, this
+/// persists the trip so the vault stays mint-paused until the guardian clears it.
+#[derive(Accounts)]
+pub struct RefreshPrice<'info> {
+    #[account(
+        mut,
+        seeds = [VAULT_TYPE_SEED, vault_type.collateral_mint.key().as_ref()],
+        bump
+    )]
+    pub vault_type: Account<'info, VaultType>,
+
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED],
+        bump,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    /// CHECK: Validated by Pyth SDK, or as the vault type's PriceFeed
+    #[account(
+        constraint = oracle_price_account.key() == vault_type.oracle_price_account @ AegisError::InvalidOracleAccount
+    )]
+    pub oracle_price_account: AccountInfo<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<RefreshPrice>) -> Result<()> {
+    let vault_type = &mut ctx.accounts.vault_type;
+    let clock = Clock::get()?;
+
+    let oracle_price = get_spot_oracle_price(
+        &ctx.accounts.oracle_price_account,
+        ctx.remaining_accounts,
+        vault_type,
+        &ctx.accounts.protocol_state,
+    )?;
+
+    observe_price(vault_type, oracle_price.price, oracle_price.source, clock.unix_timestamp)?;
+
+    Ok(())
+}
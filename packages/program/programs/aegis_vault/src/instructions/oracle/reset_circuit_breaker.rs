@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::state::{ProtocolState, VaultType};
+use crate::constants::seeds::{PROTOCOL_STATE_SEED, VAULT_TYPE_SEED};
+use crate::errors::codes::AegisError;
+use crate::utils::circuit_breaker::reset_circuit_breaker;
+
+#[derive(Accounts)]
+pub struct ResetCircuitBreaker<'info> {
+    #[account(
+        mut,
+        seeds = [VAULT_TYPE_SEED, vault_type.collateral_mint.key().as_ref()],
+        bump
+    )]
+    pub vault_type: Account<'info, VaultType>,
+
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED],
+        bump,
+        has_one = guardian_pubkey @ AegisError::Unauthorized
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    pub guardian_pubkey: Signer<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<ResetCircuitBreaker>) -> Result<()> {
+    let vault_type = &mut ctx.accounts.vault_type;
+    let clock = Clock::get()?;
+
+    reset_circuit_breaker(vault_type);
+    vault_type.updated_at = clock.unix_timestamp;
+
+    msg!(
+        "CircuitBreakerReset: vault={} guardian={} tripped_at={} trip_price={}",
+        vault_type.collateral_mint,
+        ctx.accounts.guardian_pubkey.key(),
+        vault_type.circuit_breaker_tripped_at,
+        vault_type.circuit_breaker_trip_price
+    );
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::state::{AttestationConfig, ProtocolState};
+use crate::constants::seeds::{ATTESTATION_CONFIG_SEED, PROTOCOL_STATE_SEED};
+use crate::utils::attestation::{carry_attestation_timestamps, pack_attestation_signers};
+
+#[derive(Accounts)]
+pub struct SetAttestationSigners<'info> {
+    #[account(
+        mut,
+        seeds = [ATTESTATION_CONFIG_SEED, attestation_config.vault_type.as_ref()],
+        bump = attestation_config.bump
+    )]
+    pub attestation_config: Account<'info, AttestationConfig>,
+
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED],
+        bump,
+        constraint = protocol_state.admin_pubkey == admin.key(),
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    pub admin: Signer<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<SetAttestationSigners>, signers: Vec<Pubkey>, threshold: u8) -> Result<()> {
+    let attestation_config = &mut ctx.accounts.attestation_config;
+    let packed = pack_attestation_signers(&signers, threshold)?;
+    attestation_config.last_attestation_ts = carry_attestation_timestamps(attestation_config, &packed);
+    attestation_config.signers = packed;
+    attestation_config.signer_count = signers.len() as u8;
+    attestation_config.threshold = threshold;
+    attestation_config.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "Attestation Signers Updated: vault={} signers={} threshold={}",
+        attestation_config.vault_type,
+        signers.len(),
+        threshold
+    );
+    Ok(())
+}
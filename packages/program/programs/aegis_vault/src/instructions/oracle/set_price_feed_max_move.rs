@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::state::{PriceFeed, ProtocolState};
+use crate::constants::seeds::{PRICE_FEED_SEED, PROTOCOL_STATE_SEED};
+use crate::errors::codes::AegisError;
+
+#[derive(Accounts)]
+pub struct SetPriceFeedMaxMove<'info> {
+    #[account(
+        mut,
+        seeds = [PRICE_FEED_SEED, price_feed.vault_type.as_ref()],
+        bump = price_feed.bump
+    )]
+    pub price_feed: Account<'info, PriceFeed>,
+
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED],
+        bump,
+        constraint = protocol_state.admin_pubkey == admin.key(),
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    pub admin: Signer<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<SetPriceFeedMaxMove>, max_move_bps: u16) -> Result<()> {
+    require!(max_move_bps <= 10_000, AegisError::InvalidOracleConfig);
+
+    let price_feed = &mut ctx.accounts.price_feed;
+    price_feed.max_move_bps = max_move_bps;
+    price_feed.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Price Feed Max Move Updated: vault={} max_move_bps={}", price_feed.vault_type, max_move_bps);
+    Ok(())
+}
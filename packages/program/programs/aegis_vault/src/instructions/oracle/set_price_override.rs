@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use crate::state::{PriceOverrideEntry, PriceOverrideLog, ProtocolState, VaultType};
+use crate::constants::oracle::MAX_PRICE_OVERRIDE_SECONDS;
+use crate::constants::seeds::{PRICE_OVERRIDE_LOG_SEED, PROTOCOL_STATE_SEED, VAULT_TYPE_SEED};
+use crate::errors::codes::AegisError;
+use crate::utils::price_override::{log_price_override, validate_override_price};
+
+/// Guardian: price the vault type at `price` for `duration_seconds` while its feed is broken.
+#[derive(Accounts)]
+pub struct SetPriceOverride<'info> {
+    #[account(
+        mut,
+        seeds = [VAULT_TYPE_SEED, vault_type.collateral_mint.key().as_ref()],
+        bump
+    )]
+    pub vault_type: Account<'info, VaultType>,
+
+    #[account(
+        mut,
+        seeds = [PRICE_OVERRIDE_LOG_SEED, vault_type.key().as_ref()],
+        bump = price_override_log.bump
+    )]
+    pub price_override_log: Box<Account<'info, PriceOverrideLog>>,
+
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED],
+        bump,
+        has_one = guardian_pubkey @ AegisError::Unauthorized
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    pub guardian_pubkey: Signer<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<SetPriceOverride>, price: u64, duration_seconds: i64) -> Result<()> {
+    require!(
+        duration_seconds > 0 && duration_seconds <= MAX_PRICE_OVERRIDE_SECONDS,
+        AegisError::InvalidOverrideDuration
+    );
+
+    let vault_type = &mut ctx.accounts.vault_type;
+    validate_override_price(vault_type, price)?;
+
+    let clock = Clock::get()?;
+    let expires_at = clock
+        .unix_timestamp
+        .checked_add(duration_seconds)
+        .ok_or(AegisError::MathOverflow)?;
+
+    vault_type.override_price = price;
+    vault_type.override_set_at = clock.unix_timestamp;
+    vault_type.override_expires_at = expires_at;
+    vault_type.updated_at = clock.unix_timestamp;
+
+    log_price_override(
+        &mut ctx.accounts.price_override_log,
+        PriceOverrideEntry {
+            guardian: ctx.accounts.guardian_pubkey.key(),
+            price,
+            reference_price: vault_type.last_accepted_price,
+            set_at: clock.unix_timestamp,
+            expires_at,
+        },
+    );
+
+    msg!(
+        "PriceOverrideSet: vault={} guardian={} price={} last_good_price={} expires_at={}",
+        vault_type.collateral_mint,
+        ctx.accounts.guardian_pubkey.key(),
+        price,
+        vault_type.last_accepted_price,
+        expires_at
+    );
+    Ok(())
+}
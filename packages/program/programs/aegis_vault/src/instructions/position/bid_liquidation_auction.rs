@@ -0,0 +1,157 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Mint, Burn, Transfer};
+use crate::state::{LiquidationAuction, Position, VaultType, ProtocolState};
+use crate::constants::seeds::LIQUIDATION_AUCTION_SEED;
+use crate::errors::codes::AegisError;
+use crate::utils::auction::{auction_fill, auction_price};
+use crate::utils::solvency::record_bad_debt;
+use crate::utils::redistribution::{add_position_collateral, apply_pending_redistribution};
+
+#[derive(Accounts)]
+pub struct BidLiquidationAuction<'info> {
+    #[account(
+        mut,
+        seeds = [LIQUIDATION_AUCTION_SEED, auction.position.as_ref()],
+        bump = auction.bump,
+        has_one = vault_type,
+        has_one = position,
+        has_one = keeper
+    )]
+    pub auction: Account<'info, LiquidationAuction>,
+
+    #[account(mut)]
+    pub position: Account<'info, Position>,
+
+    #[account(mut)]
+    pub vault_type: Account<'info, VaultType>,
+
+    #[account(mut)]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    #[account(
+        mut,
+        constraint = stablecoin_mint.key() == protocol_state.stablecoin_mint @ AegisError::Unauthorized
+    )]
+    pub stablecoin_mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub bidder_stablecoin_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub bidder_collateral_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = vault_collateral_account.mint == vault_type.collateral_mint,
+        constraint = vault_collateral_account.owner == vault_authority.key()
+    )]
+    pub vault_collateral_account: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA that has authority over vault collateral
+    #[account(
+        seeds = [b"vault_authority", vault_type.key().as_ref()],
+        bump = vault_type.vault_authority_bump
+    )]
+    pub vault_authority: AccountInfo<'info>,
+
+    /// CHECK: Keeper that started the auction, receives the rent when it settles
+    #[account(mut)]
+    pub keeper: AccountInfo<'info>,
+
+    pub bidder: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<BidLiquidationAuction>,
+    collateral_amount: u64,
+    max_price: u64,
+) -> Result<()> {
+    require!(collateral_amount > 0, AegisError::InvalidAmount);
+
+    let auction = &mut ctx.accounts.auction;
+    let vault_type = &mut ctx.accounts.vault_type;
+    let protocol_state = &mut ctx.accounts.protocol_state;
+    let clock = Clock::get()?;
+
+    require!(!protocol_state.is_protocol_paused, AegisError::ProtocolPaused);
+
+    let price = auction_price(auction, clock.unix_timestamp)?;
+    require!(price <= max_price, AegisError::AuctionPriceTooHigh);
+
+    // Never sell more than is needed to cover the remaining debt
+    let (lot, cost) = auction_fill(auction, collateral_amount, price, vault_type.collateral_decimals)?;
+    require!(lot > 0 && cost > 0, AegisError::InvalidAmount);
+
+    // Burn the bid
+    let cpi_accounts = Burn {
+        mint: ctx.accounts.stablecoin_mint.to_account_info(),
+        from: ctx.accounts.bidder_stablecoin_account.to_account_info(),
+        authority: ctx.accounts.bidder.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::burn(cpi_ctx, cost)?;
+
+    // Transfer the collateral to the bidder
+    let vault_type_key = vault_type.key();
+    let seeds = &[
+        b"vault_authority".as_ref(),
+        vault_type_key.as_ref(),
+        &[vault_type.vault_authority_bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.vault_collateral_account.to_account_info(),
+        to: ctx.accounts.bidder_collateral_account.to_account_info(),
+        authority: ctx.accounts.vault_authority.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer);
+    token::transfer(cpi_ctx, lot)?;
+
+    auction.collateral_amount = auction.collateral_amount
+        .checked_sub(lot)
+        .ok_or(AegisError::MathOverflow)?;
+    auction.debt_remaining = auction.debt_remaining
+        .checked_sub(cost)
+        .ok_or(AegisError::MathOverflow)?;
+
+    // Update protocol state (saturating: per-position rounding can leave dust)
+    protocol_state.total_protocol_debt = protocol_state.total_protocol_debt.saturating_sub(cost);
+    protocol_state.updated_at = clock.unix_timestamp;
+
+    msg!("LiquidationAuctionBid: auction={} bidder={} collateral={} cost={} price={}",
+        auction.key(),
+        ctx.accounts.bidder.key(),
+        lot,
+        cost,
+        price
+    );
+
+    // Settle once the debt is raised or the collateral is gone
+    if auction.debt_remaining == 0 || auction.collateral_amount == 0 {
+        let position = &mut ctx.accounts.position;
+        let surplus = auction.collateral_amount;
+        apply_pending_redistribution(position, vault_type)?;
+        add_position_collateral(position, vault_type, surplus)?;
+        position.updated_at = clock.unix_timestamp;
+
+        // Debt the collateral didn't cover is bad debt
+        if auction.debt_remaining > 0 {
+            record_bad_debt(protocol_state, auction.debt_remaining)?;
+        }
+
+        msg!("LiquidationAuctionSettled: auction={} owner={} position={} surplus={} shortfall={}",
+            auction.key(),
+            position.owner,
+            auction.position,
+            surplus,
+            auction.debt_remaining
+        );
+
+        auction.close(ctx.accounts.keeper.to_account_info())?;
+    }
+
+    Ok(())
+}
@@ -3,6 +3,9 @@ use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 use crate::state::{Position, VaultType, ProtocolState};
 use crate::constants::seeds::POSITION_SEED;
 use crate::utils::oracle::get_oracle_price;
+use crate::utils::price_history::record_price_observation;
+use crate::utils::interest::accrue_interest;
+use crate::utils::redistribution::{add_position_collateral, apply_pending_redistribution};
 use crate::errors::codes::AegisError;
 
 
@@ -21,8 +24,10 @@ pub struct DepositCollateral<'info> {
     )]
     pub position: Account<'info, Position>,
 
+    #[account(mut)]
     pub vault_type: Account<'info, VaultType>,
 
+    #[account(mut)]
     pub protocol_state: Account<'info, ProtocolState>,
 
     #[account(mut)]
@@ -42,7 +47,10 @@ pub struct DepositCollateral<'info> {
     )]
     pub vault_authority: AccountInfo<'info>,
 
-    /// CHECK: Validated by Pyth SDK
+    /// CHECK: Validated by Pyth SDK, or as the vault type's PriceFeed
+    #[account(
+        constraint = oracle_price_account.key() == vault_type.oracle_price_account @ AegisError::InvalidOracleAccount
+    )]
     pub oracle_price_account: AccountInfo<'info>,
 
     pub owner: Signer<'info>,
@@ -50,17 +58,33 @@ pub struct DepositCollateral<'info> {
     pub token_program: Program<'info, Token>,
 }
 
-pub fn handler(ctx: Context<DepositCollateral>, amount: u64) -> Result<()> {
+pub(crate) fn handler(ctx: Context<DepositCollateral>, amount: u64) -> Result<()> {
     require!(amount > 0, crate::errors::AegisError::InvalidAmount);
 
     let position = &mut ctx.accounts.position;
     let clock = Clock::get()?;
 
+    // Accrue stability fees up to now
+    accrue_interest(
+        &mut ctx.accounts.vault_type,
+        &mut ctx.accounts.protocol_state,
+        clock.unix_timestamp,
+    )?;
+    apply_pending_redistribution(position, &mut ctx.accounts.vault_type)?;
+
     // Get oracle price (for informational purposes and validation)
-    let _price = get_oracle_price(
+    let oracle_price = get_oracle_price(
         &ctx.accounts.oracle_price_account,
+        ctx.remaining_accounts,
+        &ctx.accounts.vault_type,
         &ctx.accounts.protocol_state,
     )?;
+    record_price_observation(
+        ctx.remaining_accounts,
+        &ctx.accounts.vault_type,
+        &oracle_price,
+        clock.unix_timestamp,
+    )?;
 
     // Transfer collateral from user to vault
     let cpi_accounts = Transfer {
@@ -73,9 +97,7 @@ pub fn handler(ctx: Context<DepositCollateral>, amount: u64) -> Result<()> {
     token::transfer(cpi_ctx, amount)?;
 
     // Update position
-    position.collateral_amount = position.collateral_amount
-        .checked_add(amount)
-        .ok_or(crate::errors::AegisError::MathOverflow)?;
+    add_position_collateral(position, &mut ctx.accounts.vault_type, amount)?;
     position.updated_at = clock.unix_timestamp;
 
     msg!("Deposited {} collateral", amount);
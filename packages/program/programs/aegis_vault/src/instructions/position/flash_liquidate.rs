@@ -0,0 +1,318 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke;
+use anchor_spl::token::{self, Token, TokenAccount, Mint, Burn, MintTo, Transfer};
+use crate::state::{Position, VaultType, ProtocolState};
+use crate::constants::seeds::POSITION_SEED;
+use crate::constants::liquidation::LIQUIDATION_MODE_FIXED_DISCOUNT;
+use crate::utils::oracle::get_liquidation_oracle_price;
+use crate::utils::interest::{accrue_interest, position_debt, remove_position_debt};
+use crate::utils::redistribution::{apply_pending_redistribution, remove_position_collateral};
+use crate::errors::codes::AegisError;
+use crate::utils::valuation;
+use crate::utils::circuit_breaker::observe_price;
+use crate::utils::price_history::record_price_observation;
+use crate::utils::liquidation::{is_liquidatable, liquidation_penalty_bps, liquidation_seizure, max_liquidation_repay};
+use crate::utils::solvency::{keeper_reward_sources, treasury_allowance};
+
+/// Liquidate without holding AGSUSD up front: the seized collateral is sent to the
+/// liquidator first, then `callback_program` is invoked with `callback_data` and the
+/// remaining accounts. The callback must leave the repay amount in the liquidator's
+/// stablecoin account, which is burned before the instruction returns.
+#[derive(Accounts)]
+pub struct FlashLiquidate<'info> {
+    #[account(
+        mut,
+        seeds = [
+            POSITION_SEED,
+            position.owner.as_ref(),
+            vault_type.key().as_ref()
+        ],
+        bump,
+        has_one = vault_type
+    )]
+    pub position: Account<'info, Position>,
+
+    #[account(mut)]
+    pub vault_type: Account<'info, VaultType>,
+
+    #[account(mut)]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    #[account(
+        mut,
+        constraint = stablecoin_mint.key() == protocol_state.stablecoin_mint @ AegisError::Unauthorized
+    )]
+    pub stablecoin_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = liquidator_stablecoin_account.mint == protocol_state.stablecoin_mint @ AegisError::Unauthorized
+    )]
+    pub liquidator_stablecoin_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = liquidator_collateral_account.mint == vault_type.collateral_mint
+    )]
+    pub liquidator_collateral_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = vault_collateral_account.mint == vault_type.collateral_mint,
+        constraint = vault_collateral_account.owner == vault_authority.key()
+    )]
+    pub vault_collateral_account: Account<'info, TokenAccount>,
+
+    /// Treasury collateral account to receive protocol's share of penalty
+    #[account(
+        mut,
+        constraint = treasury_collateral_account.mint == vault_type.collateral_mint,
+        constraint = treasury_collateral_account.owner == protocol_state.treasury_pubkey @ AegisError::Unauthorized
+    )]
+    pub treasury_collateral_account: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA that has authority over vault collateral
+    #[account(
+        seeds = [b"vault_authority", vault_type.key().as_ref()],
+        bump = vault_type.vault_authority_bump
+    )]
+    pub vault_authority: AccountInfo<'info>,
+
+    /// Treasury stablecoin account, pays keeper rewards from its delegated allowance
+    #[account(
+        mut,
+        constraint = treasury_stablecoin_account.key() == protocol_state.treasury_ata @ AegisError::Unauthorized
+    )]
+    pub treasury_stablecoin_account: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA that has mint authority, and the treasury allowance delegate
+    #[account(
+        seeds = [b"mint_authority"],
+        bump = protocol_state.mint_authority_bump
+    )]
+    pub mint_authority: AccountInfo<'info>,
+
+    /// CHECK: Validated by Pyth SDK, or as the vault type's PriceFeed
+    #[account(
+        constraint = oracle_price_account.key() == vault_type.oracle_price_account @ AegisError::InvalidOracleAccount
+    )]
+    pub oracle_price_account: AccountInfo<'info>,
+
+    /// CHECK: Any executable program except Aegis itself; invoked without Aegis signers
+    #[account(
+        executable,
+        constraint = callback_program.key() != crate::ID @ AegisError::InvalidCallbackProgram
+    )]
+    pub callback_program: AccountInfo<'info>,
+
+    pub liquidator: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub(crate) fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, FlashLiquidate<'info>>,
+    repay_amount: u64,
+    callback_data: Vec<u8>,
+) -> Result<()> {
+    require!(repay_amount > 0, AegisError::InvalidAmount);
+
+    let position = &mut ctx.accounts.position;
+    let vault_type = &mut ctx.accounts.vault_type;
+    let protocol_state = &mut ctx.accounts.protocol_state;
+    let clock = Clock::get()?;
+
+    require!(!protocol_state.is_protocol_paused, AegisError::ProtocolPaused);
+    require!(
+        vault_type.liquidation_mode == LIQUIDATION_MODE_FIXED_DISCOUNT,
+        AegisError::LiquidationModeMismatch
+    );
+
+    // Accrue stability fees before the health check
+    accrue_interest(vault_type, protocol_state, clock.unix_timestamp)?;
+    apply_pending_redistribution(position, vault_type)?;
+    let debt = position_debt(position, vault_type)?;
+
+    let oracle_price = get_liquidation_oracle_price(
+        &ctx.accounts.oracle_price_account,
+        ctx.remaining_accounts,
+        vault_type,
+        protocol_state,
+    )?;
+
+    // An abnormal move trips the circuit breaker without blocking the liquidation;
+    // only accepted prices go into the price history
+    if observe_price(vault_type, oracle_price.price, oracle_price.source, clock.unix_timestamp)? {
+        record_price_observation(ctx.remaining_accounts, vault_type, &oracle_price, clock.unix_timestamp)?;
+    }
+
+    let price = oracle_price.price;
+    let eligibility_price = oracle_price.liquidation_check_price(vault_type);
+    let payout_price = oracle_price.liquidation_price(vault_type)?;
+
+    let collateral_value = valuation::collateral_value(
+        position.collateral_amount,
+        eligibility_price,
+        vault_type.collateral_decimals,
+    )?;
+    require!(
+        is_liquidatable(collateral_value, debt, vault_type.liq_threshold_bps)?,
+        AegisError::PositionHealthy
+    );
+
+    // Liquidation bonus, scaled by health when the vault type's bonus curve is set
+    let penalty_bps = liquidation_penalty_bps(vault_type, collateral_value, debt)?;
+
+    // Same repay bounds and penalty split as liquidate_position
+    let max_repay = max_liquidation_repay(
+        vault_type,
+        debt,
+        collateral_value,
+        penalty_bps,
+    )?;
+    let seizure = liquidation_seizure(
+        vault_type,
+        repay_amount.min(max_repay),
+        position.collateral_amount,
+        payout_price,
+        penalty_bps,
+    )?;
+    let actual_repay = seizure.repay;
+    let total_collateral_to_transfer = seizure.collateral;
+    let protocol_fee_collateral = seizure.protocol_fee;
+    let liquidator_collateral = seizure.liquidator_collateral();
+    let complete = seizure.is_complete(max_repay, position.collateral_amount);
+
+    // Settle the position before handing control to the callback
+    remove_position_debt(position, vault_type, actual_repay)?;
+    remove_position_collateral(position, vault_type, total_collateral_to_transfer)?;
+    position.updated_at = clock.unix_timestamp;
+
+    protocol_state.total_protocol_debt = protocol_state.total_protocol_debt.saturating_sub(actual_repay);
+    protocol_state.updated_at = clock.unix_timestamp;
+
+    // Collateral goes out first
+    let vault_type_key = vault_type.key();
+    let seeds = &[
+        b"vault_authority".as_ref(),
+        vault_type_key.as_ref(),
+        &[vault_type.vault_authority_bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.vault_collateral_account.to_account_info(),
+        to: ctx.accounts.liquidator_collateral_account.to_account_info(),
+        authority: ctx.accounts.vault_authority.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer);
+    token::transfer(cpi_ctx, liquidator_collateral)?;
+
+    if protocol_fee_collateral > 0 {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.vault_collateral_account.to_account_info(),
+            to: ctx.accounts.treasury_collateral_account.to_account_info(),
+            authority: ctx.accounts.vault_authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer);
+        token::transfer(cpi_ctx, protocol_fee_collateral)?;
+
+        let fee_value_usd = valuation::collateral_value(
+            protocol_fee_collateral,
+            price,
+            vault_type.collateral_decimals,
+        )?;
+        protocol_state.total_liquidation_fees_collected = protocol_state.total_liquidation_fees_collected
+            .checked_add(fee_value_usd)
+            .ok_or(AegisError::MathOverflow)?;
+    }
+
+    // Flat keeper reward for small positions, from the surplus buffer then the treasury allowance
+    let allowance = treasury_allowance(
+        &ctx.accounts.treasury_stablecoin_account,
+        &ctx.accounts.mint_authority.key(),
+    );
+    let (reward_from_surplus, reward_from_treasury) =
+        keeper_reward_sources(protocol_state, vault_type, debt, complete, allowance);
+    let mint_seeds = &[
+        b"mint_authority".as_ref(),
+        &[protocol_state.mint_authority_bump],
+    ];
+    let mint_signer = &[&mint_seeds[..]];
+
+    if reward_from_surplus > 0 {
+        let cpi_accounts = MintTo {
+            mint: ctx.accounts.stablecoin_mint.to_account_info(),
+            to: ctx.accounts.liquidator_stablecoin_account.to_account_info(),
+            authority: ctx.accounts.mint_authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, mint_signer);
+        token::mint_to(cpi_ctx, reward_from_surplus)?;
+    }
+    if reward_from_treasury > 0 {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.treasury_stablecoin_account.to_account_info(),
+            to: ctx.accounts.liquidator_stablecoin_account.to_account_info(),
+            authority: ctx.accounts.mint_authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, mint_signer);
+        token::transfer(cpi_ctx, reward_from_treasury)?;
+    }
+
+    // Hand the remaining accounts to the callback, e.g. to swap the collateral for AGSUSD.
+    // Only the signers of the outer transaction carry over; no Aegis PDA signs.
+    let callback_accounts = ctx.remaining_accounts
+        .iter()
+        .map(|account| AccountMeta {
+            pubkey: account.key(),
+            is_signer: account.is_signer,
+            is_writable: account.is_writable,
+        })
+        .collect();
+    let callback = Instruction {
+        program_id: ctx.accounts.callback_program.key(),
+        accounts: callback_accounts,
+        data: callback_data,
+    };
+    let mut callback_infos = ctx.remaining_accounts.to_vec();
+    callback_infos.push(ctx.accounts.callback_program.clone());
+    invoke(&callback, &callback_infos)?;
+
+    // The repay amount must be back in the liquidator's account, and is burned now
+    ctx.accounts.liquidator_stablecoin_account.reload()?;
+    require!(
+        ctx.accounts.liquidator_stablecoin_account.amount >= actual_repay,
+        AegisError::FlashLiquidationNotRepaid
+    );
+
+    let cpi_accounts = Burn {
+        mint: ctx.accounts.stablecoin_mint.to_account_info(),
+        from: ctx.accounts.liquidator_stablecoin_account.to_account_info(),
+        authority: ctx.accounts.liquidator.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::burn(cpi_ctx, actual_repay)?;
+
+    msg!("FlashLiquidation: liquidator={} owner={} position={} vault={} callback={} debt={} collateral={} penalty={} price={}",
+        ctx.accounts.liquidator.key(),
+        ctx.accounts.position.owner,
+        ctx.accounts.position.key(),
+        vault_type_key,
+        ctx.accounts.callback_program.key(),
+        actual_repay,
+        total_collateral_to_transfer,
+        protocol_fee_collateral,
+        price
+    );
+    if reward_from_surplus + reward_from_treasury > 0 {
+        msg!("KeeperRewardPaid: keeper={} position={} surplus={} treasury={}",
+            ctx.accounts.liquidator.key(),
+            ctx.accounts.position.key(),
+            reward_from_surplus,
+            reward_from_treasury
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,348 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Mint, Burn, MintTo, Transfer};
+use crate::state::{Position, VaultType, ProtocolState};
+use crate::constants::liquidation::{
+    BATCH_LIQUIDATION_HEALTHY, BATCH_LIQUIDATION_LIQUIDATED, BATCH_LIQUIDATION_SKIPPED,
+    LIQUIDATION_MODE_FIXED_DISCOUNT, MAX_BATCH_LIQUIDATIONS,
+};
+use crate::utils::oracle::get_liquidation_oracle_price;
+use crate::utils::interest::{accrue_interest, position_debt, remove_position_debt};
+use crate::utils::redistribution::{apply_pending_redistribution, remove_position_collateral};
+use crate::errors::codes::AegisError;
+use crate::utils::valuation;
+use crate::utils::circuit_breaker::observe_price;
+use crate::utils::price_history::record_price_observation;
+use crate::utils::liquidation::{
+    is_liquidatable, liquidation_penalty_bps, liquidation_seizure, max_liquidation_repay, LiquidationSeizure,
+};
+use crate::utils::solvency::{keeper_reward_sources, treasury_allowance};
+
+/// Outcome for one position of a `liquidate_batch`, returned as return data.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct BatchLiquidationResult {
+    pub position: Pubkey,
+    /// `BATCH_LIQUIDATION_*` status
+    pub status: u8,
+    pub debt_repaid: u64,
+    pub collateral_seized: u64,
+}
+
+/// Liquidate every unhealthy position of a vault type against a single oracle read.
+/// Positions are the writable `remaining_accounts` that hold a `Position`; the other
+/// remaining accounts are oracle inputs (secondary feed, stake pool, price history).
+#[derive(Accounts)]
+pub struct LiquidateBatch<'info> {
+    #[account(mut)]
+    pub vault_type: Account<'info, VaultType>,
+
+    #[account(mut)]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    #[account(
+        mut,
+        constraint = stablecoin_mint.key() == protocol_state.stablecoin_mint @ AegisError::Unauthorized
+    )]
+    pub stablecoin_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = liquidator_stablecoin_account.mint == protocol_state.stablecoin_mint @ AegisError::Unauthorized
+    )]
+    pub liquidator_stablecoin_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = liquidator_collateral_account.mint == vault_type.collateral_mint
+    )]
+    pub liquidator_collateral_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = vault_collateral_account.mint == vault_type.collateral_mint,
+        constraint = vault_collateral_account.owner == vault_authority.key()
+    )]
+    pub vault_collateral_account: Account<'info, TokenAccount>,
+
+    /// Treasury collateral account to receive protocol's share of penalty
+    #[account(
+        mut,
+        constraint = treasury_collateral_account.mint == vault_type.collateral_mint,
+        constraint = treasury_collateral_account.owner == protocol_state.treasury_pubkey @ AegisError::Unauthorized
+    )]
+    pub treasury_collateral_account: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA that has authority over vault collateral
+    #[account(
+        seeds = [b"vault_authority", vault_type.key().as_ref()],
+        bump = vault_type.vault_authority_bump
+    )]
+    pub vault_authority: AccountInfo<'info>,
+
+    /// Treasury stablecoin account, pays keeper rewards from its delegated allowance
+    #[account(
+        mut,
+        constraint = treasury_stablecoin_account.key() == protocol_state.treasury_ata @ AegisError::Unauthorized
+    )]
+    pub treasury_stablecoin_account: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA that has mint authority, and the treasury allowance delegate
+    #[account(
+        seeds = [b"mint_authority"],
+        bump = protocol_state.mint_authority_bump
+    )]
+    pub mint_authority: AccountInfo<'info>,
+
+    /// CHECK: Validated by Pyth SDK, or as the vault type's PriceFeed
+    #[account(
+        constraint = oracle_price_account.key() == vault_type.oracle_price_account @ AegisError::InvalidOracleAccount
+    )]
+    pub oracle_price_account: AccountInfo<'info>,
+
+    pub liquidator: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub(crate) fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, LiquidateBatch<'info>>,
+    max_repay_per_position: u64,
+) -> Result<Vec<BatchLiquidationResult>> {
+    require!(max_repay_per_position > 0, AegisError::InvalidAmount);
+
+    let vault_type = &mut ctx.accounts.vault_type;
+    let protocol_state = &mut ctx.accounts.protocol_state;
+    let clock = Clock::get()?;
+
+    require!(!protocol_state.is_protocol_paused, AegisError::ProtocolPaused);
+    require!(
+        vault_type.liquidation_mode == LIQUIDATION_MODE_FIXED_DISCOUNT,
+        AegisError::LiquidationModeMismatch
+    );
+
+    // Accrue stability fees and read the oracle once for the whole batch
+    accrue_interest(vault_type, protocol_state, clock.unix_timestamp)?;
+
+    let oracle_price = get_liquidation_oracle_price(
+        &ctx.accounts.oracle_price_account,
+        ctx.remaining_accounts,
+        vault_type,
+        protocol_state,
+    )?;
+    // An abnormal move trips the circuit breaker without blocking the liquidation;
+    // only accepted prices go into the price history
+    if observe_price(vault_type, oracle_price.price, oracle_price.source, clock.unix_timestamp)? {
+        record_price_observation(ctx.remaining_accounts, vault_type, &oracle_price, clock.unix_timestamp)?;
+    }
+
+    let price = oracle_price.price;
+    let eligibility_price = oracle_price.liquidation_check_price(vault_type);
+    let payout_price = oracle_price.liquidation_price(vault_type)?;
+
+    // Repays are limited by the liquidator's balance, so running out skips the rest
+    let mut available = ctx.accounts.liquidator_stablecoin_account.amount;
+    let mut allowance = treasury_allowance(
+        &ctx.accounts.treasury_stablecoin_account,
+        &ctx.accounts.mint_authority.key(),
+    );
+
+    let vault_type_key = vault_type.key();
+    let mut results: Vec<BatchLiquidationResult> = Vec::new();
+    let mut total_repay: u64 = 0;
+    let mut total_liquidator_collateral: u64 = 0;
+    let mut total_protocol_fee: u64 = 0;
+    let mut total_reward_from_surplus: u64 = 0;
+    let mut total_reward_from_treasury: u64 = 0;
+
+    for info in ctx.remaining_accounts.iter().filter(|account| account.is_writable) {
+        let Ok(mut position) = Account::<Position>::try_from(info) else {
+            continue;
+        };
+        require!(results.len() < MAX_BATCH_LIQUIDATIONS, AegisError::BatchTooLarge);
+        // A position listed twice would earn the keeper reward twice
+        require!(
+            results.iter().all(|result| result.position != info.key()),
+            AegisError::DuplicateBatchPosition
+        );
+
+        let mut result = BatchLiquidationResult {
+            position: info.key(),
+            status: BATCH_LIQUIDATION_SKIPPED,
+            debt_repaid: 0,
+            collateral_seized: 0,
+        };
+        if position.vault_type != vault_type_key {
+            results.push(result);
+            continue;
+        }
+
+        // Persisted even when the position is left alone, as the vault's stakes change with it
+        apply_pending_redistribution(&mut position, vault_type)?;
+        let debt = position_debt(&position, vault_type)?;
+        let collateral_value = valuation::collateral_value(
+            position.collateral_amount,
+            eligibility_price,
+            vault_type.collateral_decimals,
+        )?;
+        if !is_liquidatable(collateral_value, debt, vault_type.liq_threshold_bps)? {
+            result.status = BATCH_LIQUIDATION_HEALTHY;
+            results.push(result);
+            position.exit(&crate::ID)?;
+            continue;
+        }
+
+        // Liquidation bonus, scaled by health when the vault type's bonus curve is set
+        let penalty_bps = liquidation_penalty_bps(vault_type, collateral_value, debt)?;
+
+        // Same repay bounds and penalty split as liquidate_position
+        let max_repay = max_liquidation_repay(
+            vault_type,
+            debt,
+            collateral_value,
+            penalty_bps,
+        )?;
+        let repay = max_repay.min(max_repay_per_position).min(available);
+        if repay == 0 {
+            results.push(result);
+            position.exit(&crate::ID)?;
+            continue;
+        }
+
+        let seizure = liquidation_seizure(
+            vault_type,
+            repay,
+            position.collateral_amount,
+            payout_price,
+            penalty_bps,
+        )?;
+        let complete = seizure.is_complete(max_repay, position.collateral_amount);
+        let LiquidationSeizure { repay, collateral: seized, protocol_fee } = seizure;
+
+        let (reward_from_surplus, reward_from_treasury) =
+            keeper_reward_sources(protocol_state, vault_type, debt, complete, allowance);
+        allowance -= reward_from_treasury;
+
+        remove_position_debt(&mut position, vault_type, repay)?;
+        remove_position_collateral(&mut position, vault_type, seized)?;
+        position.updated_at = clock.unix_timestamp;
+        position.exit(&crate::ID)?;
+
+        available -= repay;
+        total_repay = total_repay.checked_add(repay).ok_or(AegisError::MathOverflow)?;
+        total_liquidator_collateral = total_liquidator_collateral
+            .checked_add(seized - protocol_fee)
+            .ok_or(AegisError::MathOverflow)?;
+        total_protocol_fee = total_protocol_fee.checked_add(protocol_fee).ok_or(AegisError::MathOverflow)?;
+        total_reward_from_surplus += reward_from_surplus;
+        total_reward_from_treasury += reward_from_treasury;
+
+        msg!("BatchPositionLiquidated: owner={} position={} debt={} collateral={} penalty={}",
+            position.owner,
+            info.key(),
+            repay,
+            seized,
+            protocol_fee
+        );
+
+        result.status = BATCH_LIQUIDATION_LIQUIDATED;
+        result.debt_repaid = repay;
+        result.collateral_seized = seized;
+        results.push(result);
+    }
+
+    // Settle the whole batch with one burn and one transfer per destination
+    if total_repay > 0 {
+        let cpi_accounts = Burn {
+            mint: ctx.accounts.stablecoin_mint.to_account_info(),
+            from: ctx.accounts.liquidator_stablecoin_account.to_account_info(),
+            authority: ctx.accounts.liquidator.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+        token::burn(cpi_ctx, total_repay)?;
+    }
+
+    let seeds = &[
+        b"vault_authority".as_ref(),
+        vault_type_key.as_ref(),
+        &[vault_type.vault_authority_bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    if total_liquidator_collateral > 0 {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.vault_collateral_account.to_account_info(),
+            to: ctx.accounts.liquidator_collateral_account.to_account_info(),
+            authority: ctx.accounts.vault_authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer);
+        token::transfer(cpi_ctx, total_liquidator_collateral)?;
+    }
+
+    if total_protocol_fee > 0 {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.vault_collateral_account.to_account_info(),
+            to: ctx.accounts.treasury_collateral_account.to_account_info(),
+            authority: ctx.accounts.vault_authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer);
+        token::transfer(cpi_ctx, total_protocol_fee)?;
+
+        let fee_value_usd = valuation::collateral_value(
+            total_protocol_fee,
+            price,
+            vault_type.collateral_decimals,
+        )?;
+        protocol_state.total_liquidation_fees_collected = protocol_state.total_liquidation_fees_collected
+            .checked_add(fee_value_usd)
+            .ok_or(AegisError::MathOverflow)?;
+    }
+
+    // Keeper rewards, from the surplus buffer then the treasury allowance
+    let mint_seeds = &[
+        b"mint_authority".as_ref(),
+        &[protocol_state.mint_authority_bump],
+    ];
+    let mint_signer = &[&mint_seeds[..]];
+
+    if total_reward_from_surplus > 0 {
+        let cpi_accounts = MintTo {
+            mint: ctx.accounts.stablecoin_mint.to_account_info(),
+            to: ctx.accounts.liquidator_stablecoin_account.to_account_info(),
+            authority: ctx.accounts.mint_authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, mint_signer);
+        token::mint_to(cpi_ctx, total_reward_from_surplus)?;
+    }
+    if total_reward_from_treasury > 0 {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.treasury_stablecoin_account.to_account_info(),
+            to: ctx.accounts.liquidator_stablecoin_account.to_account_info(),
+            authority: ctx.accounts.mint_authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, mint_signer);
+        token::transfer(cpi_ctx, total_reward_from_treasury)?;
+    }
+
+    // Update protocol state (saturating: per-position rounding can leave dust)
+    protocol_state.total_protocol_debt = protocol_state.total_protocol_debt.saturating_sub(total_repay);
+    protocol_state.updated_at = clock.unix_timestamp;
+
+    msg!("BatchLiquidation: liquidator={} vault={} positions={} debt={} collateral={} penalty={} price={}",
+        ctx.accounts.liquidator.key(),
+        vault_type_key,
+        results.len(),
+        total_repay,
+        total_liquidator_collateral + total_protocol_fee,
+        total_protocol_fee,
+        price
+    );
+    if total_reward_from_surplus + total_reward_from_treasury > 0 {
+        msg!("KeeperRewardPaid: keeper={} vault={} surplus={} treasury={}",
+            ctx.accounts.liquidator.key(),
+            vault_type_key,
+            total_reward_from_surplus,
+            total_reward_from_treasury
+        );
+    }
+
+    Ok(results)
+}
@@ -1,9 +1,17 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Mint, Burn, Transfer};
+use anchor_spl::token::{self, Token, TokenAccount, Mint, Burn, MintTo, Transfer};
 use crate::state::{Position, VaultType, ProtocolState};
 use crate::constants::seeds::POSITION_SEED;
-use crate::utils::oracle::get_oracle_price;
+use crate::constants::liquidation::LIQUIDATION_MODE_FIXED_DISCOUNT;
+use crate::utils::oracle::get_liquidation_oracle_price;
+use crate::utils::interest::{accrue_interest, position_debt, remove_position_debt};
+use crate::utils::redistribution::{apply_pending_redistribution, remove_position_collateral};
 use crate::errors::codes::AegisError;
+use crate::utils::valuation;
+use crate::utils::circuit_breaker::observe_price;
+use crate::utils::price_history::record_price_observation;
+use crate::utils::liquidation::{liquidation_penalty_bps, liquidation_seizure, max_liquidation_repay};
+use crate::utils::solvency::{keeper_reward_sources, treasury_allowance};
 
 #[derive(Accounts)]
 pub struct LiquidatePosition<'info> {
@@ -19,6 +27,7 @@ pub struct LiquidatePosition<'info> {
     )]
     pub position: Account<'info, Position>,
 
+    #[account(mut)]
     pub vault_type: Account<'info, VaultType>,
 
     #[account(mut)]
@@ -58,7 +67,24 @@ pub struct LiquidatePosition<'info> {
     )]
     pub vault_authority: AccountInfo<'info>,
 
-    /// CHECK: Validated by Pyth SDK
+    /// Treasury stablecoin account, pays keeper rewards from its delegated allowance
+    #[account(
+        mut,
+        constraint = treasury_stablecoin_account.key() == protocol_state.treasury_ata @ AegisError::Unauthorized
+    )]
+    pub treasury_stablecoin_account: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA that has mint authority, and the treasury allowance delegate
+    #[account(
+        seeds = [b"mint_authority"],
+        bump = protocol_state.mint_authority_bump
+    )]
+    pub mint_authority: AccountInfo<'info>,
+
+    /// CHECK: Validated by Pyth SDK, or as the vault type's PriceFeed
+    #[account(
+        constraint = oracle_price_account.key() == vault_type.oracle_price_account @ AegisError::InvalidOracleAccount
+    )]
     pub oracle_price_account: AccountInfo<'info>,
 
     pub liquidator: Signer<'info>,
@@ -66,32 +92,59 @@ pub struct LiquidatePosition<'info> {
     pub token_program: Program<'info, Token>,
 }
 
-pub fn handler(ctx: Context<LiquidatePosition>, repay_amount: u64) -> Result<()> {
+pub(crate) fn handler(ctx: Context<LiquidatePosition>, repay_amount: u64) -> Result<()> {
     require!(repay_amount > 0, AegisError::InvalidAmount);
 
     let position = &mut ctx.accounts.position;
-    let vault_type = &ctx.accounts.vault_type;
+    let vault_type = &mut ctx.accounts.vault_type;
     let protocol_state = &mut ctx.accounts.protocol_state;
+    let clock = Clock::get()?;
 
     // Check if protocol is paused
     require!(!protocol_state.is_protocol_paused, AegisError::ProtocolPaused);
 
+    // Auction-mode vault types liquidate through start_liquidation_auction
+    require!(
+        vault_type.liquidation_mode == LIQUIDATION_MODE_FIXED_DISCOUNT,
+        AegisError::LiquidationModeMismatch
+    );
+
+    // Accrue stability fees before the health check
+    accrue_interest(vault_type, protocol_state, clock.unix_timestamp)?;
+    apply_pending_redistribution(position, vault_type)?;
+    let debt = position_debt(position, vault_type)?;
+
     // Get oracle price
-    let price = get_oracle_price(
+    let oracle_price = get_liquidation_oracle_price(
         &ctx.accounts.oracle_price_account,
+        ctx.remaining_accounts,
+        vault_type,
         protocol_state,
     )?;
 
+    // An abnormal move trips the circuit breaker without blocking the liquidation;
+    // only accepted prices go into the price history
+    if observe_price(vault_type, oracle_price.price, oracle_price.source, clock.unix_timestamp)? {
+        record_price_observation(ctx.remaining_accounts, vault_type, &oracle_price, clock.unix_timestamp)?;
+    }
+
+    // Eligibility uses the vault's pricing mode (spot, EMA or the higher of the two);
+    // payouts use the high end of the spot confidence interval so wide intervals
+    // don't overpay liquidators
+    let price = oracle_price.price;
+    let eligibility_price = oracle_price.liquidation_check_price(vault_type);
+    let payout_price = oracle_price.liquidation_price(vault_type)?;
+
     // Calculate collateral value
-    let collateral_value = (position.collateral_amount as u128)
-        .checked_mul(price as u128)
-        .ok_or(AegisError::MathOverflow)?
-        .checked_div(1_000_000)
-        .ok_or(AegisError::MathOverflow)? as u64;
+    let collateral_value = valuation::collateral_value(
+        position.collateral_amount,
+        eligibility_price,
+        vault_type.collateral_decimals,
+    )?;
 
     // Calculate current LTV (debt / collateral_value)
     let current_ltv_bps = if collateral_value > 0 {
-        (position.debt_amount as u128)
+        (debt as u128)
             .checked_mul(10_000)
             .ok_or(AegisError::MathOverflow)?
             .checked_div(collateral_value as u128)
@@ -101,11 +154,11 @@ pub fn handler(ctx: Context<LiquidatePosition>, repay_amount: u64) -> Result<()>
     };
 
     // Calculate health factor before liquidation (for logging)
-    let health_factor_before = if position.debt_amount > 0 && collateral_value > 0 {
+    let health_factor_before = if debt > 0 && collateral_value > 0 {
         (collateral_value as u128)
             .checked_mul(10_000)
             .ok_or(AegisError::MathOverflow)?
-            .checked_div(position.debt_amount as u128)
+            .checked_div(debt as u128)
             .ok_or(AegisError::MathOverflow)?
             .checked_div(vault_type.liq_threshold_bps as u128)
             .ok_or(AegisError::MathOverflow)? as u64
@@ -119,43 +172,31 @@ pub fn handler(ctx: Context<LiquidatePosition>, repay_amount: u64) -> Result<()>
         AegisError::PositionHealthy
     );
 
-    // Limit repay to position debt
-    let actual_repay = repay_amount.min(position.debt_amount);
+    // Liquidation bonus, scaled by health when the vault type's bonus curve is set
+    let penalty_bps = liquidation_penalty_bps(vault_type, collateral_value, debt)?;
 
-    // Calculate total collateral with penalty
-    // Total = repay_amount * (1 + penalty_bps / 10000)
-    let liq_penalty_bps = vault_type.liq_penalty_bps;
-    
-    let repay_value_with_penalty = (actual_repay as u128)
-        .checked_mul(10_000 + liq_penalty_bps as u128)
-        .ok_or(AegisError::MathOverflow)?
-        .checked_div(10_000)
-        .ok_or(AegisError::MathOverflow)? as u64;
-
-    // Convert to collateral amount
-    let total_bonus_collateral = (repay_value_with_penalty as u128)
-        .checked_mul(1_000_000)
-        .ok_or(AegisError::MathOverflow)?
-        .checked_div(price as u128)
-        .ok_or(AegisError::MathOverflow)? as u64;
-
-    // Ensure we don't transfer more collateral than available
-    let total_collateral_to_transfer = total_bonus_collateral.min(position.collateral_amount);
-
-    // Split penalty between liquidator and protocol (50/50)
-    // Liquidator gets: repay_value + half of penalty
-    // Protocol gets: half of penalty
-    let base_collateral = (actual_repay as u128)
-        .checked_mul(1_000_000)
-        .ok_or(AegisError::MathOverflow)?
-        .checked_div(price as u128)
-        .ok_or(AegisError::MathOverflow)? as u64;
-
-    let penalty_collateral = total_collateral_to_transfer.saturating_sub(base_collateral);
-    let protocol_fee_collateral = penalty_collateral / 2; // 50% to protocol
-    let liquidator_bonus = penalty_collateral.saturating_sub(protocol_fee_collateral); // 50% to liquidator
-    
-    let liquidator_collateral = base_collateral.checked_add(liquidator_bonus).ok_or(AegisError::MathOverflow)?;
+    // Limit repay by the close factor and target health, unless the position is
+    // dust or deeply underwater
+    let max_repay = max_liquidation_repay(
+        vault_type,
+        debt,
+        collateral_value,
+        penalty_bps,
+    )?;
+    // Capped to the position's collateral, with the repay scaled down to match, so the
+    // liquidator is never paid out of other depositors' collateral
+    let seizure = liquidation_seizure(
+        vault_type,
+        repay_amount.min(max_repay),
+        position.collateral_amount,
+        payout_price,
+        penalty_bps,
+    )?;
+    let actual_repay = seizure.repay;
+    let total_collateral_to_transfer = seizure.collateral;
+    let protocol_fee_collateral = seizure.protocol_fee;
+    let liquidator_collateral = seizure.liquidator_collateral();
+    let complete = seizure.is_complete(max_repay, position.collateral_amount);
 
     // Burn stablecoin from liquidator
     let cpi_accounts = Burn {
@@ -196,31 +237,57 @@ pub fn handler(ctx: Context<LiquidatePosition>, repay_amount: u64) -> Result<()>
         token::transfer(cpi_ctx_treasury, protocol_fee_collateral)?;
 
         // Update fee tracking (convert collateral to USD value)
-        let fee_value_usd = (protocol_fee_collateral as u128)
-            .checked_mul(price as u128)
-            .ok_or(AegisError::MathOverflow)?
-            .checked_div(1_000_000)
-            .ok_or(AegisError::MathOverflow)? as u64;
+        let fee_value_usd = valuation::collateral_value(
+            protocol_fee_collateral,
+            price,
+            vault_type.collateral_decimals,
+        )?;
 
         protocol_state.total_liquidation_fees_collected = protocol_state.total_liquidation_fees_collected
             .checked_add(fee_value_usd)
             .ok_or(AegisError::MathOverflow)?;
     }
 
+    // Flat keeper reward for small positions, from the surplus buffer then the treasury allowance
+    let allowance = treasury_allowance(
+        &ctx.accounts.treasury_stablecoin_account,
+        &ctx.accounts.mint_authority.key(),
+    );
+    let (reward_from_surplus, reward_from_treasury) =
+        keeper_reward_sources(protocol_state, vault_type, debt, complete, allowance);
+    let mint_seeds = &[
+        b"mint_authority".as_ref(),
+        &[protocol_state.mint_authority_bump],
+    ];
+    let mint_signer = &[&mint_seeds[..]];
+
+    if reward_from_surplus > 0 {
+        let cpi_accounts = MintTo {
+            mint: ctx.accounts.stablecoin_mint.to_account_info(),
+            to: ctx.accounts.liquidator_stablecoin_account.to_account_info(),
+            authority: ctx.accounts.mint_authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, mint_signer);
+        token::mint_to(cpi_ctx, reward_from_surplus)?;
+    }
+    if reward_from_treasury > 0 {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.treasury_stablecoin_account.to_account_info(),
+            to: ctx.accounts.liquidator_stablecoin_account.to_account_info(),
+            authority: ctx.accounts.mint_authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, mint_signer);
+        token::transfer(cpi_ctx, reward_from_treasury)?;
+    }
+
     // Update position
-    position.debt_amount = position.debt_amount
-        .checked_sub(actual_repay)
-        .ok_or(AegisError::MathOverflow)?;
-    position.collateral_amount = position.collateral_amount
-        .checked_sub(total_collateral_to_transfer)
-        .ok_or(AegisError::MathOverflow)?;
-    position.updated_at = Clock::get()?.unix_timestamp;
-
-    // Update protocol state
-    protocol_state.total_protocol_debt = protocol_state.total_protocol_debt
-        .checked_sub(actual_repay)
-        .ok_or(AegisError::MathOverflow)?;
-    protocol_state.updated_at = Clock::get()?.unix_timestamp;
+    remove_position_debt(position, vault_type, actual_repay)?;
+    remove_position_collateral(position, vault_type, total_collateral_to_transfer)?;
+    position.updated_at = clock.unix_timestamp;
+
+    // Update protocol state (saturating: per-position rounding can leave dust)
+    protocol_state.total_protocol_debt = protocol_state.total_protocol_debt.saturating_sub(actual_repay);
+    protocol_state.updated_at = clock.unix_timestamp;
 
     // Capture values for logging (after mutable borrows are done)
     let position_owner = position.owner;
@@ -251,6 +318,14 @@ pub fn handler(ctx: Context<LiquidatePosition>, repay_amount: u64) -> Result<()>
         liquidator_collateral,
         protocol_fee_collateral
     );
+    if reward_from_surplus + reward_from_treasury > 0 {
+        msg!("KeeperRewardPaid: keeper={} position={} surplus={} treasury={}",
+            liquidator_key,
+            position_key,
+            reward_from_surplus,
+            reward_from_treasury
+        );
+    }
     
     Ok(())
 }
@@ -0,0 +1,300 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Mint, Burn, MintTo, Transfer};
+use crate::state::{Position, VaultType, ProtocolState, StabilityPool};
+use crate::constants::seeds::{POSITION_SEED, STABILITY_POOL_SEED};
+use crate::constants::liquidation::LIQUIDATION_MODE_FIXED_DISCOUNT;
+use crate::utils::oracle::get_liquidation_oracle_price;
+use crate::utils::interest::{accrue_interest, position_debt, remove_position_debt};
+use crate::utils::redistribution::{apply_pending_redistribution, remove_position_collateral};
+use crate::errors::codes::AegisError;
+use crate::utils::valuation;
+use crate::utils::circuit_breaker::observe_price;
+use crate::utils::price_history::record_price_observation;
+use crate::utils::liquidation::{is_liquidatable, liquidation_penalty_bps, liquidation_seizure, max_liquidation_repay};
+use crate::utils::stability_pool::offset;
+use crate::utils::solvency::{keeper_reward_sources, treasury_allowance};
+
+/// Permissionless: cancel a liquidatable position's debt against stability pool deposits.
+/// The seized collateral stays in the vault and is owed to the pool's depositors, except
+/// the protocol's share of the penalty, which goes to the treasury.
+#[derive(Accounts)]
+pub struct LiquidateWithStabilityPool<'info> {
+    #[account(
+        mut,
+        seeds = [
+            POSITION_SEED,
+            position.owner.as_ref(),
+            vault_type.key().as_ref()
+        ],
+        bump,
+        has_one = vault_type
+    )]
+    pub position: Account<'info, Position>,
+
+    #[account(mut)]
+    pub vault_type: Account<'info, VaultType>,
+
+    #[account(mut)]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    #[account(
+        mut,
+        seeds = [STABILITY_POOL_SEED, vault_type.key().as_ref()],
+        bump = stability_pool.bump,
+        has_one = vault_type
+    )]
+    pub stability_pool: Box<Account<'info, StabilityPool>>,
+
+    #[account(
+        mut,
+        constraint = stablecoin_mint.key() == protocol_state.stablecoin_mint @ AegisError::Unauthorized
+    )]
+    pub stablecoin_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = pool_stablecoin_account.mint == protocol_state.stablecoin_mint @ AegisError::Unauthorized,
+        constraint = pool_stablecoin_account.owner == stability_pool.key() @ AegisError::Unauthorized
+    )]
+    pub pool_stablecoin_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = vault_collateral_account.mint == vault_type.collateral_mint,
+        constraint = vault_collateral_account.owner == vault_authority.key()
+    )]
+    pub vault_collateral_account: Account<'info, TokenAccount>,
+
+    /// Treasury collateral account to receive protocol's share of penalty
+    #[account(
+        mut,
+        constraint = treasury_collateral_account.mint == vault_type.collateral_mint,
+        constraint = treasury_collateral_account.owner == protocol_state.treasury_pubkey @ AegisError::Unauthorized
+    )]
+    pub treasury_collateral_account: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA that has authority over vault collateral
+    #[account(
+        seeds = [b"vault_authority", vault_type.key().as_ref()],
+        bump = vault_type.vault_authority_bump
+    )]
+    pub vault_authority: AccountInfo<'info>,
+
+    /// CHECK: Validated by Pyth SDK, or as the vault type's PriceFeed
+    #[account(
+        constraint = oracle_price_account.key() == vault_type.oracle_price_account @ AegisError::InvalidOracleAccount
+    )]
+    pub oracle_price_account: AccountInfo<'info>,
+
+    /// Keeper's stablecoin account, receives the keeper reward
+    #[account(
+        mut,
+        constraint = keeper_stablecoin_account.mint == protocol_state.stablecoin_mint @ AegisError::Unauthorized
+    )]
+    pub keeper_stablecoin_account: Account<'info, TokenAccount>,
+
+    /// Treasury stablecoin account, pays keeper rewards from its delegated allowance
+    #[account(
+        mut,
+        constraint = treasury_stablecoin_account.key() == protocol_state.treasury_ata @ AegisError::Unauthorized
+    )]
+    pub treasury_stablecoin_account: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA that has mint authority, and the treasury allowance delegate
+    #[account(
+        seeds = [b"mint_authority"],
+        bump = protocol_state.mint_authority_bump
+    )]
+    pub mint_authority: AccountInfo<'info>,
+
+    pub keeper: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub(crate) fn handler(ctx: Context<LiquidateWithStabilityPool>) -> Result<()> {
+    let position = &mut ctx.accounts.position;
+    let vault_type = &mut ctx.accounts.vault_type;
+    let protocol_state = &mut ctx.accounts.protocol_state;
+    let stability_pool = &mut ctx.accounts.stability_pool;
+    let clock = Clock::get()?;
+
+    require!(!protocol_state.is_protocol_paused, AegisError::ProtocolPaused);
+
+    // The pool takes collateral at the fixed discount; auction-mode vault types
+    // liquidate through start_liquidation_auction
+    require!(
+        vault_type.liquidation_mode == LIQUIDATION_MODE_FIXED_DISCOUNT,
+        AegisError::LiquidationModeMismatch
+    );
+
+    // Accrue stability fees before the health check
+    accrue_interest(vault_type, protocol_state, clock.unix_timestamp)?;
+    apply_pending_redistribution(position, vault_type)?;
+    let debt = position_debt(position, vault_type)?;
+
+    let oracle_price = get_liquidation_oracle_price(
+        &ctx.accounts.oracle_price_account,
+        ctx.remaining_accounts,
+        vault_type,
+        protocol_state,
+    )?;
+
+    // An abnormal move trips the circuit breaker without blocking the liquidation;
+    // only accepted prices go into the price history
+    if observe_price(vault_type, oracle_price.price, oracle_price.source, clock.unix_timestamp)? {
+        record_price_observation(ctx.remaining_accounts, vault_type, &oracle_price, clock.unix_timestamp)?;
+    }
+
+    let eligibility_price = oracle_price.liquidation_check_price(vault_type);
+    let payout_price = oracle_price.liquidation_price(vault_type)?;
+
+    let collateral_value = valuation::collateral_value(
+        position.collateral_amount,
+        eligibility_price,
+        vault_type.collateral_decimals,
+    )?;
+    require!(
+        is_liquidatable(collateral_value, debt, vault_type.liq_threshold_bps)?,
+        AegisError::PositionHealthy
+    );
+
+    // Liquidation bonus, scaled by health when the vault type's bonus curve is set
+    let penalty_bps = liquidation_penalty_bps(vault_type, collateral_value, debt)?;
+
+    // Same close factor and target health bounds as other liquidations, limited by the pool
+    let max_repay = max_liquidation_repay(
+        vault_type,
+        debt,
+        collateral_value,
+        penalty_bps,
+    )?;
+    let repay = max_repay.min(stability_pool.total_deposits);
+    require!(repay > 0, AegisError::StabilityPoolEmpty);
+
+    // Depositors receive collateral worth the debt plus the liquidation penalty, less the
+    // protocol's share of the penalty; when the position can't cover that the repay shrinks
+    // with it, so depositors never pay more AGSUSD than the collateral they get is worth
+    let seizure = liquidation_seizure(
+        vault_type,
+        repay,
+        position.collateral_amount,
+        payout_price,
+        penalty_bps,
+    )?;
+    let repay = seizure.repay;
+    let seized = seizure.collateral;
+    let depositor_collateral = seizure.liquidator_collateral();
+    let complete = seizure.is_complete(max_repay, position.collateral_amount);
+    require!(repay > 0, AegisError::InvalidAmount);
+
+    // Burn the pool's AGSUSD against the debt
+    let vault_type_key = vault_type.key();
+    let seeds = &[
+        STABILITY_POOL_SEED,
+        vault_type_key.as_ref(),
+        &[stability_pool.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let cpi_accounts = Burn {
+        mint: ctx.accounts.stablecoin_mint.to_account_info(),
+        from: ctx.accounts.pool_stablecoin_account.to_account_info(),
+        authority: stability_pool.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer);
+    token::burn(cpi_ctx, repay)?;
+
+    offset(stability_pool, repay, depositor_collateral)?;
+    stability_pool.updated_at = clock.unix_timestamp;
+
+    // Transfer protocol fee to treasury
+    if seizure.protocol_fee > 0 {
+        let vault_seeds = &[
+            b"vault_authority".as_ref(),
+            vault_type_key.as_ref(),
+            &[vault_type.vault_authority_bump],
+        ];
+        let vault_signer = &[&vault_seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.vault_collateral_account.to_account_info(),
+            to: ctx.accounts.treasury_collateral_account.to_account_info(),
+            authority: ctx.accounts.vault_authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, vault_signer);
+        token::transfer(cpi_ctx, seizure.protocol_fee)?;
+
+        // Update fee tracking (convert collateral to USD value)
+        let fee_value_usd = valuation::collateral_value(
+            seizure.protocol_fee,
+            oracle_price.price,
+            vault_type.collateral_decimals,
+        )?;
+        protocol_state.total_liquidation_fees_collected = protocol_state.total_liquidation_fees_collected
+            .checked_add(fee_value_usd)
+            .ok_or(AegisError::MathOverflow)?;
+    }
+
+    // Flat keeper reward for small positions, from the surplus buffer then the treasury allowance
+    let allowance = treasury_allowance(
+        &ctx.accounts.treasury_stablecoin_account,
+        &ctx.accounts.mint_authority.key(),
+    );
+    let (reward_from_surplus, reward_from_treasury) =
+        keeper_reward_sources(protocol_state, vault_type, debt, complete, allowance);
+    let mint_seeds = &[
+        b"mint_authority".as_ref(),
+        &[protocol_state.mint_authority_bump],
+    ];
+    let mint_signer = &[&mint_seeds[..]];
+
+    if reward_from_surplus > 0 {
+        let cpi_accounts = MintTo {
+            mint: ctx.accounts.stablecoin_mint.to_account_info(),
+            to: ctx.accounts.keeper_stablecoin_account.to_account_info(),
+            authority: ctx.accounts.mint_authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, mint_signer);
+        token::mint_to(cpi_ctx, reward_from_surplus)?;
+    }
+    if reward_from_treasury > 0 {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.treasury_stablecoin_account.to_account_info(),
+            to: ctx.accounts.keeper_stablecoin_account.to_account_info(),
+            authority: ctx.accounts.mint_authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, mint_signer);
+        token::transfer(cpi_ctx, reward_from_treasury)?;
+    }
+
+    // Update position
+    remove_position_debt(position, vault_type, repay)?;
+    remove_position_collateral(position, vault_type, seized)?;
+    position.updated_at = clock.unix_timestamp;
+
+    // Update protocol state (saturating: per-position rounding can leave dust)
+    protocol_state.total_protocol_debt = protocol_state.total_protocol_debt.saturating_sub(repay);
+    protocol_state.updated_at = clock.unix_timestamp;
+
+    msg!("StabilityPoolLiquidation: keeper={} owner={} position={} vault={} debt={} collateral={} protocol_fee={} price={} pool_deposits={}",
+        ctx.accounts.keeper.key(),
+        position.owner,
+        position.key(),
+        vault_type_key,
+        repay,
+        seized,
+        seizure.protocol_fee,
+        oracle_price.price,
+        stability_pool.total_deposits
+    );
+    if reward_from_surplus + reward_from_treasury > 0 {
+        msg!("KeeperRewardPaid: keeper={} position={} surplus={} treasury={}",
+            ctx.accounts.keeper.key(),
+            position.key(),
+            reward_from_surplus,
+            reward_from_treasury
+        );
+    }
+    Ok(())
+}
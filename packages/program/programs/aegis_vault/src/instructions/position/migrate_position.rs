@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::state::{Position, VaultType};
+use crate::errors::codes::AegisError;
+use crate::utils::migration::grow_account;
+use crate::utils::redistribution::track_migrated_position;
+
+/// Grow a position created with the original layout to the current one, and add it to
+/// its vault type's collateral, stake and normalized debt totals. Permissionless; run it
+/// for every position of a vault type right after migrate_vault_type.
+#[derive(Accounts)]
+pub struct MigratePosition<'info> {
+    /// CHECK: Owner, discriminator and size are checked by grow_account
+    #[account(mut)]
+    pub position: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub vault_type: Account<'info, VaultType>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<MigratePosition>) -> Result<()> {
+    let position_info = ctx.accounts.position.to_account_info();
+    grow_account::<Position>(
+        &position_info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        Position::LEN,
+    )?;
+
+    let mut position = Position::try_deserialize(&mut &position_info.try_borrow_data()?[..])?;
+    let vault_type = &mut ctx.accounts.vault_type;
+    require!(position.vault_type == vault_type.key(), AegisError::Unauthorized);
+
+    // The original debt was not normalized; it is normalized at the current rate
+    track_migrated_position(&mut position, vault_type)?;
+    position.try_serialize(&mut &mut position_info.try_borrow_mut_data()?[..])?;
+
+    msg!("PositionMigrated: position={} owner={} vault={} collateral={} debt={} stake={}",
+        position_info.key(),
+        position.owner,
+        vault_type.key(),
+        position.collateral_amount,
+        position.debt_amount,
+        position.stake
+    );
+    Ok(())
+}
@@ -1,9 +1,14 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Mint, MintTo, Transfer};
+use anchor_spl::token::{self, Token, TokenAccount, Mint, MintTo};
 use crate::state::{Position, VaultType, ProtocolState};
 use crate::constants::seeds::POSITION_SEED;
 use crate::utils::oracle::get_oracle_price;
+use crate::utils::interest::{accrue_interest, add_position_debt, position_debt};
+use crate::utils::redistribution::apply_pending_redistribution;
 use crate::errors::codes::AegisError;
+use crate::utils::valuation;
+use crate::utils::circuit_breaker::accept_price;
+use crate::utils::price_history::record_price_observation;
 
 #[derive(Accounts)]
 pub struct MintStablecoin<'info> {
@@ -20,6 +25,7 @@ pub struct MintStablecoin<'info> {
     )]
     pub position: Account<'info, Position>,
 
+    #[account(mut)]
     pub vault_type: Account<'info, VaultType>,
 
     #[account(mut)]
@@ -45,7 +51,10 @@ pub struct MintStablecoin<'info> {
     /// CHECK: PDA that has mint authority
     pub mint_authority: AccountInfo<'info>,
 
-    /// CHECK: Validated by Pyth SDK
+    /// CHECK: Validated by Pyth SDK, or as the vault type's PriceFeed
+    #[account(
+        constraint = oracle_price_account.key() == vault_type.oracle_price_account @ AegisError::InvalidOracleAccount
+    )]
     pub oracle_price_account: AccountInfo<'info>,
 
     pub owner: Signer<'info>,
@@ -53,29 +62,44 @@ pub struct MintStablecoin<'info> {
     pub token_program: Program<'info, Token>,
 }
 
-pub fn handler(ctx: Context<MintStablecoin>, amount: u64) -> Result<()> {
+pub(crate) fn handler(ctx: Context<MintStablecoin>, amount: u64) -> Result<()> {
     require!(amount > 0, AegisError::InvalidAmount);
 
     let position = &mut ctx.accounts.position;
-    let vault_type = &ctx.accounts.vault_type;
+    let vault_type = &mut ctx.accounts.vault_type;
     let protocol_state = &mut ctx.accounts.protocol_state;
+    let clock = Clock::get()?;
 
     // Check if minting is paused
     require!(!protocol_state.is_mint_paused, AegisError::MintPaused);
     require!(!protocol_state.is_protocol_paused, AegisError::ProtocolPaused);
+    require!(!vault_type.is_mint_paused, AegisError::VaultMintPaused);
+
+    // Accrue stability fees and apply redistributions before the health check
+    accrue_interest(vault_type, protocol_state, clock.unix_timestamp)?;
+    apply_pending_redistribution(position, vault_type)?;
 
     // Get oracle price (with staleness and TTL checks)
-    let price = get_oracle_price(
+    let oracle_price = get_oracle_price(
         &ctx.accounts.oracle_price_account,
+        ctx.remaining_accounts,
+        vault_type,
         protocol_state,
     )?;
 
+    // Reject abnormal moves from the last accepted price, then record it in the price history
+    accept_price(vault_type, &oracle_price, clock.unix_timestamp)?;
+    record_price_observation(ctx.remaining_accounts, vault_type, &oracle_price, clock.unix_timestamp)?;
+
+    // Value collateral at the low end of the confidence interval
+    let price = oracle_price.collateral_price(vault_type)?;
+
     // Calculate collateral value in USD (6 decimals)
-    let collateral_value = (position.collateral_amount as u128)
-        .checked_mul(price as u128)
-        .ok_or(AegisError::MathOverflow)?
-        .checked_div(1_000_000) // Normalize to 6 decimals
-        .ok_or(AegisError::MathOverflow)? as u64;
+    let collateral_value = valuation::collateral_value(
+        position.collateral_amount,
+        price,
+        vault_type.collateral_decimals,
+    )?;
 
     // Calculate max borrow based on LTV
     let max_borrow = (collateral_value as u128)
@@ -101,7 +125,7 @@ pub fn handler(ctx: Context<MintStablecoin>, amount: u64) -> Result<()> {
     let net_amount = amount.checked_sub(fee_amount).ok_or(AegisError::MathOverflow)?;
 
     // Calculate new debt (user owes the full amount, not net amount)
-    let new_debt = position.debt_amount
+    let new_debt = position_debt(position, vault_type)?
         .checked_add(amount)
         .ok_or(AegisError::MathOverflow)?;
 
@@ -157,12 +181,12 @@ pub fn handler(ctx: Context<MintStablecoin>, amount: u64) -> Result<()> {
     }
 
     // Update position
-    position.debt_amount = new_debt;
-    position.updated_at = Clock::get()?.unix_timestamp;
+    add_position_debt(position, vault_type, amount)?;
+    position.updated_at = clock.unix_timestamp;
 
     // Update protocol state
     protocol_state.total_protocol_debt = new_global_debt;
-    protocol_state.updated_at = Clock::get()?.unix_timestamp;
+    protocol_state.updated_at = clock.unix_timestamp;
 
     // Emit fee collection log for indexer
     msg!("MintFeeCollected: amount={} user={} vault={} net={}", 
@@ -4,6 +4,15 @@ pub mod mint_stablecoin;
 pub mod repay_stablecoin;
 pub mod withdraw_collateral;
 pub mod liquidate_position;
+pub mod start_liquidation_auction;
+pub mod bid_liquidation_auction;
+pub mod settle_liquidation_auction;
+pub mod liquidate_with_stability_pool;
+pub mod flash_liquidate;
+pub mod liquidate_batch;
+pub mod redistribute_position;
+pub mod write_off_bad_debt;
+pub mod migrate_position;
 
 pub use open_position::*;
 pub use deposit_collateral::*;
@@ -11,3 +20,12 @@ pub use mint_stablecoin::*;
 pub use repay_stablecoin::*;
 pub use withdraw_collateral::*;
 pub use liquidate_position::*;
+pub use start_liquidation_auction::*;
+pub use bid_liquidation_auction::*;
+pub use settle_liquidation_auction::*;
+pub use liquidate_with_stability_pool::*;
+pub use flash_liquidate::*;
+pub use liquidate_batch::*;
+pub use redistribute_position::*;
+pub use write_off_bad_debt::*;
+pub use migrate_position::*;
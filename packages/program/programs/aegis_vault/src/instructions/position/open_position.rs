@@ -25,7 +25,7 @@ pub struct OpenPosition<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<OpenPosition>) -> Result<()> {
+pub(crate) fn handler(ctx: Context<OpenPosition>) -> Result<()> {
     let position = &mut ctx.accounts.position;
     let clock = Clock::get()?;
 
@@ -33,6 +33,10 @@ pub fn handler(ctx: Context<OpenPosition>) -> Result<()> {
     position.vault_type = ctx.accounts.vault_type.key();
     position.collateral_amount = 0;
     position.debt_amount = 0;
+    position.stake = 0;
+    // Only redistributions from now on apply to this position
+    position.l_coll_snapshot = ctx.accounts.vault_type.l_coll;
+    position.l_debt_snapshot = ctx.accounts.vault_type.l_debt;
     position.created_at = clock.unix_timestamp;
     position.updated_at = clock.unix_timestamp;
 
@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use crate::state::{Position, VaultType, ProtocolState};
+use crate::constants::seeds::{POSITION_SEED, STABILITY_POOL_SEED};
+use crate::constants::liquidation::LIQUIDATION_MODE_FIXED_DISCOUNT;
+use crate::errors::codes::AegisError;
+use crate::utils::oracle::get_liquidation_oracle_price;
+use crate::utils::interest::{accrue_interest, position_debt};
+use crate::utils::valuation;
+use crate::utils::circuit_breaker::observe_price;
+use crate::utils::price_history::record_price_observation;
+use crate::utils::redistribution::{apply_pending_redistribution, redistribute};
+use crate::utils::stability_pool::stability_pool_deposits;
+
+/// Permissionless fallback for a position under water, which no liquidator will take at a
+/// loss and the stability pool doesn't hold enough to absorb: its collateral and debt are
+/// spread pro-rata over the other positions of the vault type and applied to each on its
+/// next touch.
+#[derive(Accounts)]
+pub struct RedistributePosition<'info> {
+    #[account(
+        mut,
+        seeds = [
+            POSITION_SEED,
+            position.owner.as_ref(),
+            vault_type.key().as_ref()
+        ],
+        bump,
+        has_one = vault_type
+    )]
+    pub position: Account<'info, Position>,
+
+    #[account(mut)]
+    pub vault_type: Account<'info, VaultType>,
+
+    #[account(mut)]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    /// CHECK: The vault type's stability pool, read by stability_pool_deposits; it may not
+    /// be initialized
+    #[account(
+        seeds = [STABILITY_POOL_SEED, vault_type.key().as_ref()],
+        bump
+    )]
+    pub stability_pool: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by Pyth SDK, or as the vault type's PriceFeed
+    #[account(
+        constraint = oracle_price_account.key() == vault_type.oracle_price_account @ AegisError::InvalidOracleAccount
+    )]
+    pub oracle_price_account: AccountInfo<'info>,
+
+    pub keeper: Signer<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<RedistributePosition>) -> Result<()> {
+    let position = &mut ctx.accounts.position;
+    let vault_type = &mut ctx.accounts.vault_type;
+    let protocol_state = &mut ctx.accounts.protocol_state;
+    let clock = Clock::get()?;
+
+    require!(!protocol_state.is_protocol_paused, AegisError::ProtocolPaused);
+
+    // Accrue stability fees so the redistributed debt includes interest
+    accrue_interest(vault_type, protocol_state, clock.unix_timestamp)?;
+    apply_pending_redistribution(position, vault_type)?;
+    let debt = position_debt(position, vault_type)?;
+
+    let oracle_price = get_liquidation_oracle_price(
+        &ctx.accounts.oracle_price_account,
+        ctx.remaining_accounts,
+        vault_type,
+        protocol_state,
+    )?;
+
+    // An abnormal move trips the circuit breaker without blocking the redistribution;
+    // only accepted prices go into the price history
+    if observe_price(vault_type, oracle_price.price, oracle_price.source, clock.unix_timestamp)? {
+        record_price_observation(ctx.remaining_accounts, vault_type, &oracle_price, clock.unix_timestamp)?;
+    }
+
+    // Only positions whose collateral no longer covers the debt; a position with no
+    // collateral left is written off instead
+    let collateral_value = valuation::collateral_value(
+        position.collateral_amount,
+        oracle_price.liquidation_check_price(vault_type),
+        vault_type.collateral_decimals,
+    )?;
+    require!(
+        position.collateral_amount > 0 && collateral_value < debt,
+        AegisError::PositionNotUnderwater
+    );
+
+    // Debt the stability pool can absorb is not pushed onto other positions
+    if vault_type.liquidation_mode == LIQUIDATION_MODE_FIXED_DISCOUNT {
+        require!(
+            stability_pool_deposits(&ctx.accounts.stability_pool)? < debt,
+            AegisError::StabilityPoolCanAbsorb
+        );
+    }
+
+    let (collateral, normalized_debt) = redistribute(position, vault_type)?;
+    position.updated_at = clock.unix_timestamp;
+    vault_type.updated_at = clock.unix_timestamp;
+
+    msg!("PositionRedistributed: keeper={} owner={} position={} vault={} debt={} normalized_debt={} collateral={} price={} stakes={}",
+        ctx.accounts.keeper.key(),
+        position.owner,
+        position.key(),
+        vault_type.key(),
+        debt,
+        normalized_debt,
+        collateral,
+        oracle_price.price,
+        vault_type.total_stakes
+    );
+    Ok(())
+}
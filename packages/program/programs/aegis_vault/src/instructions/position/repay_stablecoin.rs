@@ -3,6 +3,8 @@ use anchor_spl::token::{self, Token, TokenAccount, Mint, Burn, Transfer};
 use crate::state::{Position, VaultType, ProtocolState};
 use crate::constants::seeds::POSITION_SEED;
 use crate::errors::codes::AegisError;
+use crate::utils::interest::{accrue_interest, position_debt, remove_position_debt};
+use crate::utils::redistribution::apply_pending_redistribution;
 
 #[derive(Accounts)]
 pub struct RepayStablecoin<'info> {
@@ -19,6 +21,7 @@ pub struct RepayStablecoin<'info> {
     )]
     pub position: Account<'info, Position>,
 
+    #[account(mut)]
     pub vault_type: Account<'info, VaultType>,
 
     #[account(mut)]
@@ -46,19 +49,24 @@ pub struct RepayStablecoin<'info> {
     pub token_program: Program<'info, Token>,
 }
 
-pub fn handler(ctx: Context<RepayStablecoin>, amount: u64) -> Result<()> {
+pub(crate) fn handler(ctx: Context<RepayStablecoin>, amount: u64) -> Result<()> {
     require!(amount > 0, AegisError::InvalidAmount);
 
     let position = &mut ctx.accounts.position;
-    let vault_type = &ctx.accounts.vault_type;
+    let vault_type = &mut ctx.accounts.vault_type;
     let protocol_state = &mut ctx.accounts.protocol_state;
+    let clock = Clock::get()?;
 
     // Check if redemption is paused
     require!(!protocol_state.is_redeem_paused, AegisError::RedeemPaused);
     require!(!protocol_state.is_protocol_paused, AegisError::ProtocolPaused);
 
+    // Accrue stability fees and apply redistributions so the debt includes both
+    accrue_interest(vault_type, protocol_state, clock.unix_timestamp)?;
+    apply_pending_redistribution(position, vault_type)?;
+
     // Check if repaying more than debt
-    require!(amount <= position.debt_amount, AegisError::InvalidAmount);
+    require!(amount <= position_debt(position, vault_type)?, AegisError::InvalidAmount);
 
     // Calculate redeem fee (using vault type fee, falling back to protocol default)
     let redeem_fee_bps = if vault_type.redeem_fee_bps > 0 {
@@ -110,16 +118,12 @@ pub fn handler(ctx: Context<RepayStablecoin>, amount: u64) -> Result<()> {
     token::burn(cpi_ctx, amount)?;
 
     // Update position
-    position.debt_amount = position.debt_amount
-        .checked_sub(amount)
-        .ok_or(AegisError::MathOverflow)?;
-    position.updated_at = Clock::get()?.unix_timestamp;
+    remove_position_debt(position, vault_type, amount)?;
+    position.updated_at = clock.unix_timestamp;
 
-    // Update protocol state
-    protocol_state.total_protocol_debt = protocol_state.total_protocol_debt
-        .checked_sub(amount)
-        .ok_or(AegisError::MathOverflow)?;
-    protocol_state.updated_at = Clock::get()?.unix_timestamp;
+    // Update protocol state (saturating: per-position rounding can leave dust)
+    protocol_state.total_protocol_debt = protocol_state.total_protocol_debt.saturating_sub(amount);
+    protocol_state.updated_at = clock.unix_timestamp;
 
     // Emit fee collection log for indexer
     msg!("RedeemFeeCollected: amount={} user={} vault={}", 
@@ -127,7 +131,7 @@ pub fn handler(ctx: Context<RepayStablecoin>, amount: u64) -> Result<()> {
         ctx.accounts.owner.key(), 
         vault_type.key()
     );
-    msg!("Repaid {} stablecoin, fee: {}, remaining debt: {}", amount, fee_amount, position.debt_amount);
+    msg!("Repaid {} stablecoin, fee: {}, remaining debt: {}", amount, fee_amount, position_debt(position, vault_type)?);
     
     Ok(())
 }
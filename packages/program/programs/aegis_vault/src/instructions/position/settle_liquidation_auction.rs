@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{LiquidationAuction, Position, VaultType, ProtocolState};
+use crate::constants::seeds::LIQUIDATION_AUCTION_SEED;
+use crate::errors::codes::AegisError;
+use crate::utils::valuation;
+use crate::utils::auction::{auction_price, is_auction_settleable};
+use crate::utils::solvency::record_bad_debt;
+use crate::utils::redistribution::{add_position_collateral, apply_pending_redistribution};
+
+/// Permissionless: close an auction that ran its duration, or whose remaining collateral
+/// can no longer be sold, without raising all of its debt. The unraised debt is recognized
+/// as bad debt; the treasury takes the collateral worth it at the auction price and any
+/// collateral beyond that goes back to the position.
+#[derive(Accounts)]
+pub struct SettleLiquidationAuction<'info> {
+    #[account(
+        mut,
+        seeds = [LIQUIDATION_AUCTION_SEED, auction.position.as_ref()],
+        bump = auction.bump,
+        has_one = vault_type,
+        has_one = position,
+        has_one = keeper,
+        close = keeper
+    )]
+    pub auction: Account<'info, LiquidationAuction>,
+
+    #[account(mut)]
+    pub position: Account<'info, Position>,
+
+    #[account(mut)]
+    pub vault_type: Account<'info, VaultType>,
+
+    #[account(mut)]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    #[account(
+        mut,
+        constraint = vault_collateral_account.mint == vault_type.collateral_mint,
+        constraint = vault_collateral_account.owner == vault_authority.key()
+    )]
+    pub vault_collateral_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = treasury_collateral_account.mint == vault_type.collateral_mint,
+        constraint = treasury_collateral_account.owner == protocol_state.treasury_pubkey @ AegisError::Unauthorized
+    )]
+    pub treasury_collateral_account: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA that has authority over vault collateral
+    #[account(
+        seeds = [b"vault_authority", vault_type.key().as_ref()],
+        bump = vault_type.vault_authority_bump
+    )]
+    pub vault_authority: AccountInfo<'info>,
+
+    /// CHECK: Keeper that started the auction, receives the rent back
+    #[account(mut)]
+    pub keeper: AccountInfo<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub(crate) fn handler(ctx: Context<SettleLiquidationAuction>) -> Result<()> {
+    let auction = &ctx.accounts.auction;
+    let vault_type = &mut ctx.accounts.vault_type;
+    let protocol_state = &mut ctx.accounts.protocol_state;
+    let clock = Clock::get()?;
+
+    require!(!protocol_state.is_protocol_paused, AegisError::ProtocolPaused);
+    require!(
+        is_auction_settleable(auction, clock.unix_timestamp, vault_type.collateral_decimals)?,
+        AegisError::AuctionStillRunning
+    );
+
+    // The treasury takes over the collateral backing the unraised debt at the auction price
+    let price = auction_price(auction, clock.unix_timestamp)?;
+    let backing = valuation::collateral_for_value(
+        auction.debt_remaining,
+        price,
+        vault_type.collateral_decimals,
+    )?;
+    let to_treasury = auction.collateral_amount.min(backing);
+    let surplus = auction.collateral_amount - to_treasury;
+
+    if to_treasury > 0 {
+        let vault_type_key = vault_type.key();
+        let seeds = &[
+            b"vault_authority".as_ref(),
+            vault_type_key.as_ref(),
+            &[vault_type.vault_authority_bump],
+        ];
+        let signer = &[&seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.vault_collateral_account.to_account_info(),
+            to: ctx.accounts.treasury_collateral_account.to_account_info(),
+            authority: ctx.accounts.vault_authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer);
+        token::transfer(cpi_ctx, to_treasury)?;
+    }
+
+    let position = &mut ctx.accounts.position;
+    apply_pending_redistribution(position, vault_type)?;
+    add_position_collateral(position, vault_type, surplus)?;
+    position.updated_at = clock.unix_timestamp;
+
+    let covered = record_bad_debt(protocol_state, auction.debt_remaining)?;
+    protocol_state.updated_at = clock.unix_timestamp;
+
+    msg!("LiquidationAuctionSettled: auction={} owner={} position={} surplus={} shortfall={} covered={} treasury_collateral={}",
+        auction.key(),
+        position.owner,
+        auction.position,
+        surplus,
+        auction.debt_remaining,
+        covered,
+        to_treasury
+    );
+    Ok(())
+}
@@ -0,0 +1,165 @@
+use anchor_lang::prelude::*;
+use crate::state::{LiquidationAuction, Position, VaultType, ProtocolState};
+use crate::constants::seeds::{LIQUIDATION_AUCTION_SEED, POSITION_SEED};
+use crate::constants::liquidation::LIQUIDATION_MODE_AUCTION;
+use crate::utils::oracle::get_liquidation_oracle_price;
+use crate::utils::interest::{accrue_interest, position_debt, remove_position_debt};
+use crate::utils::redistribution::{apply_pending_redistribution, remove_position_collateral};
+use crate::errors::codes::AegisError;
+use crate::utils::valuation;
+use crate::utils::circuit_breaker::observe_price;
+use crate::utils::price_history::record_price_observation;
+use crate::utils::liquidation::{is_liquidatable, liquidation_penalty_bps, max_liquidation_repay};
+
+#[derive(Accounts)]
+pub struct StartLiquidationAuction<'info> {
+    #[account(
+        init,
+        payer = keeper,
+        space = LiquidationAuction::LEN,
+        seeds = [LIQUIDATION_AUCTION_SEED, position.key().as_ref()],
+        bump
+    )]
+    pub auction: Account<'info, LiquidationAuction>,
+
+    #[account(
+        mut,
+        seeds = [
+            POSITION_SEED,
+            position.owner.as_ref(),
+            vault_type.key().as_ref()
+        ],
+        bump,
+        has_one = vault_type
+    )]
+    pub position: Account<'info, Position>,
+
+    #[account(mut)]
+    pub vault_type: Account<'info, VaultType>,
+
+    #[account(mut)]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    /// CHECK: Validated by Pyth SDK, or as the vault type's PriceFeed
+    #[account(
+        constraint = oracle_price_account.key() == vault_type.oracle_price_account @ AegisError::InvalidOracleAccount
+    )]
+    pub oracle_price_account: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<StartLiquidationAuction>) -> Result<()> {
+    let position = &mut ctx.accounts.position;
+    let vault_type = &mut ctx.accounts.vault_type;
+    let protocol_state = &mut ctx.accounts.protocol_state;
+    let clock = Clock::get()?;
+
+    require!(!protocol_state.is_protocol_paused, AegisError::ProtocolPaused);
+    require!(
+        vault_type.liquidation_mode == LIQUIDATION_MODE_AUCTION,
+        AegisError::LiquidationModeMismatch
+    );
+
+    // Accrue stability fees before the health check
+    accrue_interest(vault_type, protocol_state, clock.unix_timestamp)?;
+    apply_pending_redistribution(position, vault_type)?;
+    let debt = position_debt(position, vault_type)?;
+
+    let oracle_price = get_liquidation_oracle_price(
+        &ctx.accounts.oracle_price_account,
+        ctx.remaining_accounts,
+        vault_type,
+        protocol_state,
+    )?;
+
+    // An abnormal move trips the circuit breaker without blocking the liquidation;
+    // only accepted prices go into the price history
+    if observe_price(vault_type, oracle_price.price, oracle_price.source, clock.unix_timestamp)? {
+        record_price_observation(ctx.remaining_accounts, vault_type, &oracle_price, clock.unix_timestamp)?;
+    }
+
+    let eligibility_price = oracle_price.liquidation_check_price(vault_type);
+    let payout_price = oracle_price.liquidation_price(vault_type)?;
+
+    let collateral_value = valuation::collateral_value(
+        position.collateral_amount,
+        eligibility_price,
+        vault_type.collateral_decimals,
+    )?;
+    require!(
+        is_liquidatable(collateral_value, debt, vault_type.liq_threshold_bps)?,
+        AegisError::PositionHealthy
+    );
+
+    // Liquidation bonus, scaled by health when the vault type's bonus curve is set
+    let penalty_bps = liquidation_penalty_bps(vault_type, collateral_value, debt)?;
+
+    // Same close factor and target health bounds as fixed-discount liquidations
+    let auction_debt = max_liquidation_repay(
+        vault_type,
+        debt,
+        collateral_value,
+        penalty_bps,
+    )?;
+
+    // Seize collateral worth the debt plus the liquidation penalty; whatever the
+    // auction doesn't need to sell goes back to the position on settlement
+    let lot_value = (auction_debt as u128)
+        .checked_mul(10_000 + penalty_bps as u128)
+        .ok_or(AegisError::MathOverflow)?
+        .checked_div(10_000)
+        .ok_or(AegisError::MathOverflow)? as u64;
+    let lot = valuation::collateral_for_value(
+        lot_value,
+        payout_price,
+        vault_type.collateral_decimals,
+    )?
+    .min(position.collateral_amount);
+    // A position without collateral left is written off with write_off_bad_debt instead
+    require!(lot > 0, AegisError::InvalidAmount);
+
+    // Price curve from the premium down to the floor, both relative to the spot price
+    let start_price = (oracle_price.price as u128)
+        .checked_mul(vault_type.auction_start_premium_bps as u128)
+        .ok_or(AegisError::MathOverflow)?
+        .checked_div(10_000)
+        .ok_or(AegisError::MathOverflow)? as u64;
+    let floor_price = (oracle_price.price as u128)
+        .checked_mul(vault_type.auction_floor_bps as u128)
+        .ok_or(AegisError::MathOverflow)?
+        .checked_div(10_000)
+        .ok_or(AegisError::MathOverflow)? as u64;
+
+    // Move the debt and collateral out of the position
+    remove_position_debt(position, vault_type, auction_debt)?;
+    remove_position_collateral(position, vault_type, lot)?;
+    position.updated_at = clock.unix_timestamp;
+
+    let auction = &mut ctx.accounts.auction;
+    auction.vault_type = vault_type.key();
+    auction.position = position.key();
+    auction.keeper = ctx.accounts.keeper.key();
+    auction.collateral_amount = lot;
+    auction.debt_remaining = auction_debt;
+    auction.start_price = start_price;
+    auction.floor_price = floor_price;
+    auction.start_time = clock.unix_timestamp;
+    auction.duration_seconds = vault_type.auction_duration_seconds;
+    auction.bump = ctx.bumps.auction;
+
+    msg!("LiquidationAuctionStarted: auction={} owner={} position={} vault={} debt={} collateral={} start_price={} floor_price={}",
+        auction.key(),
+        position.owner,
+        auction.position,
+        auction.vault_type,
+        auction_debt,
+        lot,
+        start_price,
+        floor_price
+    );
+    Ok(())
+}
@@ -3,7 +3,12 @@ use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 use crate::state::{Position, VaultType, ProtocolState};
 use crate::constants::seeds::POSITION_SEED;
 use crate::utils::oracle::get_oracle_price;
+use crate::utils::interest::{accrue_interest, position_debt};
+use crate::utils::redistribution::{apply_pending_redistribution, remove_position_collateral};
 use crate::errors::codes::AegisError;
+use crate::utils::valuation;
+use crate::utils::circuit_breaker::accept_price;
+use crate::utils::price_history::record_price_observation;
 
 #[derive(Accounts)]
 pub struct WithdrawCollateral<'info> {
@@ -20,8 +25,10 @@ pub struct WithdrawCollateral<'info> {
     )]
     pub position: Account<'info, Position>,
 
+    #[account(mut)]
     pub vault_type: Account<'info, VaultType>,
 
+    #[account(mut)]
     pub protocol_state: Account<'info, ProtocolState>,
 
     #[account(mut)]
@@ -41,7 +48,10 @@ pub struct WithdrawCollateral<'info> {
     )]
     pub vault_authority: AccountInfo<'info>,
 
-    /// CHECK: Validated by Pyth SDK
+    /// CHECK: Validated by Pyth SDK, or as the vault type's PriceFeed
+    #[account(
+        constraint = oracle_price_account.key() == vault_type.oracle_price_account @ AegisError::InvalidOracleAccount
+    )]
     pub oracle_price_account: AccountInfo<'info>,
 
     pub owner: Signer<'info>,
@@ -49,31 +59,46 @@ pub struct WithdrawCollateral<'info> {
     pub token_program: Program<'info, Token>,
 }
 
-pub fn handler(ctx: Context<WithdrawCollateral>, amount: u64) -> Result<()> {
+pub(crate) fn handler(ctx: Context<WithdrawCollateral>, amount: u64) -> Result<()> {
     require!(amount > 0, AegisError::InvalidAmount);
 
     let position = &mut ctx.accounts.position;
-    let vault_type = &ctx.accounts.vault_type;
+    let vault_type = &mut ctx.accounts.vault_type;
+    let protocol_state = &mut ctx.accounts.protocol_state;
+    let clock = Clock::get()?;
+
+    // Accrue stability fees and apply redistributions before the health check
+    accrue_interest(vault_type, protocol_state, clock.unix_timestamp)?;
+    apply_pending_redistribution(position, vault_type)?;
 
-   // Check if withdrawing more than deposited
+    // Check if withdrawing more than deposited
     require!(amount <= position.collateral_amount, AegisError::InsufficientCollateral);
 
     // Get oracle price
-    let price = get_oracle_price(
+    let oracle_price = get_oracle_price(
         &ctx.accounts.oracle_price_account,
-        &ctx.accounts.protocol_state,
+        ctx.remaining_accounts,
+        vault_type,
+        protocol_state,
     )?;
 
+    // Reject abnormal moves from the last accepted price, then record it in the price history
+    accept_price(vault_type, &oracle_price, clock.unix_timestamp)?;
+    record_price_observation(ctx.remaining_accounts, vault_type, &oracle_price, clock.unix_timestamp)?;
+
+    // Value remaining collateral at the low end of the confidence interval
+    let price = oracle_price.collateral_price(vault_type)?;
+
     // Calculate post-withdrawal collateral value
     let remaining_collateral = position.collateral_amount
         .checked_sub(amount)
         .ok_or(AegisError::MathOverflow)?;
 
-    let remaining_collateral_value = (remaining_collateral as u128)
-        .checked_mul(price as u128)
-        .ok_or(AegisError::MathOverflow)?
-        .checked_div(1_000_000)
-        .ok_or(AegisError::MathOverflow)? as u64;
+    let remaining_collateral_value = valuation::collateral_value(
+        remaining_collateral,
+        price,
+        vault_type.collateral_decimals,
+    )?;
 
     // Calculate max borrow after withdrawal
     let max_borrow_after = (remaining_collateral_value as u128)
@@ -84,7 +109,7 @@ pub fn handler(ctx: Context<WithdrawCollateral>, amount: u64) -> Result<()> {
 
     // Ensure position remains healthy
     require!(
-        position.debt_amount <= max_borrow_after,
+        position_debt(position, vault_type)? <= max_borrow_after,
         AegisError::ExceedsLTV
     );
 
@@ -107,8 +132,8 @@ pub fn handler(ctx: Context<WithdrawCollateral>, amount: u64) -> Result<()> {
     token::transfer(cpi_ctx, amount)?;
 
     // Update position
-    position.collateral_amount = remaining_collateral;
-    position.updated_at = Clock::get()?.unix_timestamp;
+    remove_position_collateral(position, vault_type, amount)?;
+    position.updated_at = clock.unix_timestamp;
 
     msg!("Withdrew {} collateral", amount);
     Ok(())
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use crate::state::{Position, VaultType, ProtocolState};
+use crate::constants::seeds::POSITION_SEED;
+use crate::errors::codes::AegisError;
+use crate::utils::interest::{accrue_interest, position_debt, remove_position_debt};
+use crate::utils::redistribution::apply_pending_redistribution;
+use crate::utils::solvency::record_bad_debt;
+
+/// Permissionless: write off the debt left on a position whose collateral is all gone.
+/// The surplus buffer absorbs it first; the rest is recorded as protocol deficit.
+#[derive(Accounts)]
+pub struct WriteOffBadDebt<'info> {
+    #[account(
+        mut,
+        seeds = [
+            POSITION_SEED,
+            position.owner.as_ref(),
+            vault_type.key().as_ref()
+        ],
+        bump,
+        has_one = vault_type
+    )]
+    pub position: Account<'info, Position>,
+
+    #[account(mut)]
+    pub vault_type: Account<'info, VaultType>,
+
+    #[account(mut)]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    pub keeper: Signer<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<WriteOffBadDebt>) -> Result<()> {
+    let position = &mut ctx.accounts.position;
+    let vault_type = &mut ctx.accounts.vault_type;
+    let protocol_state = &mut ctx.accounts.protocol_state;
+    let clock = Clock::get()?;
+
+    // Include the interest accrued up to now in the written off debt, and any
+    // redistributed collateral that makes the position solvent again
+    accrue_interest(vault_type, protocol_state, clock.unix_timestamp)?;
+    apply_pending_redistribution(position, vault_type)?;
+    let debt = position_debt(position, vault_type)?;
+    require!(
+        position.collateral_amount == 0 && debt > 0,
+        AegisError::PositionNotInsolvent
+    );
+
+    remove_position_debt(position, vault_type, debt)?;
+    position.updated_at = clock.unix_timestamp;
+
+    let covered = record_bad_debt(protocol_state, debt)?;
+    protocol_state.updated_at = clock.unix_timestamp;
+
+    msg!("BadDebtWrittenOff: keeper={} owner={} position={} vault={} amount={} covered={} deficit={}",
+        ctx.accounts.keeper.key(),
+        position.owner,
+        position.key(),
+        vault_type.key(),
+        debt,
+        covered,
+        protocol_state.deficit
+    );
+    Ok(())
+}
@@ -19,7 +19,7 @@ pub struct AddRole<'info> {
     pub target_account: AccountInfo<'info>,
 }
 
-pub fn handler(ctx: Context<AddRole>, role_type: u8) -> Result<()> {
+pub(crate) fn handler(ctx: Context<AddRole>, role_type: u8) -> Result<()> {
     let protocol_state = &mut ctx.accounts.protocol_state;
     let clock = Clock::get()?;
 
@@ -19,7 +19,7 @@ pub struct InitializeProtocol<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(
+pub(crate) fn handler(
     ctx: Context<InitializeProtocol>,
     treasury_pubkey: Pubkey,
 ) -> Result<()> {
@@ -63,18 +63,21 @@ pub fn handler(
 
     // Initialize fee tracking (zero by default)
     protocol_state.total_mint_fees_collected = 0;
+    protocol_state.total_stability_fees_collected = 0;
     protocol_state.total_redeem_fees_collected = 0;
     protocol_state.total_liquidation_fees_collected = 0;
 
+    // Initialize solvency tracking
+    protocol_state.surplus_buffer = 0;
+    protocol_state.surplus_buffer_cap = DEFAULT_SURPLUS_BUFFER_CAP;
+    protocol_state.deficit = 0;
+    protocol_state.total_bad_debt_written_off = 0;
+
     // Set metadata
     protocol_state.config_version = INITIAL_CONFIG_VERSION;
     protocol_state.created_at = clock.unix_timestamp;
     protocol_state.updated_at = clock.unix_timestamp;
 
-    // Initialize reserved bytes to zero (reduced size)
-    protocol_state.reserved = [0u8; 40];
-
-
     msg!("Protocol Initialized with defaults");
     msg!("Admin: {}", protocol_state.admin_pubkey);
     msg!("Treasury: {}", protocol_state.treasury_pubkey);
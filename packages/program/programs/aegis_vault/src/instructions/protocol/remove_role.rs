@@ -16,7 +16,7 @@ pub struct RemoveRole<'info> {
     pub admin_pubkey: Signer<'info>,
 }
 
-pub fn handler(ctx: Context<RemoveRole>, role_type: u8) -> Result<()> {
+pub(crate) fn handler(ctx: Context<RemoveRole>, role_type: u8) -> Result<()> {
     let protocol_state = &mut ctx.accounts.protocol_state;
     let clock = Clock::get()?;
 
@@ -24,7 +24,7 @@ pub struct UpdateFeatureFlags<'info> {
     pub guardian_pubkey: Signer<'info>,
 }
 
-pub fn handler(ctx: Context<UpdateFeatureFlags>, params: UpdateFeatureFlagsParams) -> Result<()> {
+pub(crate) fn handler(ctx: Context<UpdateFeatureFlags>, params: UpdateFeatureFlagsParams) -> Result<()> {
     let protocol_state = &mut ctx.accounts.protocol_state;
     let clock = Clock::get()?;
 
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::state::{EpochScaleSum, ProtocolState, StabilityPool, VaultType};
+use crate::constants::liquidation::{STABILITY_POOL_PRECISION, STABILITY_POOL_SUM_CAPACITY};
+use crate::constants::seeds::{PROTOCOL_STATE_SEED, STABILITY_POOL_SEED, VAULT_TYPE_SEED};
+
+#[derive(Accounts)]
+pub struct InitializeStabilityPool<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = StabilityPool::LEN,
+        seeds = [STABILITY_POOL_SEED, vault_type.key().as_ref()],
+        bump
+    )]
+    pub stability_pool: Box<Account<'info, StabilityPool>>,
+
+    #[account(
+        seeds = [VAULT_TYPE_SEED, vault_type.collateral_mint.key().as_ref()],
+        bump
+    )]
+    pub vault_type: Account<'info, VaultType>,
+
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED],
+        bump,
+        constraint = protocol_state.admin_pubkey == admin.key(),
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<InitializeStabilityPool>) -> Result<()> {
+    let stability_pool = &mut ctx.accounts.stability_pool;
+    let clock = Clock::get()?;
+
+    stability_pool.vault_type = ctx.accounts.vault_type.key();
+    stability_pool.total_deposits = 0;
+    stability_pool.total_collateral = 0;
+    stability_pool.product = STABILITY_POOL_PRECISION;
+    stability_pool.current_epoch = 0;
+    stability_pool.current_scale = 0;
+    stability_pool.sums = [EpochScaleSum::default(); STABILITY_POOL_SUM_CAPACITY];
+    stability_pool.sum_count = 1; // epoch 0, scale 0
+    stability_pool.bump = ctx.bumps.stability_pool;
+    stability_pool.created_at = clock.unix_timestamp;
+    stability_pool.updated_at = clock.unix_timestamp;
+
+    msg!("Stability Pool Initialized: vault={}", stability_pool.vault_type);
+    Ok(())
+}
@@ -0,0 +1,9 @@
+pub mod initialize_stability_pool;
+pub mod open_stability_deposit;
+pub mod provide_to_stability_pool;
+pub mod withdraw_from_stability_pool;
+
+pub use initialize_stability_pool::*;
+pub use open_stability_deposit::*;
+pub use provide_to_stability_pool::*;
+pub use withdraw_from_stability_pool::*;
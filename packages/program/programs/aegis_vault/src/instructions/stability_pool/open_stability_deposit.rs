@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::state::{StabilityDeposit, StabilityPool};
+use crate::constants::seeds::STABILITY_DEPOSIT_SEED;
+
+#[derive(Accounts)]
+pub struct OpenStabilityDeposit<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = StabilityDeposit::LEN,
+        seeds = [
+            STABILITY_DEPOSIT_SEED,
+            stability_pool.key().as_ref(),
+            owner.key().as_ref()
+        ],
+        bump
+    )]
+    pub stability_deposit: Account<'info, StabilityDeposit>,
+
+    pub stability_pool: Box<Account<'info, StabilityPool>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<OpenStabilityDeposit>) -> Result<()> {
+    let stability_deposit = &mut ctx.accounts.stability_deposit;
+    let clock = Clock::get()?;
+
+    stability_deposit.owner = ctx.accounts.owner.key();
+    stability_deposit.stability_pool = ctx.accounts.stability_pool.key();
+    stability_deposit.initial_value = 0;
+    stability_deposit.snapshot_product = 0;
+    stability_deposit.snapshot_sum = 0;
+    stability_deposit.snapshot_epoch = 0;
+    stability_deposit.snapshot_scale = 0;
+    stability_deposit.bump = ctx.bumps.stability_deposit;
+    stability_deposit.created_at = clock.unix_timestamp;
+    stability_deposit.updated_at = clock.unix_timestamp;
+
+    msg!("Stability deposit opened for user: {}", stability_deposit.owner);
+    Ok(())
+}
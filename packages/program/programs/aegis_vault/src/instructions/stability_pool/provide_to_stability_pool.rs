@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{ProtocolState, StabilityDeposit, StabilityPool, VaultType};
+use crate::constants::seeds::{STABILITY_DEPOSIT_SEED, STABILITY_POOL_SEED};
+use crate::errors::codes::AegisError;
+use crate::utils::stability_pool::{collateral_gain, compounded_deposit, update_deposit_snapshot};
+
+#[derive(Accounts)]
+pub struct ProvideToStabilityPool<'info> {
+    #[account(
+        mut,
+        seeds = [
+            STABILITY_DEPOSIT_SEED,
+            stability_pool.key().as_ref(),
+            owner.key().as_ref()
+        ],
+        bump = stability_deposit.bump,
+        has_one = owner,
+        has_one = stability_pool
+    )]
+    pub stability_deposit: Account<'info, StabilityDeposit>,
+
+    #[account(
+        mut,
+        seeds = [STABILITY_POOL_SEED, vault_type.key().as_ref()],
+        bump = stability_pool.bump,
+        has_one = vault_type
+    )]
+    pub stability_pool: Box<Account<'info, StabilityPool>>,
+
+    pub vault_type: Account<'info, VaultType>,
+
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    #[account(mut)]
+    pub user_stablecoin_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = pool_stablecoin_account.mint == protocol_state.stablecoin_mint @ AegisError::Unauthorized,
+        constraint = pool_stablecoin_account.owner == stability_pool.key() @ AegisError::Unauthorized
+    )]
+    pub pool_stablecoin_account: Account<'info, TokenAccount>,
+
+    /// Receives the collateral gained since the last deposit or withdrawal
+    #[account(mut)]
+    pub user_collateral_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = vault_collateral_account.mint == vault_type.collateral_mint,
+        constraint = vault_collateral_account.owner == vault_authority.key()
+    )]
+    pub vault_collateral_account: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA that has authority over vault collateral
+    #[account(
+        seeds = [b"vault_authority", vault_type.key().as_ref()],
+        bump = vault_type.vault_authority_bump
+    )]
+    pub vault_authority: AccountInfo<'info>,
+
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub(crate) fn handler(ctx: Context<ProvideToStabilityPool>, amount: u64) -> Result<()> {
+    require!(amount > 0, AegisError::InvalidAmount);
+    require!(!ctx.accounts.protocol_state.is_protocol_paused, AegisError::ProtocolPaused);
+
+    let stability_pool = &mut ctx.accounts.stability_pool;
+    let stability_deposit = &mut ctx.accounts.stability_deposit;
+    let clock = Clock::get()?;
+
+    // Settle the deposit against the liquidations since its snapshot
+    let gain = collateral_gain(stability_pool, stability_deposit)?.min(stability_pool.total_collateral);
+    let compounded = compounded_deposit(stability_pool, stability_deposit)?;
+
+    // Pay out the collateral gain
+    if gain > 0 {
+        let vault_type_key = ctx.accounts.vault_type.key();
+        let seeds = &[
+            b"vault_authority".as_ref(),
+            vault_type_key.as_ref(),
+            &[ctx.accounts.vault_type.vault_authority_bump],
+        ];
+        let signer = &[&seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.vault_collateral_account.to_account_info(),
+            to: ctx.accounts.user_collateral_account.to_account_info(),
+            authority: ctx.accounts.vault_authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer);
+        token::transfer(cpi_ctx, gain)?;
+
+        stability_pool.total_collateral -= gain;
+    }
+
+    // Transfer the deposit into the pool
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.user_stablecoin_account.to_account_info(),
+        to: ctx.accounts.pool_stablecoin_account.to_account_info(),
+        authority: ctx.accounts.owner.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_ctx, amount)?;
+
+    let new_value = compounded.checked_add(amount).ok_or(AegisError::MathOverflow)?;
+    stability_pool.total_deposits = stability_pool.total_deposits
+        .checked_add(amount)
+        .ok_or(AegisError::MathOverflow)?;
+    stability_pool.updated_at = clock.unix_timestamp;
+    update_deposit_snapshot(stability_pool, stability_deposit, new_value);
+    stability_deposit.updated_at = clock.unix_timestamp;
+
+    msg!("StabilityPoolDeposit: owner={} vault={} amount={} deposit={} collateral_gain={}",
+        stability_deposit.owner,
+        stability_pool.vault_type,
+        amount,
+        new_value,
+        gain
+    );
+    Ok(())
+}
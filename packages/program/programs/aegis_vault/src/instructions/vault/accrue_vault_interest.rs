@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::state::{ProtocolState, VaultType};
+use crate::constants::seeds::{PROTOCOL_STATE_SEED, VAULT_TYPE_SEED};
+use crate::utils::interest::accrue_interest;
+
+/// Permissionless checkpoint of a vault type's stability fee accumulator
+#[derive(Accounts)]
+pub struct AccrueVaultInterest<'info> {
+    #[account(
+        mut,
+        seeds = [VAULT_TYPE_SEED, vault_type.collateral_mint.key().as_ref()],
+        bump
+    )]
+    pub vault_type: Account<'info, VaultType>,
+
+    #[account(
+        mut,
+        seeds = [PROTOCOL_STATE_SEED],
+        bump,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+}
+
+pub(crate) fn handler(ctx: Context<AccrueVaultInterest>) -> Result<()> {
+    let vault_type = &mut ctx.accounts.vault_type;
+    let protocol_state = &mut ctx.accounts.protocol_state;
+    let clock = Clock::get()?;
+
+    let debt_before = protocol_state.total_protocol_debt;
+    accrue_interest(vault_type, protocol_state, clock.unix_timestamp)?;
+    let accrued = protocol_state.total_protocol_debt.saturating_sub(debt_before);
+
+    protocol_state.updated_at = clock.unix_timestamp;
+
+    msg!("InterestAccrued: vault={} rate={} accrued={}",
+        vault_type.key(),
+        vault_type.cumulative_rate,
+        accrued
+    );
+    Ok(())
+}
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Mint, MintTo};
+use crate::state::{ProtocolState, VaultType};
+use crate::constants::seeds::{PROTOCOL_STATE_SEED, VAULT_TYPE_SEED};
+use crate::errors::codes::AegisError;
+use crate::utils::interest::accrue_interest;
+use crate::utils::solvency::retain_surplus;
+
+#[derive(Accounts)]
+pub struct CollectStabilityFees<'info> {
+    #[account(
+        mut,
+        seeds = [VAULT_TYPE_SEED, vault_type.collateral_mint.key().as_ref()],
+        bump
+    )]
+    pub vault_type: Account<'info, VaultType>,
+
+    #[account(
+        mut,
+        seeds = [PROTOCOL_STATE_SEED],
+        bump,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    #[account(
+        mut,
+        constraint = stablecoin_mint.key() == protocol_state.stablecoin_mint @ AegisError::Unauthorized
+    )]
+    pub stablecoin_mint: Account<'info, Mint>,
+
+    /// Treasury stablecoin account to receive the surplus
+    #[account(
+        mut,
+        constraint = treasury_stablecoin_account.key() == protocol_state.treasury_ata @ AegisError::Unauthorized
+    )]
+    pub treasury_stablecoin_account: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA that has mint authority
+    #[account(
+        seeds = [b"mint_authority"],
+        bump = protocol_state.mint_authority_bump
+    )]
+    pub mint_authority: AccountInfo<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub(crate) fn handler(ctx: Context<CollectStabilityFees>) -> Result<()> {
+    let vault_type = &mut ctx.accounts.vault_type;
+    let protocol_state = &mut ctx.accounts.protocol_state;
+    let clock = Clock::get()?;
+
+    // Bring the accumulator up to date so all interest so far is collectable
+    accrue_interest(vault_type, protocol_state, clock.unix_timestamp)?;
+
+    let fee_amount = vault_type.unrealized_stability_fees;
+
+    // Fees cover any deficit and fill the surplus buffer before reaching the treasury
+    let treasury_amount = retain_surplus(protocol_state, fee_amount);
+
+    if treasury_amount > 0 {
+        // Mint the realized interest to the treasury
+        let seeds = &[
+            b"mint_authority".as_ref(),
+            &[protocol_state.mint_authority_bump],
+        ];
+        let signer = &[&seeds[..]];
+
+        let cpi_accounts = MintTo {
+            mint: ctx.accounts.stablecoin_mint.to_account_info(),
+            to: ctx.accounts.treasury_stablecoin_account.to_account_info(),
+            authority: ctx.accounts.mint_authority.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        token::mint_to(cpi_ctx, treasury_amount)?;
+    }
+
+    if fee_amount > 0 {
+        vault_type.unrealized_stability_fees = 0;
+
+        // Update fee tracking
+        protocol_state.total_stability_fees_collected = protocol_state.total_stability_fees_collected
+            .checked_add(fee_amount)
+            .ok_or(AegisError::MathOverflow)?;
+    }
+
+    protocol_state.updated_at = clock.unix_timestamp;
+
+    // Emit fee collection log for indexer
+    msg!("StabilityFeeCollected: amount={} vault={} rate={} treasury={} surplus_buffer={} deficit={}",
+        fee_amount,
+        vault_type.key(),
+        vault_type.cumulative_rate,
+        treasury_amount,
+        protocol_state.surplus_buffer,
+        protocol_state.deficit
+    );
+
+    Ok(())
+}
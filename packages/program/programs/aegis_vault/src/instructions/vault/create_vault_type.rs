@@ -1,6 +1,15 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
 use crate::state::{ProtocolState, VaultType};
 use crate::constants::seeds::{PROTOCOL_STATE_SEED, VAULT_TYPE_SEED};
+use crate::constants::protocol::RATE_PRECISION;
+use crate::errors::codes::AegisError;
+use crate::utils::auction::validate_auction_params;
+use crate::utils::liquidation::{validate_liquidation_bonus, validate_liquidation_params};
+use crate::utils::oracle::{
+    validate_oracle_aggregation, validate_oracle_source, validate_price_band,
+    validate_pricing_mode, validate_verification_level,
+};
 
 #[derive(Accounts)]
 #[instruction(collateral_mint: Pubkey, params: CreateVaultTypeParams)]
@@ -14,6 +23,9 @@ pub struct CreateVaultType<'info> {
     )]
     pub vault_type: Account<'info, VaultType>,
 
+    #[account(address = collateral_mint)]
+    pub collateral_mint_account: Account<'info, Mint>,
+
     #[account(
         mut,
         seeds = [PROTOCOL_STATE_SEED],
@@ -30,7 +42,25 @@ pub struct CreateVaultType<'info> {
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
 pub struct CreateVaultTypeParams {
+    pub collateral_decimals: u8,
+    pub oracle_source: u8,
     pub oracle_price_account: Pubkey,
+    pub oracle_feed_id: [u8; 32],
+    pub oracle_max_age_seconds: u64,
+    pub oracle_price_exponent: i32,
+    pub oracle_verification_level: u8,
+    pub oracle_min_signatures: u8,
+    pub conf_multiplier_bps: u16,
+    pub max_conf_ratio_bps: u16,
+    pub pricing_mode: u8,
+    pub stake_pool_account: Pubkey,
+    pub is_pegged: bool,
+    pub min_price: u64,
+    pub max_price: u64,
+    pub secondary_oracle_account: Pubkey,
+    pub secondary_oracle_max_age_seconds: u64,
+    pub oracle_aggregation: u8,
+    pub max_oracle_deviation_bps: u16,
     pub ltv_bps: u64,
     pub liq_threshold_bps: u64,
     pub liq_penalty_bps: u64,
@@ -38,13 +68,67 @@ pub struct CreateVaultTypeParams {
     pub mint_fee_bps: u16,
     pub redeem_fee_bps: u16,
     pub vault_debt_ceiling: u64,
+    pub close_factor_bps: u16,
+    pub target_health_bps: u16,
+    pub full_liquidation_health_bps: u16,
+    pub liquidation_dust_threshold: u64,
+    pub liquidation_mode: u8,
+    pub auction_start_premium_bps: u16,
+    pub auction_floor_bps: u16,
+    pub auction_duration_seconds: u64,
+    pub liquidation_protocol_share_bps: u16,
+    pub keeper_reward: u64,
+    pub keeper_reward_max_debt: u64,
+    pub liquidation_bonus_min_bps: u16,
+    pub liquidation_bonus_max_bps: u16,
+    pub max_price_move_bps_per_minute: u16,
+    pub twap_window_seconds: u64,
 }
 
-pub fn handler(
+pub(crate) fn handler(
     ctx: Context<CreateVaultType>,
     collateral_mint: Pubkey,
     params: CreateVaultTypeParams,
 ) -> Result<()> {
+    require!(
+        params.collateral_decimals == ctx.accounts.collateral_mint_account.decimals,
+        AegisError::InvalidCollateralDecimals
+    );
+    validate_oracle_source(
+        params.oracle_source,
+        &params.oracle_price_account,
+        &params.oracle_feed_id,
+        &params.stake_pool_account,
+        &ctx.accounts.vault_type.key(),
+    )?;
+    validate_verification_level(params.oracle_verification_level, params.oracle_min_signatures)?;
+    require!(params.max_conf_ratio_bps <= 10_000, AegisError::InvalidOracleConfig);
+    validate_pricing_mode(params.pricing_mode)?;
+    validate_price_band(params.min_price, params.max_price)?;
+    validate_oracle_aggregation(
+        params.oracle_aggregation,
+        &params.secondary_oracle_account,
+        params.max_oracle_deviation_bps,
+    )?;
+    validate_liquidation_params(
+        params.close_factor_bps,
+        params.target_health_bps,
+        params.full_liquidation_health_bps,
+        params.liquidation_protocol_share_bps,
+    )?;
+    validate_liquidation_bonus(
+        params.liquidation_bonus_min_bps,
+        params.liquidation_bonus_max_bps,
+    )?;
+    validate_auction_params(
+        params.liquidation_mode,
+        params.auction_start_premium_bps,
+        params.auction_floor_bps,
+        params.auction_duration_seconds,
+    )?;
+    // The TWAP is enabled by update_vault_type once a price history is attached
+    require!(params.twap_window_seconds == 0, AegisError::InvalidOracleConfig);
+
     let vault_type = &mut ctx.accounts.vault_type;
     let clock = Clock::get()?;
 
@@ -54,7 +138,25 @@ pub fn handler(
     vault_type.vault_type_id = u32::from_le_bytes(id_bytes);
 
     vault_type.collateral_mint = collateral_mint;
+    vault_type.collateral_decimals = params.collateral_decimals;
+    vault_type.oracle_source = params.oracle_source;
     vault_type.oracle_price_account = params.oracle_price_account;
+    vault_type.oracle_feed_id = params.oracle_feed_id;
+    vault_type.oracle_max_age_seconds = params.oracle_max_age_seconds;
+    vault_type.oracle_price_exponent = params.oracle_price_exponent;
+    vault_type.oracle_verification_level = params.oracle_verification_level;
+    vault_type.oracle_min_signatures = params.oracle_min_signatures;
+    vault_type.conf_multiplier_bps = params.conf_multiplier_bps;
+    vault_type.max_conf_ratio_bps = params.max_conf_ratio_bps;
+    vault_type.pricing_mode = params.pricing_mode;
+    vault_type.stake_pool_account = params.stake_pool_account;
+    vault_type.is_pegged = params.is_pegged;
+    vault_type.min_price = params.min_price;
+    vault_type.max_price = params.max_price;
+    vault_type.secondary_oracle_account = params.secondary_oracle_account;
+    vault_type.secondary_oracle_max_age_seconds = params.secondary_oracle_max_age_seconds;
+    vault_type.oracle_aggregation = params.oracle_aggregation;
+    vault_type.max_oracle_deviation_bps = params.max_oracle_deviation_bps;
     vault_type.ltv_bps = params.ltv_bps;
     vault_type.liq_threshold_bps = params.liq_threshold_bps;
     vault_type.liq_penalty_bps = params.liq_penalty_bps;
@@ -62,6 +164,51 @@ pub fn handler(
     vault_type.mint_fee_bps = params.mint_fee_bps;
     vault_type.redeem_fee_bps = params.redeem_fee_bps;
     vault_type.vault_debt_ceiling = params.vault_debt_ceiling;
+    vault_type.close_factor_bps = params.close_factor_bps;
+    vault_type.target_health_bps = params.target_health_bps;
+    vault_type.full_liquidation_health_bps = params.full_liquidation_health_bps;
+    vault_type.liquidation_dust_threshold = params.liquidation_dust_threshold;
+    vault_type.liquidation_mode = params.liquidation_mode;
+    vault_type.auction_start_premium_bps = params.auction_start_premium_bps;
+    vault_type.auction_floor_bps = params.auction_floor_bps;
+    vault_type.auction_duration_seconds = params.auction_duration_seconds;
+    vault_type.liquidation_protocol_share_bps = params.liquidation_protocol_share_bps;
+    vault_type.keeper_reward = params.keeper_reward;
+    vault_type.keeper_reward_max_debt = params.keeper_reward_max_debt;
+    vault_type.liquidation_bonus_min_bps = params.liquidation_bonus_min_bps;
+    vault_type.liquidation_bonus_max_bps = params.liquidation_bonus_max_bps;
+
+    // Stability fee accrual starts at a rate of 1.0
+    vault_type.cumulative_rate = RATE_PRECISION;
+    vault_type.last_accrual_ts = clock.unix_timestamp;
+    vault_type.total_normalized_debt = 0;
+    vault_type.unrealized_stability_fees = 0;
+
+    // No positions to redistribute to yet
+    vault_type.total_collateral = 0;
+    vault_type.total_stakes = 0;
+    vault_type.total_stakes_snapshot = 0;
+    vault_type.total_collateral_snapshot = 0;
+    vault_type.l_coll = 0;
+    vault_type.l_debt = 0;
+
+    // Circuit breaker baselines on the first accepted price
+    vault_type.last_accepted_price = 0;
+    vault_type.last_accepted_price_ts = 0;
+    vault_type.max_price_move_bps_per_minute = params.max_price_move_bps_per_minute;
+    vault_type.is_mint_paused = false;
+    vault_type.circuit_breaker_tripped_at = 0;
+    vault_type.circuit_breaker_trip_price = 0;
+
+    // The price history is attached by initialize_price_history, then
+    // update_vault_type can enable the TWAP
+    vault_type.price_history = Pubkey::default();
+    vault_type.twap_window_seconds = 0;
+
+    // No guardian override
+    vault_type.override_price = 0;
+    vault_type.override_set_at = 0;
+    vault_type.override_expires_at = 0;
     
     // Derive vault_authority PDA bump
     let vault_type_key = vault_type.key();
@@ -74,7 +221,7 @@ pub fn handler(
     vault_type.is_active = true;
     vault_type.created_at = clock.unix_timestamp;
     vault_type.updated_at = clock.unix_timestamp;
-    vault_type.reserved = [0; 96];
+    vault_type.reserved = [0; 64];
 
     msg!("Vault Type Created: {}", collateral_mint);
     Ok(())
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use crate::state::{ProtocolState, VaultType};
+use crate::constants::seeds::{PROTOCOL_STATE_SEED, VAULT_TYPE_SEED};
+use crate::constants::oracle::PRICE_DECIMALS;
+use crate::errors::codes::AegisError;
+use crate::utils::oracle::{get_oracle_price, read_primary_price};
+
+/// Oracle price of a vault type as seen by the UI. Prices are in 6 decimals.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct PriceView {
+    pub price: u64,
+    pub conf: u64,
+    pub publish_time: i64,
+    /// Exponent of `price` and `conf`
+    pub exponent: i32,
+    /// Seconds since `publish_time`
+    pub staleness_seconds: i64,
+    /// `ORACLE_SOURCE_*` the price came from
+    pub source: u8,
+    /// Whether the price passes the checks used by position instructions
+    pub is_valid: bool,
+    /// `AegisError` code of the failed check, 0 when valid
+    pub error_code: u32,
+}
+
+#[derive(Accounts)]
+pub struct GetPriceView<'info> {
+    #[account(
+        seeds = [VAULT_TYPE_SEED, vault_type.collateral_mint.key().as_ref()],
+        bump
+    )]
+    pub vault_type: Account<'info, VaultType>,
+
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED],
+        bump,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    /// CHECK: Validated by Pyth SDK, or as the vault type's PriceFeed
+    #[account(
+        constraint = oracle_price_account.key() == vault_type.oracle_price_account @ AegisError::InvalidOracleAccount
+    )]
+    pub oracle_price_account: AccountInfo<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<GetPriceView>) -> Result<PriceView> {
+    let vault_type = &ctx.accounts.vault_type;
+    let oracle_info = &ctx.accounts.oracle_price_account;
+
+    // For UI display, we want to show the price even if it fails the strict checks,
+    // so fall back to the undecorated primary price and report why it was rejected.
+    let (oracle_price, error_code) = match get_oracle_price(
+        oracle_info,
+        ctx.remaining_accounts,
+        vault_type,
+        &ctx.accounts.protocol_state,
+    ) {
+        Ok(oracle_price) => (oracle_price, 0),
+        Err(err) => {
+            let error_code = match &err {
+                Error::AnchorError(anchor_error) => anchor_error.error_code_number,
+                Error::ProgramError(_) => u32::MAX,
+            };
+            (read_primary_price(oracle_info, ctx.remaining_accounts, vault_type)?, error_code)
+        }
+    };
+
+    let staleness_seconds = Clock::get()?
+        .unix_timestamp
+        .saturating_sub(oracle_price.publish_time)
+        .max(0);
+
+    msg!("Latest Oracle Price (UI View): {} valid={}", oracle_price.price, error_code == 0);
+    Ok(PriceView {
+        price: oracle_price.price,
+        conf: oracle_price.conf,
+        publish_time: oracle_price.publish_time,
+        exponent: -PRICE_DECIMALS,
+        staleness_seconds,
+        source: oracle_price.source,
+        is_valid: error_code == 0,
+        error_code,
+    })
+}
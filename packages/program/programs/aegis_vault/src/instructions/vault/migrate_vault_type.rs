@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use crate::state::{ProtocolState, VaultType};
+use crate::constants::seeds::{PROTOCOL_STATE_SEED, VAULT_TYPE_SEED};
+use crate::constants::protocol::RATE_PRECISION;
+use crate::utils::migration::grow_account;
+
+/// Grow a vault type created with the original layout to the current one. The appended
+/// fields start zeroed, except the ones below that keep its original behaviour; the oracle
+/// configuration is then set with update_vault_type.
+#[derive(Accounts)]
+pub struct MigrateVaultType<'info> {
+    /// CHECK: Owner, discriminator and size are checked by grow_account
+    #[account(
+        mut,
+        seeds = [VAULT_TYPE_SEED, collateral_mint.key().as_ref()],
+        bump
+    )]
+    pub vault_type: UncheckedAccount<'info>,
+
+    pub collateral_mint: Account<'info, Mint>,
+
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED],
+        bump,
+        constraint = protocol_state.admin_pubkey == admin.key(),
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<MigrateVaultType>) -> Result<()> {
+    let vault_type_info = ctx.accounts.vault_type.to_account_info();
+    grow_account::<VaultType>(
+        &vault_type_info,
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        VaultType::LEN,
+    )?;
+
+    let mut vault_type = VaultType::try_deserialize(&mut &vault_type_info.try_borrow_data()?[..])?;
+    let clock = Clock::get()?;
+
+    vault_type.collateral_decimals = ctx.accounts.collateral_mint.decimals;
+
+    // Stability fees accrue from the migration on
+    vault_type.cumulative_rate = RATE_PRECISION;
+    vault_type.last_accrual_ts = clock.unix_timestamp;
+
+    // Full repayment allowed and the penalty split 50/50, as before
+    vault_type.close_factor_bps = 10_000;
+    vault_type.liquidation_protocol_share_bps = 5_000;
+
+    vault_type.updated_at = clock.unix_timestamp;
+    vault_type.try_serialize(&mut &mut vault_type_info.try_borrow_mut_data()?[..])?;
+
+    msg!("VaultTypeMigrated: vault={} mint={} len={}",
+        vault_type_info.key(),
+        vault_type.collateral_mint,
+        VaultType::LEN
+    );
+    Ok(())
+}
@@ -1,9 +1,15 @@
 pub mod create_vault_type;
 pub mod update_vault_type;
 pub mod toggle_vault_active;
-pub mod get_latest_price;
+pub mod get_price_view;
+pub mod accrue_vault_interest;
+pub mod collect_stability_fees;
+pub mod migrate_vault_type;
 
 pub use create_vault_type::*;
 pub use update_vault_type::*;
 pub use toggle_vault_active::*;
-pub use get_latest_price::*;
+pub use get_price_view::*;
+pub use accrue_vault_interest::*;
+pub use collect_stability_fees::*;
+pub use migrate_vault_type::*;
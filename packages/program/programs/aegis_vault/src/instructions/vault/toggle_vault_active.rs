@@ -21,7 +21,7 @@ pub struct ToggleVaultActive<'info> {
     pub admin: Signer<'info>,
 }
 
-pub fn handler(ctx: Context<ToggleVaultActive>) -> Result<()> {
+pub(crate) fn handler(ctx: Context<ToggleVaultActive>) -> Result<()> {
     let vault_type = &mut ctx.accounts.vault_type;
     let clock = Clock::get()?;
 
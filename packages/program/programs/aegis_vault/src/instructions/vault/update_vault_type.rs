@@ -1,6 +1,16 @@
 use anchor_lang::prelude::*;
 use crate::state::{ProtocolState, VaultType};
 use crate::constants::seeds::{PROTOCOL_STATE_SEED, VAULT_TYPE_SEED};
+use crate::utils::interest::accrue_interest;
+use crate::constants::oracle::MAX_TWAP_WINDOW_SECONDS;
+use crate::errors::codes::AegisError;
+use crate::utils::auction::validate_auction_params;
+use crate::utils::liquidation::{validate_liquidation_bonus, validate_liquidation_params};
+use crate::utils::price_history::{load_price_history, validate_twap_window};
+use crate::utils::oracle::{
+    validate_oracle_aggregation, validate_oracle_source, validate_price_band,
+    validate_pricing_mode, validate_verification_level,
+};
 
 #[derive(Accounts)]
 pub struct UpdateVaultType<'info> {
@@ -12,6 +22,7 @@ pub struct UpdateVaultType<'info> {
     pub vault_type: Account<'info, VaultType>,
 
     #[account(
+        mut,
         seeds = [PROTOCOL_STATE_SEED],
         bump,
         constraint = protocol_state.admin_pubkey == admin.key(),
@@ -23,7 +34,24 @@ pub struct UpdateVaultType<'info> {
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
 pub struct UpdateVaultTypeParams {
+    pub oracle_source: Option<u8>,
     pub oracle_price_account: Option<Pubkey>,
+    pub oracle_feed_id: Option<[u8; 32]>,
+    pub stake_pool_account: Option<Pubkey>,
+    pub oracle_max_age_seconds: Option<u64>,
+    pub oracle_price_exponent: Option<i32>,
+    pub oracle_verification_level: Option<u8>,
+    pub oracle_min_signatures: Option<u8>,
+    pub conf_multiplier_bps: Option<u16>,
+    pub max_conf_ratio_bps: Option<u16>,
+    pub pricing_mode: Option<u8>,
+    pub is_pegged: Option<bool>,
+    pub min_price: Option<u64>,
+    pub max_price: Option<u64>,
+    pub secondary_oracle_account: Option<Pubkey>,
+    pub secondary_oracle_max_age_seconds: Option<u64>,
+    pub oracle_aggregation: Option<u8>,
+    pub max_oracle_deviation_bps: Option<u16>,
     pub ltv_bps: Option<u64>,
     pub liq_threshold_bps: Option<u64>,
     pub liq_penalty_bps: Option<u64>,
@@ -31,18 +59,106 @@ pub struct UpdateVaultTypeParams {
     pub mint_fee_bps: Option<u16>,
     pub redeem_fee_bps: Option<u16>,
     pub vault_debt_ceiling: Option<u64>,
+    pub close_factor_bps: Option<u16>,
+    pub target_health_bps: Option<u16>,
+    pub full_liquidation_health_bps: Option<u16>,
+    pub liquidation_dust_threshold: Option<u64>,
+    pub liquidation_mode: Option<u8>,
+    pub auction_start_premium_bps: Option<u16>,
+    pub auction_floor_bps: Option<u16>,
+    pub auction_duration_seconds: Option<u64>,
+    pub liquidation_protocol_share_bps: Option<u16>,
+    pub keeper_reward: Option<u64>,
+    pub keeper_reward_max_debt: Option<u64>,
+    pub liquidation_bonus_min_bps: Option<u16>,
+    pub liquidation_bonus_max_bps: Option<u16>,
+    pub max_price_move_bps_per_minute: Option<u16>,
+    pub twap_window_seconds: Option<u64>,
 }
 
-pub fn handler(
+pub(crate) fn handler(
     ctx: Context<UpdateVaultType>,
     params: UpdateVaultTypeParams,
 ) -> Result<()> {
     let vault_type = &mut ctx.accounts.vault_type;
     let clock = Clock::get()?;
 
+    // Settle interest at the old stability fee before any change
+    accrue_interest(vault_type, &mut ctx.accounts.protocol_state, clock.unix_timestamp)?;
+
+    if let Some(source) = params.oracle_source {
+        vault_type.oracle_source = source;
+    }
     if let Some(oracle) = params.oracle_price_account {
         vault_type.oracle_price_account = oracle;
     }
+    if let Some(feed_id) = params.oracle_feed_id {
+        vault_type.oracle_feed_id = feed_id;
+    }
+    if let Some(stake_pool) = params.stake_pool_account {
+        vault_type.stake_pool_account = stake_pool;
+    }
+    validate_oracle_source(
+        vault_type.oracle_source,
+        &vault_type.oracle_price_account,
+        &vault_type.oracle_feed_id,
+        &vault_type.stake_pool_account,
+        &vault_type.key(),
+    )?;
+    if let Some(max_age) = params.oracle_max_age_seconds {
+        vault_type.oracle_max_age_seconds = max_age;
+    }
+    if let Some(exponent) = params.oracle_price_exponent {
+        vault_type.oracle_price_exponent = exponent;
+    }
+    if let Some(level) = params.oracle_verification_level {
+        vault_type.oracle_verification_level = level;
+    }
+    if let Some(min_signatures) = params.oracle_min_signatures {
+        vault_type.oracle_min_signatures = min_signatures;
+    }
+    validate_verification_level(
+        vault_type.oracle_verification_level,
+        vault_type.oracle_min_signatures,
+    )?;
+    if let Some(multiplier) = params.conf_multiplier_bps {
+        vault_type.conf_multiplier_bps = multiplier;
+    }
+    if let Some(max_ratio) = params.max_conf_ratio_bps {
+        require!(max_ratio <= 10_000, AegisError::InvalidOracleConfig);
+        vault_type.max_conf_ratio_bps = max_ratio;
+    }
+    if let Some(pricing_mode) = params.pricing_mode {
+        validate_pricing_mode(pricing_mode)?;
+        vault_type.pricing_mode = pricing_mode;
+    }
+    if let Some(is_pegged) = params.is_pegged {
+        vault_type.is_pegged = is_pegged;
+    }
+    if let Some(min_price) = params.min_price {
+        vault_type.min_price = min_price;
+    }
+    if let Some(max_price) = params.max_price {
+        vault_type.max_price = max_price;
+    }
+    validate_price_band(vault_type.min_price, vault_type.max_price)?;
+    if let Some(secondary) = params.secondary_oracle_account {
+        vault_type.secondary_oracle_account = secondary;
+    }
+    if let Some(max_age) = params.secondary_oracle_max_age_seconds {
+        vault_type.secondary_oracle_max_age_seconds = max_age;
+    }
+    if let Some(aggregation) = params.oracle_aggregation {
+        vault_type.oracle_aggregation = aggregation;
+    }
+    if let Some(max_deviation) = params.max_oracle_deviation_bps {
+        vault_type.max_oracle_deviation_bps = max_deviation;
+    }
+    validate_oracle_aggregation(
+        vault_type.oracle_aggregation,
+        &vault_type.secondary_oracle_account,
+        vault_type.max_oracle_deviation_bps,
+    )?;
     if let Some(ltv) = params.ltv_bps {
         vault_type.ltv_bps = ltv;
     }
@@ -64,6 +180,73 @@ pub fn handler(
     if let Some(ceiling) = params.vault_debt_ceiling {
         vault_type.vault_debt_ceiling = ceiling;
     }
+    if let Some(close_factor) = params.close_factor_bps {
+        vault_type.close_factor_bps = close_factor;
+    }
+    if let Some(target_health) = params.target_health_bps {
+        vault_type.target_health_bps = target_health;
+    }
+    if let Some(full_liquidation_health) = params.full_liquidation_health_bps {
+        vault_type.full_liquidation_health_bps = full_liquidation_health;
+    }
+    if let Some(protocol_share) = params.liquidation_protocol_share_bps {
+        vault_type.liquidation_protocol_share_bps = protocol_share;
+    }
+    validate_liquidation_params(
+        vault_type.close_factor_bps,
+        vault_type.target_health_bps,
+        vault_type.full_liquidation_health_bps,
+        vault_type.liquidation_protocol_share_bps,
+    )?;
+    if let Some(bonus_min) = params.liquidation_bonus_min_bps {
+        vault_type.liquidation_bonus_min_bps = bonus_min;
+    }
+    if let Some(bonus_max) = params.liquidation_bonus_max_bps {
+        vault_type.liquidation_bonus_max_bps = bonus_max;
+    }
+    validate_liquidation_bonus(
+        vault_type.liquidation_bonus_min_bps,
+        vault_type.liquidation_bonus_max_bps,
+    )?;
+    if let Some(dust) = params.liquidation_dust_threshold {
+        vault_type.liquidation_dust_threshold = dust;
+    }
+    if let Some(mode) = params.liquidation_mode {
+        vault_type.liquidation_mode = mode;
+    }
+    if let Some(premium) = params.auction_start_premium_bps {
+        vault_type.auction_start_premium_bps = premium;
+    }
+    if let Some(floor) = params.auction_floor_bps {
+        vault_type.auction_floor_bps = floor;
+    }
+    if let Some(duration) = params.auction_duration_seconds {
+        vault_type.auction_duration_seconds = duration;
+    }
+    if let Some(reward) = params.keeper_reward {
+        vault_type.keeper_reward = reward;
+    }
+    if let Some(max_debt) = params.keeper_reward_max_debt {
+        vault_type.keeper_reward_max_debt = max_debt;
+    }
+    validate_auction_params(
+        vault_type.liquidation_mode,
+        vault_type.auction_start_premium_bps,
+        vault_type.auction_floor_bps,
+        vault_type.auction_duration_seconds,
+    )?;
+    if let Some(max_move) = params.max_price_move_bps_per_minute {
+        vault_type.max_price_move_bps_per_minute = max_move;
+    }
+    if let Some(window) = params.twap_window_seconds {
+        require!(window <= MAX_TWAP_WINDOW_SECONDS, AegisError::InvalidOracleConfig);
+        // A TWAP needs an attached price history (passed in remaining accounts) that can cover it
+        if window > 0 {
+            let history = load_price_history(ctx.remaining_accounts, vault_type)?;
+            validate_twap_window(window, history.min_interval_seconds)?;
+        }
+        vault_type.twap_window_seconds = window;
+    }
 
     vault_type.updated_at = clock.unix_timestamp;
 
@@ -119,6 +119,10 @@ pub mod aegis_vault {
         instructions::config::set_stablecoin_mint::handler(ctx, stablecoin_mint)
     }
 
+    pub fn set_surplus_buffer_cap(ctx: Context<SetSurplusBufferCap>, new_cap: u64) -> Result<()> {
+        instructions::config::set_surplus_buffer_cap::handler(ctx, new_cap)
+    }
+
     // Vault Management
     pub fn create_vault_type(
         ctx: Context<CreateVaultType>,
@@ -139,8 +143,95 @@ pub mod aegis_vault {
         instructions::vault::toggle_vault_active::handler(ctx)
     }
 
-    pub fn get_latest_price(ctx: Context<GetLatestPrice>) -> Result<u64> {
-        instructions::vault::get_latest_price::handler(ctx)
+    pub fn get_price_view(ctx: Context<GetPriceView>) -> Result<PriceView> {
+        instructions::vault::get_price_view::handler(ctx)
+    }
+
+    pub fn accrue_vault_interest(ctx: Context<AccrueVaultInterest>) -> Result<()> {
+        instructions::vault::accrue_vault_interest::handler(ctx)
+    }
+
+    pub fn collect_stability_fees(ctx: Context<CollectStabilityFees>) -> Result<()> {
+        instructions::vault::collect_stability_fees::handler(ctx)
+    }
+
+    pub fn migrate_vault_type(ctx: Context<MigrateVaultType>) -> Result<()> {
+        instructions::vault::migrate_vault_type::handler(ctx)
+    }
+
+    // Oracle Management
+    pub fn initialize_price_feed(
+        ctx: Context<InitializePriceFeed>,
+        max_move_bps: u16,
+    ) -> Result<()> {
+        instructions::oracle::initialize_price_feed::handler(ctx, max_move_bps)
+    }
+
+    pub fn push_price(
+        ctx: Context<PushPrice>,
+        price: u64,
+        conf: u64,
+        publish_time: i64,
+    ) -> Result<()> {
+        instructions::oracle::push_price::handler(ctx, price, conf, publish_time)
+    }
+
+    pub fn set_price_feed_max_move(
+        ctx: Context<SetPriceFeedMaxMove>,
+        max_move_bps: u16,
+    ) -> Result<()> {
+        instructions::oracle::set_price_feed_max_move::handler(ctx, max_move_bps)
+    }
+
+    pub fn refresh_price(ctx: Context<RefreshPrice>) -> Result<()> {
+        instructions::oracle::refresh_price::handler(ctx)
+    }
+
+    pub fn reset_circuit_breaker(ctx: Context<ResetCircuitBreaker>) -> Result<()> {
+        instructions::oracle::reset_circuit_breaker::handler(ctx)
+    }
+
+    pub fn initialize_price_history(
+        ctx: Context<InitializePriceHistory>,
+        min_interval_seconds: u32,
+    ) -> Result<()> {
+        instructions::oracle::initialize_price_history::handler(ctx, min_interval_seconds)
+    }
+
+    pub fn record_price(ctx: Context<RecordPrice>) -> Result<()> {
+        instructions::oracle::record_price::handler(ctx)
+    }
+
+    pub fn initialize_attestation_config(
+        ctx: Context<InitializeAttestationConfig>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::oracle::initialize_attestation_config::handler(ctx, signers, threshold)
+    }
+
+    pub fn set_attestation_signers(
+        ctx: Context<SetAttestationSigners>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::oracle::set_attestation_signers::handler(ctx, signers, threshold)
+    }
+
+    pub fn initialize_price_override_log(ctx: Context<InitializePriceOverrideLog>) -> Result<()> {
+        instructions::oracle::initialize_price_override_log::handler(ctx)
+    }
+
+    pub fn set_price_override(
+        ctx: Context<SetPriceOverride>,
+        price: u64,
+        duration_seconds: i64,
+    ) -> Result<()> {
+        instructions::oracle::set_price_override::handler(ctx, price, duration_seconds)
+    }
+
+    pub fn clear_price_override(ctx: Context<ClearPriceOverride>) -> Result<()> {
+        instructions::oracle::clear_price_override::handler(ctx)
     }
 
     // Position Management
@@ -167,4 +258,71 @@ pub mod aegis_vault {
     pub fn liquidate_position(ctx: Context<LiquidatePosition>, repay_amount: u64) -> Result<()> {
         instructions::position::liquidate_position::handler(ctx, repay_amount)
     }
+
+    pub fn start_liquidation_auction(ctx: Context<StartLiquidationAuction>) -> Result<()> {
+        instructions::position::start_liquidation_auction::handler(ctx)
+    }
+
+    pub fn bid_liquidation_auction(
+        ctx: Context<BidLiquidationAuction>,
+        collateral_amount: u64,
+        max_price: u64,
+    ) -> Result<()> {
+        instructions::position::bid_liquidation_auction::handler(ctx, collateral_amount, max_price)
+    }
+
+    pub fn settle_liquidation_auction(ctx: Context<SettleLiquidationAuction>) -> Result<()> {
+        instructions::position::settle_liquidation_auction::handler(ctx)
+    }
+
+    pub fn liquidate_with_stability_pool(ctx: Context<LiquidateWithStabilityPool>) -> Result<()> {
+        instructions::position::liquidate_with_stability_pool::handler(ctx)
+    }
+
+    pub fn flash_liquidate<'info>(
+        ctx: Context<'_, '_, '_, 'info, FlashLiquidate<'info>>,
+        repay_amount: u64,
+        callback_data: Vec<u8>,
+    ) -> Result<()> {
+        instructions::position::flash_liquidate::handler(ctx, repay_amount, callback_data)
+    }
+
+    pub fn liquidate_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, LiquidateBatch<'info>>,
+        max_repay_per_position: u64,
+    ) -> Result<Vec<BatchLiquidationResult>> {
+        instructions::position::liquidate_batch::handler(ctx, max_repay_per_position)
+    }
+
+    pub fn redistribute_position(ctx: Context<RedistributePosition>) -> Result<()> {
+        instructions::position::redistribute_position::handler(ctx)
+    }
+
+    pub fn write_off_bad_debt(ctx: Context<WriteOffBadDebt>) -> Result<()> {
+        instructions::position::write_off_bad_debt::handler(ctx)
+    }
+
+    pub fn migrate_position(ctx: Context<MigratePosition>) -> Result<()> {
+        instructions::position::migrate_position::handler(ctx)
+    }
+
+    // Stability Pool
+    pub fn initialize_stability_pool(ctx: Context<InitializeStabilityPool>) -> Result<()> {
+        instructions::stability_pool::initialize_stability_pool::handler(ctx)
+    }
+
+    pub fn open_stability_deposit(ctx: Context<OpenStabilityDeposit>) -> Result<()> {
+        instructions::stability_pool::open_stability_deposit::handler(ctx)
+    }
+
+    pub fn provide_to_stability_pool(ctx: Context<ProvideToStabilityPool>, amount: u64) -> Result<()> {
+        instructions::stability_pool::provide_to_stability_pool::handler(ctx, amount)
+    }
+
+    pub fn withdraw_from_stability_pool(
+        ctx: Context<WithdrawFromStabilityPool>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::stability_pool::withdraw_from_stability_pool::handler(ctx, amount)
+    }
 }
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::constants::oracle::MAX_ATTESTATION_SIGNERS;
+
+#[account]
+pub struct AttestationConfig {
+    /// Vault type priced by the attestations
+    pub vault_type: Pubkey,
+
+    /// Keys allowed to sign price attestations (first `signer_count` are set)
+    pub signers: [Pubkey; MAX_ATTESTATION_SIGNERS],
+
+    /// Number of configured signers
+    pub signer_count: u8,
+
+    /// Distinct signer attestations required in a transaction
+    pub threshold: u8,
+
+    /// PDA bump
+    pub bump: u8,
+
+    /// Timestamp when the config was created
+    pub created_at: i64,
+
+    /// Timestamp when the signer set was last updated
+    pub updated_at: i64,
+
+    /// Latest attestation timestamp consumed from each signer, by index in `signers`
+    pub last_attestation_ts: [i64; MAX_ATTESTATION_SIGNERS],
+}
+
+impl AttestationConfig {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // vault_type
+        32 * MAX_ATTESTATION_SIGNERS + // signers
+        1 +  // signer_count
+        1 +  // threshold
+        1 +  // bump
+        8 +  // created_at
+        8 +  // updated_at
+        8 * MAX_ATTESTATION_SIGNERS; // last_attestation_ts
+}
@@ -47,8 +47,17 @@ pub struct ProtocolState {
     pub created_at: i64,
     pub updated_at: i64,
 
-    // Reserved bytes (reduced to accommodate new fields)
-    pub reserved: [u8; 40],
+    // Fields below take the place of the original 40 reserved bytes, so accounts
+    // created with the original layout keep their size
+
+    // Cumulative stability fees
+    pub total_stability_fees_collected: u64,
+
+    // Solvency: fees retained to absorb bad debt, and bad debt not yet covered
+    pub surplus_buffer: u64,
+    pub surplus_buffer_cap: u64,
+    pub deficit: u64,
+    pub total_bad_debt_written_off: u64,
 }
 
 impl ProtocolState {
@@ -82,6 +91,10 @@ impl ProtocolState {
         8 + // config_version
         8 + // created_at
         8 + // updated_at
-        40; // reserved (reduced from 64)
+        8 + // total_stability_fees_collected
+        8 + // surplus_buffer
+        8 + // surplus_buffer_cap
+        8 + // deficit
+        8; // total_bad_debt_written_off
 }
 
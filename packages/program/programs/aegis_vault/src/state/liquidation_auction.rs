@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct LiquidationAuction {
+    /// Vault type the collateral belongs to
+    pub vault_type: Pubkey,
+
+    /// Liquidated position, credited with any collateral left when the auction settles
+    pub position: Pubkey,
+
+    /// Keeper that started the auction and receives its rent back on settlement
+    pub keeper: Pubkey,
+
+    /// Collateral left for sale (in collateral token decimals)
+    pub collateral_amount: u64,
+
+    /// Debt still to be raised in AGSUSD (6 decimals)
+    pub debt_remaining: u64,
+
+    /// Auction price per whole token at the start, in USD (6 decimals)
+    pub start_price: u64,
+
+    /// Price the auction decays to and stays at, in USD (6 decimals)
+    pub floor_price: u64,
+
+    /// Timestamp the auction started at
+    pub start_time: i64,
+
+    /// Seconds for the price to decay from start to floor
+    pub duration_seconds: u64,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl LiquidationAuction {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // vault_type
+        32 + // position
+        32 + // keeper
+        8 +  // collateral_amount
+        8 +  // debt_remaining
+        8 +  // start_price
+        8 +  // floor_price
+        8 +  // start_time
+        8 +  // duration_seconds
+        1;   // bump
+}
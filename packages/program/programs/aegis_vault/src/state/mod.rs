@@ -1,7 +1,19 @@
 pub mod config;
 pub mod vault_type;
 pub mod position;
+pub mod price_feed;
+pub mod price_history;
+pub mod attestation_config;
+pub mod price_override_log;
+pub mod liquidation_auction;
+pub mod stability_pool;
 
 pub use config::*;
 pub use vault_type::*;
 pub use position::*;
+pub use price_feed::*;
+pub use price_history::*;
+pub use attestation_config::*;
+pub use price_override_log::*;
+pub use liquidation_auction::*;
+pub use stability_pool::*;
@@ -11,7 +11,8 @@ pub struct Position {
     /// Amount of collateral deposited (in collateral token decimals)
     pub collateral_amount: u64,
     
-    /// Amount of stablecoin debt (in 6 decimals)
+    /// Normalized stablecoin debt (in 6 decimals).
+    /// Amount owed is `debt_amount * vault_type.cumulative_rate / RATE_PRECISION`.
     pub debt_amount: u64,
     
     /// Timestamp when position was created
@@ -19,6 +20,16 @@ pub struct Position {
     
     /// Timestamp when position was last updated
     pub updated_at: i64,
+
+    // Fields below were appended after the first deployment; `migrate_position`
+    // grows accounts created with the original layout
+
+    /// Share of redistributed collateral and debt, see `utils::redistribution`
+    pub stake: u64,
+
+    /// Vault type's `l_coll` and `l_debt` when redistributions were last applied
+    pub l_coll_snapshot: u128,
+    pub l_debt_snapshot: u128,
 }
 
 impl Position {
@@ -28,5 +39,8 @@ impl Position {
         8 +  // collateral_amount
         8 +  // debt_amount
         8 +  // created_at
-        8;   // updated_at
+        8 +  // updated_at
+        8 +  // stake
+        16 + // l_coll_snapshot
+        16;  // l_debt_snapshot
 }
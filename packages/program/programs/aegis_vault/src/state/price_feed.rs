@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct PriceFeed {
+    /// Vault type this feed prices
+    pub vault_type: Pubkey,
+    
+    /// Latest pushed price in USD (6 decimals)
+    pub price: u64,
+    
+    /// Confidence interval of the latest price in USD (6 decimals)
+    pub conf: u64,
+    
+    /// Timestamp the latest price was observed at
+    pub publish_time: i64,
+    
+    /// Maximum price move between consecutive updates in bps (0 = unbounded)
+    pub max_move_bps: u16,
+    
+    /// PDA bump
+    pub bump: u8,
+    
+    /// Timestamp when the feed was created
+    pub created_at: i64,
+    
+    /// Timestamp when the feed was last updated
+    pub updated_at: i64,
+}
+
+impl PriceFeed {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // vault_type
+        8 +  // price
+        8 +  // conf
+        8 +  // publish_time
+        2 +  // max_move_bps
+        1 +  // bump
+        8 +  // created_at
+        8;   // updated_at
+}
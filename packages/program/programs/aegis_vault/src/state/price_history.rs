@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::constants::oracle::PRICE_HISTORY_CAPACITY;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct PriceObservation {
+    /// Accepted spot price in USD (6 decimals)
+    pub price: u64,
+
+    /// Timestamp the price was recorded at
+    pub timestamp: i64,
+}
+
+#[account]
+pub struct PriceHistory {
+    /// Vault type whose prices are recorded
+    pub vault_type: Pubkey,
+
+    /// Minimum time between consecutive observations
+    pub min_interval_seconds: u32,
+
+    /// Index the next observation is written to
+    pub head: u16,
+
+    /// Number of observations recorded, up to the capacity
+    pub len: u16,
+
+    /// Ring buffer of observations, oldest overwritten first
+    pub observations: [PriceObservation; PRICE_HISTORY_CAPACITY],
+
+    /// PDA bump
+    pub bump: u8,
+
+    /// Timestamp when the history was created
+    pub created_at: i64,
+}
+
+impl PriceHistory {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // vault_type
+        4 +  // min_interval_seconds
+        2 +  // head
+        2 +  // len
+        PRICE_HISTORY_CAPACITY * (8 + 8) + // observations
+        1 +  // bump
+        8;   // created_at
+}
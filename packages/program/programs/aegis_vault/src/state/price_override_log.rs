@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::constants::oracle::PRICE_OVERRIDE_LOG_CAPACITY;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct PriceOverrideEntry {
+    /// Guardian that set or cleared the override
+    pub guardian: Pubkey,
+
+    /// Override price in USD (6 decimals), 0 when cleared
+    pub price: u64,
+
+    /// Last good price the override was bounded against
+    pub reference_price: u64,
+
+    /// Timestamp the entry was recorded at
+    pub set_at: i64,
+
+    /// Timestamp the override expires at
+    pub expires_at: i64,
+}
+
+#[account]
+pub struct PriceOverrideLog {
+    /// Vault type whose overrides are recorded
+    pub vault_type: Pubkey,
+
+    /// Index the next entry is written to
+    pub head: u8,
+
+    /// Number of entries recorded, up to the capacity
+    pub len: u8,
+
+    /// Ring buffer of entries, oldest overwritten first
+    pub entries: [PriceOverrideEntry; PRICE_OVERRIDE_LOG_CAPACITY],
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl PriceOverrideLog {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // vault_type
+        1 +  // head
+        1 +  // len
+        PRICE_OVERRIDE_LOG_CAPACITY * (32 + 8 + 8 + 8 + 8) + // entries
+        1;   // bump
+}
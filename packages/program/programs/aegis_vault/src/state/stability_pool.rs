@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+use crate::constants::liquidation::STABILITY_POOL_SUM_CAPACITY;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct EpochScaleSum {
+    /// Epoch, incremented each time a liquidation empties the pool
+    pub epoch: u64,
+
+    /// Scale, incremented each time P is rescaled within an epoch
+    pub scale: u64,
+
+    /// Collateral gain sum S for this epoch and scale (STABILITY_POOL_PRECISION fixed point)
+    pub sum: u128,
+
+    /// Deposits whose snapshot is at this epoch and scale
+    pub snapshot_count: u64,
+}
+
+#[account]
+pub struct StabilityPool {
+    /// Vault type whose liquidations the pool absorbs
+    pub vault_type: Pubkey,
+
+    /// AGSUSD deposited and not yet used in liquidations (6 decimals)
+    pub total_deposits: u64,
+
+    /// Seized collateral not yet claimed by depositors (in collateral token decimals)
+    pub total_collateral: u64,
+
+    /// Running product P of deposit losses (STABILITY_POOL_PRECISION fixed point)
+    pub product: u128,
+
+    /// Current epoch
+    pub current_epoch: u64,
+
+    /// Current scale within the epoch
+    pub current_scale: u64,
+
+    /// Number of sums recorded; the last one belongs to the current epoch and scale
+    pub sum_count: u8,
+
+    /// Collateral gain sums per epoch and scale still read by a deposit, oldest first
+    pub sums: [EpochScaleSum; STABILITY_POOL_SUM_CAPACITY],
+
+    /// PDA bump
+    pub bump: u8,
+
+    /// Timestamp when the pool was created
+    pub created_at: i64,
+
+    /// Timestamp when the pool was last updated
+    pub updated_at: i64,
+}
+
+impl StabilityPool {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // vault_type
+        8 +  // total_deposits
+        8 +  // total_collateral
+        16 + // product
+        8 +  // current_epoch
+        8 +  // current_scale
+        1 +  // sum_count
+        STABILITY_POOL_SUM_CAPACITY * (8 + 8 + 16 + 8) + // sums
+        1 +  // bump
+        8 +  // created_at
+        8;   // updated_at
+}
+
+#[account]
+pub struct StabilityDeposit {
+    /// Depositor
+    pub owner: Pubkey,
+
+    /// Stability pool the deposit belongs to
+    pub stability_pool: Pubkey,
+
+    /// Deposit at the last snapshot (6 decimals)
+    pub initial_value: u64,
+
+    /// P at the last snapshot
+    pub snapshot_product: u128,
+
+    /// S of the snapshot epoch and scale at the last snapshot
+    pub snapshot_sum: u128,
+
+    /// Epoch at the last snapshot
+    pub snapshot_epoch: u64,
+
+    /// Scale at the last snapshot
+    pub snapshot_scale: u64,
+
+    /// PDA bump
+    pub bump: u8,
+
+    /// Timestamp when the deposit was opened
+    pub created_at: i64,
+
+    /// Timestamp when the deposit was last updated
+    pub updated_at: i64,
+}
+
+impl StabilityDeposit {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // owner
+        32 + // stability_pool
+        8 +  // initial_value
+        16 + // snapshot_product
+        16 + // snapshot_sum
+        8 +  // snapshot_epoch
+        8 +  // snapshot_scale
+        1 +  // bump
+        8 +  // created_at
+        8;   // updated_at
+}
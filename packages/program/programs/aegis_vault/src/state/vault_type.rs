@@ -17,7 +17,7 @@ pub struct VaultType {
 
     // Limits
     pub vault_debt_ceiling: u64,
-    
+
     // PDA bumps
     pub vault_authority_bump: u8,
     
@@ -28,8 +28,92 @@ pub struct VaultType {
     pub created_at: i64,
     pub updated_at: i64,
 
-    // Reserved
-    pub reserved: [u8; 96],
+    // Fields below were appended after the first deployment; `migrate_vault_type`
+    // grows accounts created with the original layout
+
+    // Stability fee accrual
+    pub cumulative_rate: u128,
+    pub last_accrual_ts: i64,
+    pub total_normalized_debt: u64,
+    pub unrealized_stability_fees: u64,
+
+    // Oracle configuration
+    pub oracle_feed_id: [u8; 32],
+    pub oracle_max_age_seconds: u64,
+    pub oracle_price_exponent: i32,
+    pub oracle_verification_level: u8,
+    pub oracle_min_signatures: u8,
+    pub conf_multiplier_bps: u16,
+    pub max_conf_ratio_bps: u16,
+
+    // Collateral mint decimals, for valuing collateral amounts
+    pub collateral_decimals: u8,
+
+    // Secondary oracle (Switchboard On-Demand pull feed)
+    pub secondary_oracle_account: Pubkey,
+    pub secondary_oracle_max_age_seconds: u64,
+    pub oracle_aggregation: u8,
+    pub max_oracle_deviation_bps: u16,
+
+    // Primary price source (`ORACLE_SOURCE_*`)
+    pub oracle_source: u8,
+
+    // Price used for borrowing and liquidation checks (`PRICING_MODE_*`)
+    pub pricing_mode: u8,
+
+    // Circuit breaker
+    pub last_accepted_price: u64,
+    pub last_accepted_price_ts: i64,
+    pub max_price_move_bps_per_minute: u16,
+    pub is_mint_paused: bool,
+    pub circuit_breaker_tripped_at: i64,
+    pub circuit_breaker_trip_price: u64,
+
+    // Stake pool whose exchange rate prices LST collateral
+    pub stake_pool_account: Pubkey,
+
+    // Pegged assets and price band
+    pub is_pegged: bool,
+    pub min_price: u64,
+    pub max_price: u64,
+
+    // Price history (TWAP replaces the provider EMA when the window is non-zero)
+    pub price_history: Pubkey,
+    pub twap_window_seconds: u64,
+
+    // Guardian price override, used instead of the oracle until it expires
+    pub override_price: u64,
+    pub override_set_at: i64,
+    pub override_expires_at: i64,
+
+    // Liquidation
+    pub close_factor_bps: u16,
+    pub target_health_bps: u16,
+    pub full_liquidation_health_bps: u16,
+    pub liquidation_dust_threshold: u64,
+    pub liquidation_mode: u8,
+    pub auction_start_premium_bps: u16,
+    pub auction_floor_bps: u16,
+    pub auction_duration_seconds: u64,
+    pub liquidation_protocol_share_bps: u16,
+    pub keeper_reward: u64,
+    pub keeper_reward_max_debt: u64,
+    /// Health-scaled bonus bounds; a max of 0 keeps the fixed `liq_penalty_bps`
+    pub liquidation_bonus_min_bps: u16,
+    pub liquidation_bonus_max_bps: u16,
+
+    // Redistribution of unliquidatable positions (Liquity L_coll / L_debt)
+    /// Collateral of every position, including redistributed collateral not yet applied
+    pub total_collateral: u64,
+    pub total_stakes: u64,
+    pub total_stakes_snapshot: u64,
+    pub total_collateral_snapshot: u64,
+    /// Redistributed collateral and normalized debt per unit of stake
+    pub l_coll: u128,
+    pub l_debt: u128,
+
+    // Reserved for future fields
+    pub reserved: [u8; 64],
 }
 
 impl VaultType {
@@ -48,5 +132,57 @@ impl VaultType {
         1 + // is_active
         8 + // created_at
         8 + // updated_at
-        96; // reserved
+        16 + // cumulative_rate
+        8 + // last_accrual_ts
+        8 + // total_normalized_debt
+        8 + // unrealized_stability_fees
+        32 + // oracle_feed_id
+        8 + // oracle_max_age_seconds
+        4 + // oracle_price_exponent
+        1 + // oracle_verification_level
+        1 + // oracle_min_signatures
+        2 + // conf_multiplier_bps
+        2 + // max_conf_ratio_bps
+        1 + // collateral_decimals
+        32 + // secondary_oracle_account
+        8 + // secondary_oracle_max_age_seconds
+        1 + // oracle_aggregation
+        2 + // max_oracle_deviation_bps
+        1 + // oracle_source
+        1 + // pricing_mode
+        8 + // last_accepted_price
+        8 + // last_accepted_price_ts
+        2 + // max_price_move_bps_per_minute
+        1 + // is_mint_paused
+        8 + // circuit_breaker_tripped_at
+        8 + // circuit_breaker_trip_price
+        32 + // stake_pool_account
+        1 + // is_pegged
+        8 + // min_price
+        8 + // max_price
+        32 + // price_history
+        8 + // twap_window_seconds
+        8 + // override_price
+        8 + // override_set_at
+        8 + // override_expires_at
+        2 + // close_factor_bps
+        2 + // target_health_bps
+        2 + // full_liquidation_health_bps
+        8 + // liquidation_dust_threshold
+        1 + // liquidation_mode
+        2 + // auction_start_premium_bps
+        2 + // auction_floor_bps
+        8 + // auction_duration_seconds
+        2 + // liquidation_protocol_share_bps
+        8 + // keeper_reward
+        8 + // keeper_reward_max_debt
+        2 + // liquidation_bonus_min_bps
+        2 + // liquidation_bonus_max_bps
+        8 + // total_collateral
+        8 + // total_stakes
+        8 + // total_stakes_snapshot
+        8 + // total_collateral_snapshot
+        16 + // l_coll
+        16 + // l_debt
+        64; // reserved
 }
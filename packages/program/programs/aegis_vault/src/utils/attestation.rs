@@ -0,0 +1,197 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::sysvar::instructions::{self as instructions_sysvar, load_instruction_at_checked};
+use crate::constants::oracle::{
+    ATTESTATION_MESSAGE_LEN, ATTESTATION_MESSAGE_PREFIX, MAX_ATTESTATION_SIGNERS,
+};
+use crate::errors::codes::AegisError;
+use crate::state::AttestationConfig;
+
+// Ed25519SignatureOffsets entries follow the 2-byte header (count, padding)
+const ED25519_HEADER_LEN: usize = 2;
+const ED25519_OFFSETS_LEN: usize = 14;
+
+/// A price message signed by `signer`, as verified by the Ed25519 program.
+pub struct PriceAttestation {
+    pub signer: Pubkey,
+    pub feed_id: [u8; 32],
+    pub price: u64,
+    pub conf: u64,
+    pub timestamp: i64,
+}
+
+/// Decode the price attestations verified by Ed25519 program instructions in the current
+/// transaction. Only signatures whose key and message live in the Ed25519 instruction
+/// itself are read; anything that isn't a well-formed attestation is skipped.
+pub fn verified_attestations(instructions_info: &AccountInfo) -> Result<Vec<PriceAttestation>> {
+    require!(
+        instructions_info.key() == instructions_sysvar::ID,
+        AegisError::InvalidOracleAccount
+    );
+
+    let mut attestations = Vec::new();
+    let mut index = 0;
+    while let Ok(instruction) = load_instruction_at_checked(index, instructions_info) {
+        index += 1;
+        if instruction.program_id != ed25519_program::ID {
+            continue;
+        }
+
+        let data = &instruction.data;
+        let count = data.first().copied().unwrap_or(0) as usize;
+        for i in 0..count {
+            let start = ED25519_HEADER_LEN + i * ED25519_OFFSETS_LEN;
+            let Some(offsets) = data.get(start..start + ED25519_OFFSETS_LEN) else {
+                break;
+            };
+            let read_u16 = |at: usize| u16::from_le_bytes([offsets[at], offsets[at + 1]]);
+
+            // u16::MAX: signature, key and message are in this instruction's data
+            if read_u16(2) != u16::MAX || read_u16(6) != u16::MAX || read_u16(12) != u16::MAX {
+                continue;
+            }
+            let public_key_offset = read_u16(4) as usize;
+            let message_offset = read_u16(8) as usize;
+            if read_u16(10) as usize != ATTESTATION_MESSAGE_LEN {
+                continue;
+            }
+
+            let (Some(public_key), Some(message)) = (
+                data.get(public_key_offset..public_key_offset + 32),
+                data.get(message_offset..message_offset + ATTESTATION_MESSAGE_LEN),
+            ) else {
+                continue;
+            };
+            if message[..8] != ATTESTATION_MESSAGE_PREFIX {
+                continue;
+            }
+
+            let read_u64 = |at: usize| {
+                let mut bytes = [0u8; 8];
+                bytes.copy_from_slice(&message[at..at + 8]);
+                u64::from_le_bytes(bytes)
+            };
+            let mut feed_id = [0u8; 32];
+            feed_id.copy_from_slice(&message[8..40]);
+
+            attestations.push(PriceAttestation {
+                signer: Pubkey::try_from(public_key).map_err(|_| error!(AegisError::InvalidOracleAccount))?,
+                feed_id,
+                price: read_u64(40),
+                conf: read_u64(48),
+                timestamp: read_u64(56) as i64,
+            });
+        }
+    }
+    Ok(attestations)
+}
+
+/// Validate an attestation signer set and pack it into the config's fixed array.
+pub fn pack_attestation_signers(
+    signers: &[Pubkey],
+    threshold: u8,
+) -> Result<[Pubkey; MAX_ATTESTATION_SIGNERS]> {
+    require!(
+        !signers.is_empty() && signers.len() <= MAX_ATTESTATION_SIGNERS,
+        AegisError::InvalidAttestationSigners
+    );
+    require!(
+        threshold > 0 && threshold as usize <= signers.len(),
+        AegisError::InvalidAttestationSigners
+    );
+
+    let mut packed = [Pubkey::default(); MAX_ATTESTATION_SIGNERS];
+    for (i, signer) in signers.iter().enumerate() {
+        require!(
+            *signer != Pubkey::default() && !signers[..i].contains(signer),
+            AegisError::InvalidAttestationSigners
+        );
+        packed[i] = *signer;
+    }
+    Ok(packed)
+}
+
+/// Consumed attestation timestamps for a new signer set: signers that stay keep theirs,
+/// new signers start from zero.
+pub fn carry_attestation_timestamps(
+    config: &AttestationConfig,
+    signers: &[Pubkey; MAX_ATTESTATION_SIGNERS],
+) -> [i64; MAX_ATTESTATION_SIGNERS] {
+    let mut last_attestation_ts = [0; MAX_ATTESTATION_SIGNERS];
+    for (i, signer) in signers.iter().enumerate() {
+        if let Some(j) = config.signers[..config.signer_count as usize]
+            .iter()
+            .position(|previous| previous == signer)
+        {
+            last_attestation_ts[i] = config.last_attestation_ts[j];
+        }
+    }
+    last_attestation_ts
+}
+
+/// Record the attestation consumed from each signer. An attestation older than the last one
+/// consumed from its signer is rejected, so it can't be replayed within its max age once a
+/// newer price has been used.
+pub fn consume_attestations(config: &mut AttestationConfig, attestations: &[PriceAttestation]) -> Result<()> {
+    for attestation in attestations {
+        let Some(i) = config.signers[..config.signer_count as usize]
+            .iter()
+            .position(|signer| *signer == attestation.signer)
+        else {
+            continue;
+        };
+        require!(
+            attestation.timestamp >= config.last_attestation_ts[i],
+            AegisError::AttestationReplayed
+        );
+        config.last_attestation_ts[i] = attestation.timestamp;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(signers: &[Pubkey]) -> AttestationConfig {
+        let mut config = AttestationConfig::try_from_slice(&[0u8; AttestationConfig::LEN - 8]).unwrap();
+        config.signers = pack_attestation_signers(signers, 1).unwrap();
+        config.signer_count = signers.len() as u8;
+        config
+    }
+
+    fn attestation(signer: Pubkey, timestamp: i64) -> PriceAttestation {
+        PriceAttestation { signer, feed_id: [0; 32], price: 2_000_000, conf: 0, timestamp }
+    }
+
+    #[test]
+    fn consume_attestations_rejects_a_replay_after_a_newer_attestation() {
+        let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let mut config = config(&signers);
+
+        consume_attestations(&mut config, &[attestation(signers[0], 100), attestation(signers[1], 90)]).unwrap();
+        assert_eq!(config.last_attestation_ts[..2], [100, 90]);
+
+        // The same attestation again is accepted, an older one is not
+        consume_attestations(&mut config, &[attestation(signers[0], 100)]).unwrap();
+        assert_eq!(
+            consume_attestations(&mut config, &[attestation(signers[0], 99)]).unwrap_err(),
+            AegisError::AttestationReplayed.into()
+        );
+
+        // Each signer is tracked separately
+        consume_attestations(&mut config, &[attestation(signers[1], 95)]).unwrap();
+        assert_eq!(config.last_attestation_ts[..2], [100, 95]);
+    }
+
+    #[test]
+    fn carry_attestation_timestamps_keeps_remaining_signers() {
+        let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let mut config = config(&signers);
+        config.last_attestation_ts[..2].copy_from_slice(&[100, 90]);
+
+        let replacement = Pubkey::new_unique();
+        let packed = pack_attestation_signers(&[replacement, signers[1]], 1).unwrap();
+        assert_eq!(carry_attestation_timestamps(&config, &packed)[..2], [0, 90]);
+    }
+}
@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+use crate::constants::liquidation::{
+    LIQUIDATION_MODE_AUCTION, LIQUIDATION_MODE_FIXED_DISCOUNT, MAX_AUCTION_DURATION_SECONDS,
+    MAX_AUCTION_START_PREMIUM_BPS,
+};
+use crate::errors::codes::AegisError;
+use crate::state::LiquidationAuction;
+use crate::utils::valuation;
+
+/// Validate the liquidation mode and, in auction mode, the auction price curve.
+/// The curve starts at `start_premium_bps` of the oracle price and decays to `floor_bps`.
+pub fn validate_auction_params(
+    liquidation_mode: u8,
+    start_premium_bps: u16,
+    floor_bps: u16,
+    duration_seconds: u64,
+) -> Result<()> {
+    match liquidation_mode {
+        LIQUIDATION_MODE_FIXED_DISCOUNT => Ok(()),
+        LIQUIDATION_MODE_AUCTION => {
+            require!(
+                floor_bps > 0
+                    && floor_bps <= start_premium_bps
+                    && start_premium_bps <= MAX_AUCTION_START_PREMIUM_BPS,
+                AegisError::InvalidLiquidationConfig
+            );
+            require!(
+                duration_seconds > 0 && duration_seconds <= MAX_AUCTION_DURATION_SECONDS,
+                AegisError::InvalidLiquidationConfig
+            );
+            Ok(())
+        }
+        _ => err!(AegisError::InvalidLiquidationConfig),
+    }
+}
+
+/// Current auction price: linear decay from the start price to the floor over the auction
+/// duration, then flat at the floor.
+pub fn auction_price(auction: &LiquidationAuction, now: i64) -> Result<u64> {
+    let elapsed = now.saturating_sub(auction.start_time).max(0) as u64;
+    if elapsed >= auction.duration_seconds {
+        return Ok(auction.floor_price);
+    }
+
+    let decay = ((auction.start_price - auction.floor_price) as u128)
+        .checked_mul(elapsed as u128)
+        .ok_or(AegisError::MathOverflow)?
+        .checked_div(auction.duration_seconds as u128)
+        .ok_or(AegisError::MathOverflow)? as u64;
+    Ok(auction.start_price - decay)
+}
+
+/// Collateral a bid for up to `collateral_amount` buys at `price`, and its cost in AGSUSD.
+/// Never sells more than is needed to cover the remaining debt; buying all of that costs
+/// exactly the remaining debt.
+pub fn auction_fill(
+    auction: &LiquidationAuction,
+    collateral_amount: u64,
+    price: u64,
+    collateral_decimals: u8,
+) -> Result<(u64, u64)> {
+    let needed = valuation::collateral_for_value(auction.debt_remaining, price, collateral_decimals)?;
+    let lot = collateral_amount.min(auction.collateral_amount).min(needed);
+    let cost = if lot == needed {
+        auction.debt_remaining
+    } else {
+        valuation::collateral_value(lot, price, collateral_decimals)?
+    };
+    Ok((lot, cost))
+}
+
+/// Whether an auction can be settled with debt left to raise: its duration has passed,
+/// or what is left can't be bought because the lot or its cost rounds to zero.
+pub fn is_auction_settleable(auction: &LiquidationAuction, now: i64, collateral_decimals: u8) -> Result<bool> {
+    if now >= auction.start_time.saturating_add(auction.duration_seconds as i64) {
+        return Ok(true);
+    }
+
+    let price = auction_price(auction, now)?;
+    let (lot, cost) = auction_fill(auction, auction.collateral_amount, price, collateral_decimals)?;
+    Ok(lot == 0 || cost == 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn auction(collateral_amount: u64, debt_remaining: u64) -> LiquidationAuction {
+        let mut auction = LiquidationAuction::try_from_slice(&[0u8; LiquidationAuction::LEN - 8]).unwrap();
+        auction.collateral_amount = collateral_amount;
+        auction.debt_remaining = debt_remaining;
+        auction.start_price = 49_500_000;
+        auction.floor_price = 40_500_000;
+        auction.start_time = 1_000;
+        auction.duration_seconds = 3_600;
+        auction
+    }
+
+    #[test]
+    fn auction_fill_stops_at_the_remaining_debt() {
+        // $25 of debt at $50 per 9-decimal token needs 0.5 tokens
+        let auction = auction(600_000_000, 25_000_000);
+        assert_eq!(auction_fill(&auction, 100_000_000, 50_000_000, 9).unwrap(), (100_000_000, 5_000_000));
+        assert_eq!(auction_fill(&auction, u64::MAX, 50_000_000, 9).unwrap(), (500_000_000, 25_000_000));
+    }
+
+    #[test]
+    fn auction_is_settleable_once_expired_or_unsellable() {
+        let running = auction(600_000_000, 25_000_000);
+        assert!(!is_auction_settleable(&running, 1_000, 9).unwrap());
+        assert!(!is_auction_settleable(&running, 4_599, 9).unwrap());
+        assert!(is_auction_settleable(&running, 4_600, 9).unwrap());
+
+        // A single base unit left is worth nothing at the auction price
+        let dust_collateral = auction(1, 25_000_000);
+        assert!(is_auction_settleable(&dust_collateral, 1_000, 9).unwrap());
+
+        // $0.00001 of debt doesn't buy a base unit of a 6-decimal token at $49.50
+        let dust_debt = auction(600_000, 10);
+        assert!(is_auction_settleable(&dust_debt, 1_000, 6).unwrap());
+    }
+}
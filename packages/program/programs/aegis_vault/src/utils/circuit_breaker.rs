@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+use crate::errors::codes::AegisError;
+use crate::constants::oracle::ORACLE_SOURCE_GUARDIAN_OVERRIDE;
+use crate::state::VaultType;
+use crate::utils::oracle::OraclePrice;
+
+/// Move of `price` from the vault's last accepted price in bps, when it exceeds the
+/// configured per-minute limit for the time elapsed since (at least one minute).
+/// No move is measured until a baseline price has been accepted.
+pub fn circuit_breaker_move(vault_type: &VaultType, price: u64, now: i64) -> Result<Option<u64>> {
+    if vault_type.max_price_move_bps_per_minute == 0 || vault_type.last_accepted_price_ts == 0 {
+        return Ok(None);
+    }
+
+    let elapsed = now.saturating_sub(vault_type.last_accepted_price_ts).max(60) as u128;
+    let allowed_bps = (vault_type.max_price_move_bps_per_minute as u128)
+        .checked_mul(elapsed)
+        .ok_or(AegisError::MathOverflow)?
+        / 60;
+
+    let move_bps = (price.abs_diff(vault_type.last_accepted_price) as u128)
+        .checked_mul(10_000)
+        .ok_or(AegisError::MathOverflow)?
+        .checked_div(vault_type.last_accepted_price as u128)
+        .ok_or(AegisError::MathOverflow)?;
+
+    if move_bps > allowed_bps {
+        Ok(Some(u64::try_from(move_bps).unwrap_or(u64::MAX)))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Record the oracle price as the last accepted price, rejecting abnormal moves.
+/// Used by mint and withdraw, which take on risk at the new price.
+/// Guardian override prices are neither checked nor recorded.
+pub fn accept_price(vault_type: &mut VaultType, oracle_price: &OraclePrice, now: i64) -> Result<()> {
+    if oracle_price.source == ORACLE_SOURCE_GUARDIAN_OVERRIDE {
+        return Ok(());
+    }
+
+    let price = oracle_price.price;
+    require!(
+        circuit_breaker_move(vault_type, price, now)?.is_none(),
+        AegisError::CircuitBreakerTripped
+    );
+
+    vault_type.last_accepted_price = price;
+    vault_type.last_accepted_price_ts = now;
+    Ok(())
+}
+
+/// Record the oracle price as the last accepted price, or trip the breaker on an abnormal
+/// move without failing. Used by liquidations and price keepers, which must keep working
+/// through the crashes the breaker is there for. Guardian override prices are ignored.
+/// Returns false when the breaker tripped.
+pub fn observe_price(vault_type: &mut VaultType, price: u64, source: u8, now: i64) -> Result<bool> {
+    if source == ORACLE_SOURCE_GUARDIAN_OVERRIDE {
+        return Ok(true);
+    }
+
+    if let Some(move_bps) = circuit_breaker_move(vault_type, price, now)? {
+        trip_circuit_breaker(vault_type, price, move_bps, now);
+        return Ok(false);
+    }
+
+    vault_type.last_accepted_price = price;
+    vault_type.last_accepted_price_ts = now;
+    Ok(true)
+}
+
+/// Pause minting on the vault type until the guardian clears the breaker.
+pub fn trip_circuit_breaker(vault_type: &mut VaultType, price: u64, move_bps: u64, now: i64) {
+    vault_type.is_mint_paused = true;
+    vault_type.circuit_breaker_tripped_at = now;
+    vault_type.circuit_breaker_trip_price = price;
+
+    msg!(
+        "CircuitBreakerTripped: vault={} last_price={} last_price_ts={} price={} move_bps={}",
+        vault_type.collateral_mint,
+        vault_type.last_accepted_price,
+        vault_type.last_accepted_price_ts,
+        price,
+        move_bps
+    );
+}
+
+/// Unpause minting and re-baseline the breaker on the next accepted price. The last
+/// accepted price stays as the reference for guardian overrides until then.
+pub fn reset_circuit_breaker(vault_type: &mut VaultType) {
+    vault_type.is_mint_paused = false;
+    vault_type.last_accepted_price_ts = 0;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::price_override::validate_override_price;
+
+    #[test]
+    fn reset_keeps_the_override_reference_and_rebaselines_the_breaker() {
+        let mut vault_type = VaultType::try_from_slice(&[0u8; VaultType::LEN - 8]).unwrap();
+        vault_type.max_price_move_bps_per_minute = 100; // 1% per minute
+        assert!(observe_price(&mut vault_type, 3_000_000, 0, 1_000).unwrap());
+
+        // +10% within a minute trips the breaker
+        assert!(!observe_price(&mut vault_type, 3_300_000, 0, 1_030).unwrap());
+        assert!(vault_type.is_mint_paused);
+
+        reset_circuit_breaker(&mut vault_type);
+        assert!(!vault_type.is_mint_paused);
+
+        // Overrides are still bounded by 20% around the last good price of $3.00
+        validate_override_price(&vault_type, 3_500_000).unwrap();
+        assert_eq!(
+            validate_override_price(&vault_type, 3_700_000).unwrap_err(),
+            AegisError::PriceOverrideOutOfBounds.into()
+        );
+
+        // The next price becomes the baseline
+        assert!(observe_price(&mut vault_type, 3_300_000, 0, 1_040).unwrap());
+        assert_eq!(vault_type.last_accepted_price, 3_300_000);
+        assert_eq!(
+            accept_price(&mut vault_type, &price(3_700_000), 1_050).unwrap_err(),
+            AegisError::CircuitBreakerTripped.into()
+        );
+    }
+
+    fn price(price: u64) -> OraclePrice {
+        OraclePrice { price, conf: 0, ema_price: price, ema_conf: 0, publish_time: 0, source: 0 }
+    }
+}
@@ -0,0 +1,253 @@
+use anchor_lang::prelude::*;
+use crate::constants::{RATE_PRECISION, SECONDS_PER_YEAR};
+use crate::errors::codes::AegisError;
+use crate::state::{Position, ProtocolState, VaultType};
+
+/// Stability fee for a vault type, falling back to the protocol default.
+pub fn effective_stability_fee_bps(vault_type: &VaultType, protocol: &ProtocolState) -> u16 {
+    if vault_type.stability_fee_bps > 0 {
+        vault_type.stability_fee_bps
+    } else {
+        protocol.base_stability_fee_bps
+    }
+}
+
+/// Compound the vault's cumulative rate up to `now` (Maker `drip`).
+/// The debt created by the rate increase is added to `total_protocol_debt`
+/// and held as unrealized stability fees until collected to the treasury.
+pub fn accrue_interest(
+    vault_type: &mut VaultType,
+    protocol: &mut ProtocolState,
+    now: i64,
+) -> Result<()> {
+    if now <= vault_type.last_accrual_ts {
+        return Ok(());
+    }
+
+    let elapsed = (now - vault_type.last_accrual_ts) as u64;
+    let stability_fee_bps = effective_stability_fee_bps(vault_type, protocol);
+
+    if stability_fee_bps > 0 {
+        // Annual fee compounded per second: rate *= (1 + fee / year) ^ elapsed
+        let per_second_rate = RATE_PRECISION
+            .checked_add(
+                (stability_fee_bps as u128)
+                    .checked_mul(RATE_PRECISION)
+                    .ok_or(AegisError::MathOverflow)?
+                    .checked_div(10_000 * SECONDS_PER_YEAR as u128)
+                    .ok_or(AegisError::MathOverflow)?,
+            )
+            .ok_or(AegisError::MathOverflow)?;
+
+        let old_rate = vault_type.cumulative_rate;
+        let new_rate = rate_mul(old_rate, rate_pow(per_second_rate, elapsed)?)?;
+
+        // Interest accrued across every position in this vault type
+        let old_debt = normalized_to_debt(vault_type.total_normalized_debt, old_rate)?;
+        let new_debt = normalized_to_debt(vault_type.total_normalized_debt, new_rate)?;
+        let accrued = new_debt.saturating_sub(old_debt);
+
+        vault_type.cumulative_rate = new_rate;
+        vault_type.unrealized_stability_fees = vault_type.unrealized_stability_fees
+            .checked_add(accrued)
+            .ok_or(AegisError::MathOverflow)?;
+        protocol.total_protocol_debt = protocol.total_protocol_debt
+            .checked_add(accrued)
+            .ok_or(AegisError::MathOverflow)?;
+    }
+
+    vault_type.last_accrual_ts = now;
+    Ok(())
+}
+
+/// Amount owed for a normalized debt, rounded up in favour of the protocol.
+pub fn normalized_to_debt(normalized: u64, rate: u128) -> Result<u64> {
+    let debt = (normalized as u128)
+        .checked_mul(rate)
+        .ok_or(AegisError::MathOverflow)?
+        .checked_add(RATE_PRECISION - 1)
+        .ok_or(AegisError::MathOverflow)?
+        .checked_div(RATE_PRECISION)
+        .ok_or(AegisError::MathOverflow)?;
+
+    u64::try_from(debt).map_err(|_| error!(AegisError::MathOverflow))
+}
+
+/// Current debt of a position, including accrued stability fees.
+pub fn position_debt(position: &Position, vault_type: &VaultType) -> Result<u64> {
+    normalized_to_debt(position.debt_amount, vault_type.cumulative_rate)
+}
+
+/// Add `amount` of debt to a position at the current rate.
+/// Normalized debt is rounded up so the borrower owes at least `amount`.
+pub fn add_position_debt(
+    position: &mut Position,
+    vault_type: &mut VaultType,
+    amount: u64,
+) -> Result<()> {
+    let normalized = (amount as u128)
+        .checked_mul(RATE_PRECISION)
+        .ok_or(AegisError::MathOverflow)?
+        .checked_add(vault_type.cumulative_rate - 1)
+        .ok_or(AegisError::MathOverflow)?
+        .checked_div(vault_type.cumulative_rate)
+        .ok_or(AegisError::MathOverflow)?;
+    let normalized = u64::try_from(normalized).map_err(|_| error!(AegisError::MathOverflow))?;
+
+    position.debt_amount = position.debt_amount
+        .checked_add(normalized)
+        .ok_or(AegisError::MathOverflow)?;
+    vault_type.total_normalized_debt = vault_type.total_normalized_debt
+        .checked_add(normalized)
+        .ok_or(AegisError::MathOverflow)?;
+    Ok(())
+}
+
+/// Remove `amount` of debt from a position at the current rate.
+/// Repaying the full current debt clears the normalized balance exactly.
+pub fn remove_position_debt(
+    position: &mut Position,
+    vault_type: &mut VaultType,
+    amount: u64,
+) -> Result<()> {
+    let normalized = if amount >= position_debt(position, vault_type)? {
+        position.debt_amount
+    } else {
+        let normalized = (amount as u128)
+            .checked_mul(RATE_PRECISION)
+            .ok_or(AegisError::MathOverflow)?
+            .checked_div(vault_type.cumulative_rate)
+            .ok_or(AegisError::MathOverflow)?;
+        u64::try_from(normalized).map_err(|_| error!(AegisError::MathOverflow))?
+    };
+
+    position.debt_amount = position.debt_amount
+        .checked_sub(normalized)
+        .ok_or(AegisError::MathOverflow)?;
+    vault_type.total_normalized_debt = vault_type.total_normalized_debt.saturating_sub(normalized);
+    Ok(())
+}
+
+fn rate_mul(a: u128, b: u128) -> Result<u128> {
+    a.checked_mul(b)
+        .ok_or(AegisError::MathOverflow)?
+        .checked_div(RATE_PRECISION)
+        .ok_or(error!(AegisError::MathOverflow))
+}
+
+/// Exponentiation by squaring in RATE_PRECISION fixed point.
+fn rate_pow(mut base: u128, mut exp: u64) -> Result<u128> {
+    let mut result = RATE_PRECISION;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = rate_mul(result, base)?;
+        }
+        exp >>= 1;
+        if exp > 0 {
+            base = rate_mul(base, base)?;
+        }
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// (1 + 10% / year) ^ year, compounded per second: 1.1051709179004239...
+    const TEN_PERCENT_YEAR_RATE: u128 = 1_105_170_917_900_423_925;
+
+    fn vault_type(stability_fee_bps: u16, total_normalized_debt: u64) -> VaultType {
+        let mut vault_type = VaultType::try_from_slice(&[0u8; VaultType::LEN - 8]).unwrap();
+        vault_type.stability_fee_bps = stability_fee_bps;
+        vault_type.cumulative_rate = RATE_PRECISION;
+        vault_type.total_normalized_debt = total_normalized_debt;
+        vault_type
+    }
+
+    fn protocol(base_stability_fee_bps: u16) -> ProtocolState {
+        let mut protocol = ProtocolState::try_from_slice(&[0u8; ProtocolState::LEN - 8]).unwrap();
+        protocol.base_stability_fee_bps = base_stability_fee_bps;
+        protocol
+    }
+
+    fn per_second_rate(stability_fee_bps: u128) -> u128 {
+        RATE_PRECISION + stability_fee_bps * RATE_PRECISION / (10_000 * SECONDS_PER_YEAR as u128)
+    }
+
+    #[test]
+    fn rate_pow_matches_integer_powers() {
+        assert_eq!(rate_pow(3 * RATE_PRECISION, 0).unwrap(), RATE_PRECISION);
+        assert_eq!(rate_pow(3 * RATE_PRECISION, 1).unwrap(), 3 * RATE_PRECISION);
+        assert_eq!(rate_pow(2 * RATE_PRECISION, 5).unwrap(), 32 * RATE_PRECISION);
+        assert_eq!(rate_pow(RATE_PRECISION / 2, 3).unwrap(), RATE_PRECISION / 8);
+    }
+
+    #[test]
+    fn rate_pow_compounds_a_year_of_seconds() {
+        let rate = rate_pow(per_second_rate(1_000), SECONDS_PER_YEAR).unwrap();
+        assert!(rate.abs_diff(TEN_PERCENT_YEAR_RATE) < 1_000_000_000, "rate {}", rate);
+    }
+
+    #[test]
+    fn accrue_interest_charges_the_stability_fee() {
+        // 1M AGSUSD of debt at 10% for a year
+        let mut vault_type = vault_type(1_000, 1_000_000_000_000);
+        let mut protocol = protocol(0);
+        protocol.total_protocol_debt = 1_000_000_000_000;
+
+        accrue_interest(&mut vault_type, &mut protocol, SECONDS_PER_YEAR as i64).unwrap();
+
+        assert!(vault_type.cumulative_rate.abs_diff(TEN_PERCENT_YEAR_RATE) < 1_000_000_000);
+        assert_eq!(vault_type.last_accrual_ts, SECONDS_PER_YEAR as i64);
+        assert!(vault_type.unrealized_stability_fees.abs_diff(105_170_917_900) < 1_000);
+        assert_eq!(
+            protocol.total_protocol_debt,
+            1_000_000_000_000 + vault_type.unrealized_stability_fees
+        );
+        assert_eq!(
+            normalized_to_debt(vault_type.total_normalized_debt, vault_type.cumulative_rate).unwrap(),
+            protocol.total_protocol_debt
+        );
+    }
+
+    #[test]
+    fn accrue_interest_falls_back_to_the_protocol_fee() {
+        let mut vault_type = vault_type(0, 1_000_000_000_000);
+        let mut protocol = protocol(1_000);
+
+        accrue_interest(&mut vault_type, &mut protocol, SECONDS_PER_YEAR as i64).unwrap();
+        assert!(vault_type.cumulative_rate.abs_diff(TEN_PERCENT_YEAR_RATE) < 1_000_000_000);
+    }
+
+    #[test]
+    fn accrue_interest_is_idempotent_within_a_timestamp() {
+        let mut vault_type = vault_type(1_000, 1_000_000_000_000);
+        let mut protocol = protocol(0);
+
+        accrue_interest(&mut vault_type, &mut protocol, 60).unwrap();
+        let rate = vault_type.cumulative_rate;
+        let fees = vault_type.unrealized_stability_fees;
+        accrue_interest(&mut vault_type, &mut protocol, 60).unwrap();
+        accrue_interest(&mut vault_type, &mut protocol, 30).unwrap();
+
+        assert_eq!(vault_type.cumulative_rate, rate);
+        assert_eq!(vault_type.unrealized_stability_fees, fees);
+        assert_eq!(vault_type.cumulative_rate, rate_pow(per_second_rate(1_000), 60).unwrap());
+    }
+
+    #[test]
+    fn position_debt_rounds_in_favour_of_the_protocol() {
+        let mut vault_type = vault_type(0, 0);
+        vault_type.cumulative_rate = TEN_PERCENT_YEAR_RATE;
+        let mut position = Position::try_from_slice(&[0u8; Position::LEN - 8]).unwrap();
+
+        add_position_debt(&mut position, &mut vault_type, 1_000_000).unwrap();
+        assert!(position_debt(&position, &vault_type).unwrap() >= 1_000_000);
+
+        let debt = position_debt(&position, &vault_type).unwrap();
+        remove_position_debt(&mut position, &mut vault_type, debt).unwrap();
+        assert_eq!(position.debt_amount, 0);
+        assert_eq!(vault_type.total_normalized_debt, 0);
+    }
+}
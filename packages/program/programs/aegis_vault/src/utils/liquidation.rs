@@ -0,0 +1,230 @@
+use anchor_lang::prelude::*;
+use crate::constants::liquidation::MAX_LIQUIDATION_BONUS_BPS;
+use crate::errors::codes::AegisError;
+use crate::state::VaultType;
+use crate::utils::valuation::{collateral_for_value, collateral_value};
+
+/// Validate the liquidation settings from vault type params.
+/// The target health must be above 1.0 and full liquidation only applies below 1.0.
+pub fn validate_liquidation_params(
+    close_factor_bps: u16,
+    target_health_bps: u16,
+    full_liquidation_health_bps: u16,
+    protocol_share_bps: u16,
+) -> Result<()> {
+    require!(
+        close_factor_bps > 0 && close_factor_bps <= 10_000,
+        AegisError::InvalidLiquidationConfig
+    );
+    require!(
+        target_health_bps == 0 || target_health_bps > 10_000,
+        AegisError::InvalidLiquidationConfig
+    );
+    require!(
+        full_liquidation_health_bps < 10_000,
+        AegisError::InvalidLiquidationConfig
+    );
+    require!(
+        protocol_share_bps <= 10_000,
+        AegisError::InvalidLiquidationConfig
+    );
+    Ok(())
+}
+
+/// Validate the health-scaled bonus bounds. A max of 0 disables the curve.
+pub fn validate_liquidation_bonus(bonus_min_bps: u16, bonus_max_bps: u16) -> Result<()> {
+    require!(
+        bonus_min_bps <= bonus_max_bps && bonus_max_bps <= MAX_LIQUIDATION_BONUS_BPS,
+        AegisError::InvalidLiquidationConfig
+    );
+    Ok(())
+}
+
+/// Liquidation bonus for a position, in bps of the repaid debt. With the bonus curve
+/// enabled it is how far health is below 1.0, bounded by the vault's min and max
+/// (Euler-style); otherwise the fixed `liq_penalty_bps`.
+pub fn liquidation_penalty_bps(vault_type: &VaultType, collateral_value: u64, debt: u64) -> Result<u64> {
+    if vault_type.liquidation_bonus_max_bps == 0 {
+        return Ok(vault_type.liq_penalty_bps);
+    }
+
+    let health = health_bps(collateral_value, debt, vault_type.liq_threshold_bps)?;
+    Ok(10_000u64.saturating_sub(health).clamp(
+        vault_type.liquidation_bonus_min_bps as u64,
+        vault_type.liquidation_bonus_max_bps as u64,
+    ))
+}
+
+/// Health of a position in bps: `collateral_value * liq_threshold / debt`.
+/// Below 10_000 the position can be liquidated.
+pub fn health_bps(collateral_value: u64, debt: u64, liq_threshold_bps: u64) -> Result<u64> {
+    if debt == 0 {
+        return Ok(u64::MAX);
+    }
+
+    let health = (collateral_value as u128)
+        .checked_mul(liq_threshold_bps as u128)
+        .ok_or(AegisError::MathOverflow)?
+        .checked_div(debt as u128)
+        .ok_or(AegisError::MathOverflow)?;
+    Ok(u64::try_from(health).unwrap_or(u64::MAX))
+}
+
+/// Debt to repay for the position to reach `target_health_bps`, when seizing
+/// `repay * (1 + penalty)` of collateral value. None if repaying can't raise health that far.
+///
+/// From `(C - r * (1 + p)) * T / (D - r) = H`: `r = (H * D - C * T) / (H - (1 + p) * T)`.
+fn repay_to_target_health(
+    collateral_value: u64,
+    debt: u64,
+    liq_threshold_bps: u64,
+    target_health_bps: u64,
+    penalty_bps: u64,
+) -> Result<Option<u64>> {
+    let threshold = liq_threshold_bps as i128;
+    let target = target_health_bps as i128;
+
+    let denominator = target * 10_000 - (10_000 + penalty_bps as i128) * threshold;
+    if denominator <= 0 {
+        return Ok(None);
+    }
+
+    let numerator = (target * debt as i128 - collateral_value as i128 * threshold)
+        .checked_mul(10_000)
+        .ok_or(AegisError::MathOverflow)?;
+    if numerator <= 0 {
+        return Ok(Some(0));
+    }
+
+    // Round up so the target is reached
+    let repay = (numerator + denominator - 1) / denominator;
+    Ok(Some(u64::try_from(repay).unwrap_or(u64::MAX)))
+}
+
+/// Most debt a liquidator may repay in one call: the close factor share of the debt, further
+/// limited to what brings the position back to the target health. Dust positions, positions
+/// below the full liquidation health, and repayments that would leave dust behind can be
+/// liquidated in full.
+pub fn max_liquidation_repay(
+    vault_type: &VaultType,
+    debt: u64,
+    collateral_value: u64,
+    penalty_bps: u64,
+) -> Result<u64> {
+    if debt <= vault_type.liquidation_dust_threshold {
+        return Ok(debt);
+    }
+
+    let health = health_bps(collateral_value, debt, vault_type.liq_threshold_bps)?;
+    if health < vault_type.full_liquidation_health_bps as u64 {
+        return Ok(debt);
+    }
+
+    let mut max_repay = (debt as u128)
+        .checked_mul(vault_type.close_factor_bps as u128)
+        .ok_or(AegisError::MathOverflow)?
+        .checked_div(10_000)
+        .ok_or(AegisError::MathOverflow)? as u64;
+
+    if vault_type.target_health_bps > 0 {
+        if let Some(to_target) = repay_to_target_health(
+            collateral_value,
+            debt,
+            vault_type.liq_threshold_bps,
+            vault_type.target_health_bps as u64,
+            penalty_bps,
+        )? {
+            max_repay = max_repay.min(to_target);
+        }
+    }
+
+    if debt.saturating_sub(max_repay) <= vault_type.liquidation_dust_threshold {
+        return Ok(debt);
+    }
+    Ok(max_repay.clamp(1, debt))
+}
+
+/// Debt repaid and collateral seized by a liquidation, with the protocol's share of the
+/// seized penalty.
+pub struct LiquidationSeizure {
+    pub repay: u64,
+    pub collateral: u64,
+    pub protocol_fee: u64,
+}
+
+impl LiquidationSeizure {
+    /// Collateral paid to the liquidator: the repaid value and the rest of the penalty
+    pub fn liquidator_collateral(&self) -> u64 {
+        self.collateral - self.protocol_fee
+    }
+
+    /// Whether the liquidation repaid all the debt the call allowed (`max_repay`) or took
+    /// all of the position's `collateral_amount`. Only these earn the keeper reward, so a
+    /// liquidation split into tiny repays can't collect it over and over.
+    pub fn is_complete(&self, max_repay: u64, collateral_amount: u64) -> bool {
+        self.repay > 0 && (self.repay >= max_repay || self.collateral >= collateral_amount)
+    }
+}
+
+/// Collateral worth `repay * (1 + penalty)` at `payout_price`, taken from the position's
+/// `collateral_amount`. When the position can't cover that, all of its collateral is seized
+/// and the repay shrinks to what the collateral pays for including the penalty; the rest of
+/// the debt stays on the position for write-off or redistribution.
+pub fn liquidation_seizure(
+    vault_type: &VaultType,
+    repay: u64,
+    collateral_amount: u64,
+    payout_price: u64,
+    penalty_bps: u64,
+) -> Result<LiquidationSeizure> {
+    let repay_value_with_penalty = (repay as u128)
+        .checked_mul(10_000 + penalty_bps as u128)
+        .ok_or(AegisError::MathOverflow)?
+        .checked_div(10_000)
+        .ok_or(AegisError::MathOverflow)? as u64;
+    let mut collateral = collateral_for_value(
+        repay_value_with_penalty,
+        payout_price,
+        vault_type.collateral_decimals,
+    )?;
+
+    let mut repay = repay;
+    if collateral > collateral_amount {
+        collateral = collateral_amount;
+        let covered = (collateral_value(collateral, payout_price, vault_type.collateral_decimals)? as u128)
+            .checked_mul(10_000)
+            .ok_or(AegisError::MathOverflow)?
+            .checked_div(10_000 + penalty_bps as u128)
+            .ok_or(AegisError::MathOverflow)?;
+        repay = repay.min(covered as u64);
+    }
+
+    // The penalty is what's left of the seized collateral after the repaid value
+    let base_collateral = collateral_for_value(repay, payout_price, vault_type.collateral_decimals)?
+        .min(collateral);
+    let protocol_fee = ((collateral - base_collateral) as u128)
+        .checked_mul(vault_type.liquidation_protocol_share_bps as u128)
+        .ok_or(AegisError::MathOverflow)?
+        .checked_div(10_000)
+        .ok_or(AegisError::MathOverflow)? as u64;
+
+    Ok(LiquidationSeizure { repay, collateral, protocol_fee })
+}
+
+/// Whether a position's LTV has reached the liquidation threshold.
+/// Positions with debt but no collateral value are always liquidatable.
+pub fn is_liquidatable(collateral_value: u64, debt: u64, liq_threshold_bps: u64) -> Result<bool> {
+    if debt == 0 {
+        return Ok(false);
+    }
+    if collateral_value == 0 {
+        return Ok(true);
+    }
+
+    let ltv_bps = (debt as u128)
+        .checked_mul(10_000)
+        .ok_or(AegisError::MathOverflow)?
+        .checked_div(collateral_value as u128)
+        .ok_or(AegisError::MathOverflow)?;
+    Ok(ltv_bps >= liq_threshold_bps as u128)
+}
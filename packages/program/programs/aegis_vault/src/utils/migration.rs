@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use crate::errors::codes::AegisError;
+
+/// Grow a program account of type `T` created with an older, shorter layout to `new_len`,
+/// topping up rent from `payer`. The appended bytes are zeroed.
+pub fn grow_account<'info, T: Discriminator>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    new_len: usize,
+) -> Result<()> {
+    require!(account.owner == &crate::ID, AegisError::Unauthorized);
+    {
+        let data = account.try_borrow_data()?;
+        require!(data.starts_with(T::DISCRIMINATOR), AegisError::Unauthorized);
+        require!(data.len() < new_len, AegisError::AccountAlreadyMigrated);
+    }
+
+    let rent = Rent::get()?.minimum_balance(new_len).saturating_sub(account.lamports());
+    if rent > 0 {
+        let cpi_accounts = Transfer {
+            from: payer.clone(),
+            to: account.clone(),
+        };
+        system_program::transfer(CpiContext::new(system_program.clone(), cpi_accounts), rent)?;
+    }
+
+    account.resize(new_len)?;
+    Ok(())
+}
@@ -1,3 +1,27 @@
 pub mod oracle;
+pub mod interest;
+pub mod valuation;
+pub mod circuit_breaker;
+pub mod price_history;
+pub mod attestation;
+pub mod price_override;
+pub mod liquidation;
+pub mod auction;
+pub mod stability_pool;
+pub mod solvency;
+pub mod redistribution;
+pub mod migration;
 
 pub use oracle::*;
+pub use interest::*;
+pub use valuation::*;
+pub use circuit_breaker::*;
+pub use price_history::*;
+pub use attestation::*;
+pub use price_override::*;
+pub use liquidation::*;
+pub use auction::*;
+pub use stability_pool::*;
+pub use solvency::*;
+pub use redistribution::*;
+pub use migration::*;
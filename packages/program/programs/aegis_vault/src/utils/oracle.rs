@@ -1,39 +1,886 @@
 use anchor_lang::prelude::*;
-use pyth_solana_receiver_sdk::price_update::{get_feed_id_from_hex, PriceUpdateV2};
+use pyth_solana_receiver_sdk::price_update::{PriceUpdateV2, VerificationLevel};
+use crate::constants::oracle::*;
+use crate::constants::seeds::{ATTESTATION_CONFIG_SEED, PRICE_FEED_SEED};
 use crate::errors::codes::AegisError;
-use crate::state::ProtocolState;
+use crate::state::{AttestationConfig, PriceFeed, ProtocolState, VaultType};
+use crate::utils::attestation::{consume_attestations, verified_attestations, PriceAttestation};
+use crate::utils::price_history::{find_twap, get_twap};
+use crate::utils::valuation::{scale_fixed, scale_price};
 
+/// Oracle spot and EMA prices with their confidence intervals, all in 6 decimals (USD standard).
+/// Sources without an EMA report their spot price as the EMA.
+#[derive(Clone, Copy)]
+pub struct OraclePrice {
+    pub price: u64,
+    pub conf: u64,
+    pub ema_price: u64,
+    pub ema_conf: u64,
+    /// Unix time the price was published (the older source's for a mean)
+    pub publish_time: i64,
+    /// `ORACLE_SOURCE_*` the price came from
+    pub source: u8,
+}
+
+impl OraclePrice {
+    /// Price and confidence backing mint and withdrawal limits
+    fn borrow_price(&self, vault_type: &VaultType) -> (u64, u64) {
+        if vault_type.pricing_mode == PRICING_MODE_CONSERVATIVE && self.ema_price < self.price {
+            (self.ema_price, self.ema_conf)
+        } else {
+            (self.price, self.conf)
+        }
+    }
+
+    /// `price - k * conf`, used to value collateral backing debt (mint, withdraw)
+    pub fn collateral_price(&self, vault_type: &VaultType) -> Result<u64> {
+        let (price, conf) = self.borrow_price(vault_type);
+        Ok(price.saturating_sub(conf_adjustment(conf, vault_type)?))
+    }
+
+    /// Price deciding whether a position can be liquidated
+    pub fn liquidation_check_price(&self, vault_type: &VaultType) -> u64 {
+        match vault_type.pricing_mode {
+            PRICING_MODE_EMA_LIQUIDATION => self.ema_price,
+            PRICING_MODE_CONSERVATIVE => self.price.max(self.ema_price),
+            _ => self.price,
+        }
+    }
+
+    /// `price + k * conf`, used to size collateral paid out to liquidators
+    pub fn liquidation_price(&self, vault_type: &VaultType) -> Result<u64> {
+        self.price
+            .checked_add(conf_adjustment(self.conf, vault_type)?)
+            .ok_or(error!(AegisError::MathOverflow))
+    }
+}
+
+/// Confidence band applied to a price: `k * conf`
+fn conf_adjustment(conf: u64, vault_type: &VaultType) -> Result<u64> {
+    let adjustment = (conf as u128)
+        .checked_mul(vault_type.conf_multiplier_bps as u128)
+        .ok_or(AegisError::MathOverflow)?
+        .checked_div(10_000)
+        .ok_or(AegisError::MathOverflow)?;
+
+    u64::try_from(adjustment).map_err(|_| error!(AegisError::MathOverflow))
+}
+
+/// Validate the price band from vault type params (0 = unbounded).
+pub fn validate_price_band(min_price: u64, max_price: u64) -> Result<()> {
+    require!(
+        min_price == 0 || max_price == 0 || min_price <= max_price,
+        AegisError::InvalidOracleConfig
+    );
+    Ok(())
+}
+
+/// Validate a pricing mode setting from vault type params.
+pub fn validate_pricing_mode(pricing_mode: u8) -> Result<()> {
+    require!(
+        pricing_mode <= PRICING_MODE_CONSERVATIVE,
+        AegisError::InvalidOracleConfig
+    );
+    Ok(())
+}
+
+/// Maximum price age for a vault type, falling back to the protocol TTL.
+pub fn oracle_max_age(vault_type: &VaultType, protocol: &ProtocolState) -> u64 {
+    if vault_type.oracle_max_age_seconds > 0 {
+        vault_type.oracle_max_age_seconds
+    } else {
+        protocol.oracle_ttl_seconds as u64
+    }
+}
+
+/// Maximum secondary price age, falling back to the primary oracle max age.
+pub fn secondary_oracle_max_age(vault_type: &VaultType, protocol: &ProtocolState) -> u64 {
+    if vault_type.secondary_oracle_max_age_seconds > 0 {
+        vault_type.secondary_oracle_max_age_seconds
+    } else {
+        oracle_max_age(vault_type, protocol)
+    }
+}
+
+/// Validate the primary price source of a vault type.
+/// A push feed vault type must point at its own `PriceFeed` PDA, a signed attestation
+/// vault type at its own `AttestationConfig` PDA, and a stake pool vault type needs
+/// both the SOL/USD feed and the stake pool account.
+pub fn validate_oracle_source(
+    source: u8,
+    oracle_price_account: &Pubkey,
+    oracle_feed_id: &[u8; 32],
+    stake_pool_account: &Pubkey,
+    vault_type_key: &Pubkey,
+) -> Result<()> {
+    match source {
+        ORACLE_SOURCE_PYTH => {
+            require!(*oracle_feed_id != [0u8; 32], AegisError::InvalidOracleConfig);
+        }
+        ORACLE_SOURCE_STAKE_POOL => {
+            // oracle_price_account / oracle_feed_id are the SOL/USD Pyth feed
+            require!(*oracle_feed_id != [0u8; 32], AegisError::InvalidOracleConfig);
+            require!(*stake_pool_account != Pubkey::default(), AegisError::InvalidOracleConfig);
+        }
+        ORACLE_SOURCE_PUSH_FEED => {
+            let (price_feed, _) = Pubkey::find_program_address(
+                &[PRICE_FEED_SEED, vault_type_key.as_ref()],
+                &crate::ID,
+            );
+            require!(*oracle_price_account == price_feed, AegisError::InvalidOracleConfig);
+        }
+        ORACLE_SOURCE_SIGNED_ATTESTATION => {
+            require!(*oracle_feed_id != [0u8; 32], AegisError::InvalidOracleConfig);
+            let (attestation_config, _) = Pubkey::find_program_address(
+                &[ATTESTATION_CONFIG_SEED, vault_type_key.as_ref()],
+                &crate::ID,
+            );
+            require!(*oracle_price_account == attestation_config, AegisError::InvalidOracleConfig);
+        }
+        _ => return Err(AegisError::InvalidOracleConfig.into()),
+    }
+    Ok(())
+}
+
+/// Validate a secondary oracle setting from vault type params.
+pub fn validate_oracle_aggregation(
+    aggregation: u8,
+    secondary_oracle_account: &Pubkey,
+    max_deviation_bps: u16,
+) -> Result<()> {
+    match aggregation {
+        ORACLE_AGGREGATION_PRIMARY_ONLY => {}
+        ORACLE_AGGREGATION_FALLBACK | ORACLE_AGGREGATION_MEAN => {
+            require!(
+                *secondary_oracle_account != Pubkey::default(),
+                AegisError::InvalidOracleConfig
+            );
+        }
+        _ => return Err(AegisError::InvalidOracleConfig.into()),
+    }
+    require!(max_deviation_bps <= 10_000, AegisError::InvalidOracleConfig);
+    Ok(())
+}
+
+/// Validate a verification level setting from vault type params.
+pub fn validate_verification_level(level: u8, min_signatures: u8) -> Result<()> {
+    match level {
+        ORACLE_VERIFICATION_FULL => Ok(()),
+        ORACLE_VERIFICATION_PARTIAL => {
+            require!(min_signatures > 0, AegisError::InvalidOracleConfig);
+            Ok(())
+        }
+        _ => Err(AegisError::InvalidOracleConfig.into()),
+    }
+}
+
+/// Minimum Wormhole verification level the vault type accepts.
+pub fn required_verification_level(vault_type: &VaultType) -> VerificationLevel {
+    if vault_type.oracle_verification_level == ORACLE_VERIFICATION_PARTIAL {
+        VerificationLevel::Partial { num_signatures: vault_type.oracle_min_signatures }
+    } else {
+        VerificationLevel::Full
+    }
+}
+
+/// Price of the vault type's collateral, aggregated from the primary (Pyth, push feed or
+/// stake pool) and optional secondary (Switchboard) oracle per `vault_type.oracle_aggregation`.
+/// The secondary feed, stake pool account and price history are passed in `remaining_accounts`.
+/// With a TWAP window configured, the TWAP replaces the provider EMA. An active guardian
+/// override takes precedence over all sources.
 pub fn get_oracle_price(
     oracle_info: &AccountInfo,
+    remaining_accounts: &[AccountInfo],
+    vault_type: &VaultType,
+    protocol: &ProtocolState,
+) -> Result<OraclePrice> {
+    resolve_oracle_price(oracle_info, remaining_accounts, vault_type, protocol, false)
+}
+
+/// Like `get_oracle_price`, but while the price history can't cover the TWAP window yet the
+/// provider EMA (spot for sources without one) stands in for the TWAP, so liquidations keep
+/// working while the history fills up.
+pub fn get_liquidation_oracle_price(
+    oracle_info: &AccountInfo,
+    remaining_accounts: &[AccountInfo],
+    vault_type: &VaultType,
+    protocol: &ProtocolState,
+) -> Result<OraclePrice> {
+    resolve_oracle_price(oracle_info, remaining_accounts, vault_type, protocol, true)
+}
+
+fn resolve_oracle_price(
+    oracle_info: &AccountInfo,
+    remaining_accounts: &[AccountInfo],
+    vault_type: &VaultType,
+    protocol: &ProtocolState,
+    twap_fallback: bool,
+) -> Result<OraclePrice> {
+    let now = Clock::get()?.unix_timestamp;
+    if let Some(oracle_price) = active_price_override(vault_type, now) {
+        return apply_price_bounds(oracle_price, vault_type);
+    }
+
+    let mut oracle_price = aggregate_oracle_price(oracle_info, remaining_accounts, vault_type, protocol)?;
+    if vault_type.twap_window_seconds > 0 {
+        let twap = if twap_fallback {
+            find_twap(remaining_accounts, vault_type, now)?
+        } else {
+            Some(get_twap(remaining_accounts, vault_type, now)?)
+        };
+        if let Some(twap) = twap {
+            oracle_price.ema_price = twap;
+            oracle_price.ema_conf = oracle_price.conf;
+        }
+    }
+    apply_price_bounds(oracle_price, vault_type)
+}
+
+/// Like `get_oracle_price`, without the TWAP. Used to feed the price history and
+/// circuit breaker, which must keep working while the history fills up.
+pub fn get_spot_oracle_price(
+    oracle_info: &AccountInfo,
+    remaining_accounts: &[AccountInfo],
+    vault_type: &VaultType,
+    protocol: &ProtocolState,
+) -> Result<OraclePrice> {
+    let oracle_price = aggregate_oracle_price(oracle_info, remaining_accounts, vault_type, protocol)?;
+    apply_price_bounds(oracle_price, vault_type)
+}
+
+/// The guardian override price, until it expires.
+fn active_price_override(vault_type: &VaultType, now: i64) -> Option<OraclePrice> {
+    if vault_type.override_price == 0 || now >= vault_type.override_expires_at {
+        return None;
+    }
+
+    Some(OraclePrice {
+        price: vault_type.override_price,
+        conf: 0,
+        ema_price: vault_type.override_price,
+        ema_conf: 0,
+        publish_time: vault_type.override_set_at,
+        source: ORACLE_SOURCE_GUARDIAN_OVERRIDE,
+    })
+}
+
+/// Reject prices outside the vault's [min, max] band, then cap pegged assets at $1.00
+/// so an upward depeg can't be borrowed against. Prices below the peg are kept.
+fn apply_price_bounds(mut oracle_price: OraclePrice, vault_type: &VaultType) -> Result<OraclePrice> {
+    require!(
+        vault_type.min_price == 0 || oracle_price.price >= vault_type.min_price,
+        AegisError::OraclePriceOutOfBand
+    );
+    require!(
+        vault_type.max_price == 0 || oracle_price.price <= vault_type.max_price,
+        AegisError::OraclePriceOutOfBand
+    );
+
+    if vault_type.is_pegged {
+        oracle_price.price = oracle_price.price.min(PEGGED_PRICE_CAP);
+        oracle_price.ema_price = oracle_price.ema_price.min(PEGGED_PRICE_CAP);
+    }
+    Ok(oracle_price)
+}
+
+fn aggregate_oracle_price(
+    oracle_info: &AccountInfo,
+    remaining_accounts: &[AccountInfo],
+    vault_type: &VaultType,
+    protocol: &ProtocolState,
+) -> Result<OraclePrice> {
+    let primary = match vault_type.oracle_source {
+        ORACLE_SOURCE_PUSH_FEED => get_push_feed_price(oracle_info, vault_type, protocol),
+        ORACLE_SOURCE_SIGNED_ATTESTATION => {
+            get_attested_price(oracle_info, remaining_accounts, vault_type, protocol)
+        }
+        ORACLE_SOURCE_STAKE_POOL => {
+            get_stake_pool_price(oracle_info, remaining_accounts, vault_type, protocol)
+        }
+        _ => get_pyth_price(oracle_info, vault_type, protocol),
+    };
+    if vault_type.oracle_aggregation == ORACLE_AGGREGATION_PRIMARY_ONLY {
+        return primary;
+    }
+
+    let secondary = match remaining_accounts
+        .iter()
+        .find(|account| account.key() == vault_type.secondary_oracle_account)
+    {
+        Some(feed_info) => get_switchboard_price(feed_info, vault_type, protocol),
+        None => Err(AegisError::InvalidOracleAccount.into()),
+    };
+
+    match (primary, secondary) {
+        (Ok(primary), Ok(secondary)) => {
+            check_deviation(&primary, &secondary, vault_type)?;
+            if vault_type.oracle_aggregation == ORACLE_AGGREGATION_MEAN {
+                // Mean of the two sources; keep the wider confidence
+                let price = (primary.price as u128 + secondary.price as u128) / 2;
+                let ema_price = (primary.ema_price as u128 + secondary.ema_price as u128) / 2;
+                Ok(OraclePrice {
+                    price: price as u64,
+                    conf: primary.conf.max(secondary.conf),
+                    ema_price: ema_price as u64,
+                    ema_conf: primary.ema_conf.max(secondary.ema_conf),
+                    publish_time: primary.publish_time.min(secondary.publish_time),
+                    source: primary.source,
+                })
+            } else {
+                Ok(primary)
+            }
+        }
+        (Ok(primary), Err(_)) if vault_type.oracle_aggregation == ORACLE_AGGREGATION_FALLBACK => {
+            Ok(primary)
+        }
+        (Err(_), Ok(secondary)) if vault_type.oracle_aggregation == ORACLE_AGGREGATION_FALLBACK => {
+            msg!(
+                "OracleFallback: vault={} secondary={} price={}",
+                vault_type.collateral_mint,
+                vault_type.secondary_oracle_account,
+                secondary.price
+            );
+            Ok(secondary)
+        }
+        (Err(err), _) | (_, Err(err)) => Err(err),
+    }
+}
+
+/// Reject the pair when the sources disagree by more than `max_oracle_deviation_bps`.
+fn check_deviation(primary: &OraclePrice, secondary: &OraclePrice, vault_type: &VaultType) -> Result<()> {
+    if vault_type.max_oracle_deviation_bps == 0 {
+        return Ok(());
+    }
+
+    let low = primary.price.min(secondary.price);
+    let high = primary.price.max(secondary.price);
+    let deviation_bps = ((high - low) as u128)
+        .checked_mul(10_000)
+        .ok_or(AegisError::MathOverflow)?
+        .checked_div(low as u128)
+        .ok_or(AegisError::MathOverflow)?;
+
+    require!(
+        deviation_bps <= vault_type.max_oracle_deviation_bps as u128,
+        AegisError::OracleDeviationTooHigh
+    );
+    Ok(())
+}
+
+/// Reject prices whose confidence interval is too wide relative to the price.
+fn check_confidence(oracle_price: &OraclePrice, vault_type: &VaultType) -> Result<()> {
+    if vault_type.max_conf_ratio_bps > 0 {
+        let conf_ratio_bps = (oracle_price.conf as u128)
+            .checked_mul(10_000)
+            .ok_or(AegisError::MathOverflow)?
+            .checked_div(oracle_price.price as u128)
+            .ok_or(AegisError::MathOverflow)?;
+        require!(
+            conf_ratio_bps <= vault_type.max_conf_ratio_bps as u128,
+            AegisError::OracleConfidenceTooWide
+        );
+    }
+    Ok(())
+}
+
+/// Reject prices published more than `max_age` seconds ago.
+fn check_freshness(publish_time: i64, max_age: u64) -> Result<()> {
+    let max_age = i64::try_from(max_age).unwrap_or(i64::MAX);
+    require!(
+        publish_time.saturating_add(max_age) >= Clock::get()?.unix_timestamp,
+        AegisError::OracleStale
+    );
+    Ok(())
+}
+
+/// Decode the vault type's primary price without the freshness, verification and
+/// confidence checks applied by `get_oracle_price`. Used for display only.
+pub fn read_primary_price(
+    oracle_info: &AccountInfo,
+    remaining_accounts: &[AccountInfo],
+    vault_type: &VaultType,
+) -> Result<OraclePrice> {
+    match vault_type.oracle_source {
+        ORACLE_SOURCE_PUSH_FEED => read_push_feed_price(oracle_info),
+        ORACLE_SOURCE_SIGNED_ATTESTATION => {
+            read_attested_price(oracle_info, remaining_accounts, vault_type)
+                .map(|(oracle_price, _)| oracle_price)
+        }
+        ORACLE_SOURCE_STAKE_POOL => {
+            let sol_price = read_pyth_price(&load_pyth_update(oracle_info)?, vault_type)?;
+            let (total_lamports, pool_token_supply, _) =
+                read_stake_pool_rate(remaining_accounts, vault_type)?;
+            to_pool_token_price(&sol_price, total_lamports, pool_token_supply)
+        }
+        _ => read_pyth_price(&load_pyth_update(oracle_info)?, vault_type),
+    }
+}
+
+fn read_push_feed_price(feed_info: &AccountInfo) -> Result<OraclePrice> {
+    require!(feed_info.owner == &crate::ID, AegisError::InvalidOracleAccount);
+
+    let price_feed = PriceFeed::try_deserialize(&mut &feed_info.data.borrow()[..])
+        .map_err(|_| error!(AegisError::InvalidOracleAccount))?;
+    require!(price_feed.price > 0, AegisError::OracleNegativePrice);
+
+    Ok(OraclePrice {
+        price: price_feed.price,
+        conf: price_feed.conf,
+        ema_price: price_feed.price,
+        ema_conf: price_feed.conf,
+        publish_time: price_feed.publish_time,
+        source: ORACLE_SOURCE_PUSH_FEED,
+    })
+}
+
+/// Read the vault type's program-owned `PriceFeed`, updated by the oracle authority.
+pub fn get_push_feed_price(
+    feed_info: &AccountInfo,
+    vault_type: &VaultType,
     protocol: &ProtocolState,
-) -> Result<u64> {
-    // Load the price update account
-    let price_update = PriceUpdateV2::try_deserialize(&mut &oracle_info.data.borrow()[..])
-        .map_err(|_| error!(AegisError::OracleStale))?;
-    
-    // Get the price feed for SOL/USD
-    // SOL/USD feed ID: 0xef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d
-    let feed_id = get_feed_id_from_hex("ef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d")
-        .map_err(|_| error!(AegisError::OracleStale))?;
-    
-    let price_feed = price_update.get_price_no_older_than(
-        &Clock::get()?,
-        protocol.oracle_ttl_seconds as u64,
-        &feed_id
-    ).map_err(|_| error!(AegisError::OracleStale))?;
-    
-    // Convert price to u64 with 6 decimals
-    // Pyth prices have a variable exponent, we need to normalize to 6 decimals
-    let price_i64 = price_feed.price;
-    let expo = price_feed.exponent;
-    
-    // Convert to 6 decimal places (USD standard)
-    // Formula: price * 10^(6 - expo)
-    let price_f64 = (price_i64 as f64) * 10f64.powi(6 + expo);
-    
-    if price_f64 < 0.0 {
-        return Err(AegisError::OracleStale.into());
-    }
-    
-    Ok(price_f64 as u64)
+) -> Result<OraclePrice> {
+    let oracle_price = read_push_feed_price(feed_info)?;
+    check_freshness(oracle_price.publish_time, oracle_max_age(vault_type, protocol))?;
+    check_confidence(&oracle_price, vault_type)?;
+    Ok(oracle_price)
+}
+
+/// Median of the attestations by distinct configured signers for the vault's feed,
+/// verified through the instructions sysvar passed in `remaining_accounts`. Returns the
+/// attestation used from each signer along with the price.
+fn read_attested_price(
+    config_info: &AccountInfo,
+    remaining_accounts: &[AccountInfo],
+    vault_type: &VaultType,
+) -> Result<(OraclePrice, Vec<PriceAttestation>)> {
+    require!(config_info.owner == &crate::ID, AegisError::InvalidOracleAccount);
+    let config = AttestationConfig::try_deserialize(&mut &config_info.data.borrow()[..])
+        .map_err(|_| error!(AegisError::InvalidOracleAccount))?;
+
+    let instructions_info = remaining_accounts
+        .iter()
+        .find(|account| account.key() == anchor_lang::solana_program::sysvar::instructions::ID)
+        .ok_or(AegisError::InvalidOracleAccount)?;
+
+    // Latest attestation of each configured signer for this feed
+    let signers = &config.signers[..config.signer_count as usize];
+    let mut attestations: Vec<PriceAttestation> = Vec::new();
+    for attestation in verified_attestations(instructions_info)? {
+        if attestation.feed_id != vault_type.oracle_feed_id || !signers.contains(&attestation.signer) {
+            continue;
+        }
+        require!(attestation.price > 0, AegisError::OracleNegativePrice);
+        match attestations.iter_mut().find(|seen| seen.signer == attestation.signer) {
+            Some(seen) if seen.timestamp < attestation.timestamp => *seen = attestation,
+            Some(_) => {}
+            None => attestations.push(attestation),
+        }
+    }
+    require!(
+        !attestations.is_empty() && attestations.len() >= config.threshold as usize,
+        AegisError::AttestationThresholdNotMet
+    );
+
+    // Lower median price, widest confidence and oldest timestamp
+    attestations.sort_by_key(|attestation| attestation.price);
+    let price = attestations[(attestations.len() - 1) / 2].price;
+    let conf = attestations.iter().map(|attestation| attestation.conf).max().unwrap_or(0);
+    let publish_time = attestations
+        .iter()
+        .map(|attestation| attestation.timestamp)
+        .min()
+        .unwrap_or(0);
+
+    let oracle_price = OraclePrice {
+        price,
+        conf,
+        ema_price: price,
+        ema_conf: conf,
+        publish_time,
+        source: ORACLE_SOURCE_SIGNED_ATTESTATION,
+    };
+    Ok((oracle_price, attestations))
+}
+
+/// Price from Ed25519-signed attestations submitted in the same transaction. The attestation
+/// config records the attestation consumed from each signer, so it must be writable (pass it
+/// writable in `remaining_accounts`).
+pub fn get_attested_price(
+    config_info: &AccountInfo,
+    remaining_accounts: &[AccountInfo],
+    vault_type: &VaultType,
+    protocol: &ProtocolState,
+) -> Result<OraclePrice> {
+    let (oracle_price, attestations) = read_attested_price(config_info, remaining_accounts, vault_type)?;
+    check_freshness(oracle_price.publish_time, oracle_max_age(vault_type, protocol))?;
+    check_confidence(&oracle_price, vault_type)?;
+
+    require!(config_info.is_writable, AegisError::InvalidOracleAccount);
+    let mut data = config_info.try_borrow_mut_data()?;
+    let mut config = AttestationConfig::try_deserialize(&mut &data[..])
+        .map_err(|_| error!(AegisError::InvalidOracleAccount))?;
+    consume_attestations(&mut config, &attestations)?;
+    config.try_serialize(&mut &mut data[..])?;
+    Ok(oracle_price)
+}
+
+/// Read the stake pool's `(total_lamports, pool_token_supply, last_update_epoch)`.
+fn read_stake_pool_rate(
+    remaining_accounts: &[AccountInfo],
+    vault_type: &VaultType,
+) -> Result<(u64, u64, u64)> {
+    let stake_pool_info = remaining_accounts
+        .iter()
+        .find(|account| account.key() == vault_type.stake_pool_account)
+        .ok_or(AegisError::InvalidOracleAccount)?;
+    require!(
+        stake_pool_info.owner == &SPL_STAKE_POOL_PROGRAM_ID,
+        AegisError::InvalidOracleAccount
+    );
+
+    let data = stake_pool_info.try_borrow_data()?;
+    require!(
+        data.len() >= STAKE_POOL_LAST_UPDATE_EPOCH_OFFSET + 8
+            && data[0] == STAKE_POOL_ACCOUNT_TYPE,
+        AegisError::InvalidOracleAccount
+    );
+
+    // The pool must mint the vault's collateral
+    require!(
+        data[STAKE_POOL_POOL_MINT_OFFSET..STAKE_POOL_POOL_MINT_OFFSET + 32]
+            == vault_type.collateral_mint.to_bytes(),
+        AegisError::InvalidOracleAccount
+    );
+
+    let read_u64 = |offset: usize| {
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&data[offset..offset + 8]);
+        u64::from_le_bytes(bytes)
+    };
+    let total_lamports = read_u64(STAKE_POOL_TOTAL_LAMPORTS_OFFSET);
+    let pool_token_supply = read_u64(STAKE_POOL_POOL_TOKEN_SUPPLY_OFFSET);
+    require!(total_lamports > 0 && pool_token_supply > 0, AegisError::OracleStale);
+
+    Ok((total_lamports, pool_token_supply, read_u64(STAKE_POOL_LAST_UPDATE_EPOCH_OFFSET)))
+}
+
+fn to_pool_token_price(
+    sol_price: &OraclePrice,
+    total_lamports: u64,
+    pool_token_supply: u64,
+) -> Result<OraclePrice> {
+    let to_pool_token = |sol_value: u64| -> Result<u64> {
+        let value = (sol_value as u128)
+            .checked_mul(total_lamports as u128)
+            .ok_or(AegisError::MathOverflow)?
+            .checked_div(pool_token_supply as u128)
+            .ok_or(AegisError::MathOverflow)?;
+        u64::try_from(value).map_err(|_| error!(AegisError::MathOverflow))
+    };
+
+    Ok(OraclePrice {
+        price: to_pool_token(sol_price.price)?,
+        conf: to_pool_token(sol_price.conf)?,
+        ema_price: to_pool_token(sol_price.ema_price)?,
+        ema_conf: to_pool_token(sol_price.ema_conf)?,
+        publish_time: sol_price.publish_time,
+        source: ORACLE_SOURCE_STAKE_POOL,
+    })
+}
+
+/// Price an SPL stake pool token as `SOL/USD * total_lamports / pool_token_supply`.
+/// The SOL/USD price goes through the usual Pyth checks and the stake pool must
+/// have been updated in the current epoch.
+pub fn get_stake_pool_price(
+    oracle_info: &AccountInfo,
+    remaining_accounts: &[AccountInfo],
+    vault_type: &VaultType,
+    protocol: &ProtocolState,
+) -> Result<OraclePrice> {
+    let sol_price = get_pyth_price(oracle_info, vault_type, protocol)?;
+    let (total_lamports, pool_token_supply, last_update_epoch) =
+        read_stake_pool_rate(remaining_accounts, vault_type)?;
+
+    // Like the stake pool program itself, only trust totals updated this epoch
+    require!(last_update_epoch == Clock::get()?.epoch, AegisError::OracleStale);
+
+    to_pool_token_price(&sol_price, total_lamports, pool_token_supply)
+}
+
+/// Read a Switchboard On-Demand pull feed. The feed's 18-decimal median is
+/// used as the price and its standard deviation as the confidence.
+pub fn get_switchboard_price(
+    feed_info: &AccountInfo,
+    vault_type: &VaultType,
+    protocol: &ProtocolState,
+) -> Result<OraclePrice> {
+    require!(
+        feed_info.owner == &SWITCHBOARD_ON_DEMAND_PROGRAM_ID,
+        AegisError::InvalidOracleAccount
+    );
+
+    let data = feed_info.try_borrow_data()?;
+    require!(
+        data.len() >= SWITCHBOARD_PULL_FEED_LEN
+            && data[..8] == SWITCHBOARD_PULL_FEED_DISCRIMINATOR,
+        AegisError::InvalidOracleAccount
+    );
+
+    let read_i128 = |offset: usize| {
+        let mut bytes = [0u8; 16];
+        bytes.copy_from_slice(&data[offset..offset + 16]);
+        i128::from_le_bytes(bytes)
+    };
+    let mut ts_bytes = [0u8; 8];
+    ts_bytes.copy_from_slice(&data[SWITCHBOARD_LAST_UPDATE_OFFSET..SWITCHBOARD_LAST_UPDATE_OFFSET + 8]);
+    let last_update = i64::from_le_bytes(ts_bytes);
+    let value = read_i128(SWITCHBOARD_RESULT_VALUE_OFFSET);
+    let std_dev = read_i128(SWITCHBOARD_RESULT_STD_DEV_OFFSET);
+
+    check_freshness(last_update, secondary_oracle_max_age(vault_type, protocol))?;
+    require!(value > 0, AegisError::OracleNegativePrice);
+    require!(std_dev >= 0, AegisError::InvalidOracleAccount);
+
+    let price = scale_fixed(value as u128, SWITCHBOARD_DECIMALS, PRICE_DECIMALS as u32)?;
+    let conf = scale_fixed(std_dev as u128, SWITCHBOARD_DECIMALS, PRICE_DECIMALS as u32)?;
+    require!(price > 0, AegisError::OracleNegativePrice);
+
+    let oracle_price = OraclePrice {
+        price,
+        conf,
+        ema_price: price,
+        ema_conf: conf,
+        publish_time: last_update,
+        source: ORACLE_SOURCE_SWITCHBOARD,
+    };
+    check_confidence(&oracle_price, vault_type)?;
+    Ok(oracle_price)
+}
+
+fn load_pyth_update(oracle_info: &AccountInfo) -> Result<PriceUpdateV2> {
+    // Only accept price updates posted by the Pyth receiver program
+    require!(
+        oracle_info.owner == &pyth_solana_receiver_sdk::ID,
+        AegisError::InvalidOracleAccount
+    );
+
+    PriceUpdateV2::try_deserialize(&mut &oracle_info.data.borrow()[..])
+        .map_err(|_| error!(AegisError::InvalidOracleAccount))
+}
+
+/// Spot and EMA price of a Pyth update, normalized to 6 decimals.
+fn read_pyth_price(price_update: &PriceUpdateV2, vault_type: &VaultType) -> Result<OraclePrice> {
+    let message = &price_update.price_message;
+
+    // The update must carry the price feed configured for this vault type
+    require!(
+        message.feed_id == vault_type.oracle_feed_id,
+        AegisError::OracleFeedMismatch
+    );
+
+    // Reject feeds whose exponent differs from the one the vault was configured with
+    require!(
+        message.exponent == vault_type.oracle_price_exponent,
+        AegisError::InvalidOracleConfig
+    );
+    require!(
+        message.price > 0 && message.ema_price > 0,
+        AegisError::OracleNegativePrice
+    );
+
+    // Pyth prices have a variable exponent, normalize to 6 decimals (USD standard)
+    // with exact integer scaling: price * 10^(6 + expo)
+    let price = scale_price(message.price as u64, message.exponent, PRICE_DECIMALS)?;
+    let conf = scale_price(message.conf, message.exponent, PRICE_DECIMALS)?;
+    let ema_price = scale_price(message.ema_price as u64, message.exponent, PRICE_DECIMALS)?;
+    let ema_conf = scale_price(message.ema_conf, message.exponent, PRICE_DECIMALS)?;
+    require!(price > 0 && ema_price > 0, AegisError::OracleNegativePrice);
+
+    Ok(OraclePrice {
+        price,
+        conf,
+        ema_price,
+        ema_conf,
+        publish_time: message.publish_time,
+        source: ORACLE_SOURCE_PYTH,
+    })
+}
+
+/// Read the vault type's Pyth price update.
+pub fn get_pyth_price(
+    oracle_info: &AccountInfo,
+    vault_type: &VaultType,
+    protocol: &ProtocolState,
+) -> Result<OraclePrice> {
+    let price_update = load_pyth_update(oracle_info)?;
+
+    require!(
+        price_update
+            .verification_level
+            .gte(required_verification_level(vault_type)),
+        AegisError::OracleVerificationTooLow
+    );
+
+    let oracle_price = read_pyth_price(&price_update, vault_type)?;
+    check_freshness(oracle_price.publish_time, oracle_max_age(vault_type, protocol))?;
+    check_confidence(&oracle_price, vault_type)?;
+    Ok(oracle_price)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vault_type(conf_multiplier_bps: u16, max_conf_ratio_bps: u16) -> VaultType {
+        let mut vault_type = VaultType::try_from_slice(&[0u8; VaultType::LEN - 8]).unwrap();
+        vault_type.conf_multiplier_bps = conf_multiplier_bps;
+        vault_type.max_conf_ratio_bps = max_conf_ratio_bps;
+        vault_type
+    }
+
+    fn oracle_price(price: u64, conf: u64) -> OraclePrice {
+        OraclePrice {
+            price,
+            conf,
+            ema_price: price,
+            ema_conf: conf,
+            publish_time: 0,
+            source: ORACLE_SOURCE_PYTH,
+        }
+    }
+
+    #[test]
+    fn conf_multiplier_widens_the_valuation_band() {
+        // $150 +/- $1
+        let price = oracle_price(150_000_000, 1_000_000);
+
+        let ignored = vault_type(0, 0);
+        assert_eq!(price.collateral_price(&ignored).unwrap(), 150_000_000);
+        assert_eq!(price.liquidation_price(&ignored).unwrap(), 150_000_000);
+
+        let one_conf = vault_type(10_000, 0);
+        assert_eq!(price.collateral_price(&one_conf).unwrap(), 149_000_000);
+        assert_eq!(price.liquidation_price(&one_conf).unwrap(), 151_000_000);
+
+        let two_and_a_half_conf = vault_type(25_000, 0);
+        assert_eq!(price.collateral_price(&two_and_a_half_conf).unwrap(), 147_500_000);
+        assert_eq!(price.liquidation_price(&two_and_a_half_conf).unwrap(), 152_500_000);
+    }
+
+    #[test]
+    fn collateral_price_floors_at_zero() {
+        let price = oracle_price(1_000_000, 600_000);
+        assert_eq!(price.collateral_price(&vault_type(20_000, 0)).unwrap(), 0);
+    }
+
+    #[test]
+    fn max_conf_ratio_rejects_wide_intervals() {
+        // 2% cap: a 2% interval passes, 2.01% fails
+        let capped = vault_type(10_000, 200);
+        check_confidence(&oracle_price(150_000_000, 3_000_000), &capped).unwrap();
+        assert_eq!(
+            check_confidence(&oracle_price(150_000_000, 3_015_000), &capped).unwrap_err(),
+            AegisError::OracleConfidenceTooWide.into()
+        );
+
+        // 0 disables the check
+        check_confidence(&oracle_price(150_000_000, 150_000_000), &vault_type(10_000, 0)).unwrap();
+    }
+
+    fn with_ema(price: u64, ema_price: u64) -> OraclePrice {
+        OraclePrice {
+            ema_price,
+            ema_conf: 2_000_000,
+            ..oracle_price(price, 1_000_000)
+        }
+    }
+
+    #[test]
+    fn ema_liquidation_mode_checks_health_at_the_ema() {
+        let mut vault_type = vault_type(10_000, 0);
+        vault_type.pricing_mode = PRICING_MODE_EMA_LIQUIDATION;
+
+        // A wick below the EMA doesn't make positions liquidatable
+        let wick = with_ema(80_000_000, 120_000_000);
+        assert_eq!(wick.liquidation_check_price(&vault_type), 120_000_000);
+        // Borrowing and liquidator payouts still use the spot price
+        assert_eq!(wick.collateral_price(&vault_type).unwrap(), 79_000_000);
+        assert_eq!(wick.liquidation_price(&vault_type).unwrap(), 81_000_000);
+
+        vault_type.pricing_mode = PRICING_MODE_SPOT;
+        assert_eq!(wick.liquidation_check_price(&vault_type), 80_000_000);
+    }
+
+    #[test]
+    fn conservative_mode_borrows_at_the_lower_price_and_liquidates_at_the_higher() {
+        let mut vault_type = vault_type(10_000, 0);
+        vault_type.pricing_mode = PRICING_MODE_CONSERVATIVE;
+
+        // Spot above the EMA: borrow against the EMA (and its confidence), check health at spot
+        let pump = with_ema(120_000_000, 100_000_000);
+        assert_eq!(pump.collateral_price(&vault_type).unwrap(), 98_000_000);
+        assert_eq!(pump.liquidation_check_price(&vault_type), 120_000_000);
+
+        // Spot below the EMA: borrow against spot, check health at the EMA
+        let dip = with_ema(100_000_000, 120_000_000);
+        assert_eq!(dip.collateral_price(&vault_type).unwrap(), 99_000_000);
+        assert_eq!(dip.liquidation_check_price(&vault_type), 120_000_000);
+
+        vault_type.pricing_mode = PRICING_MODE_SPOT;
+        assert_eq!(pump.collateral_price(&vault_type).unwrap(), 119_000_000);
+    }
+
+    #[test]
+    fn pegged_price_is_capped_at_one_dollar() {
+        let mut vault_type = vault_type(0, 0);
+        vault_type.is_pegged = true;
+
+        // An upward depeg can't be borrowed against
+        let above = apply_price_bounds(with_ema(1_050_000, 1_020_000), &vault_type).unwrap();
+        assert_eq!(above.price, PEGGED_PRICE_CAP);
+        assert_eq!(above.ema_price, PEGGED_PRICE_CAP);
+
+        // A downward depeg is kept
+        let below = apply_price_bounds(with_ema(970_000, 990_000), &vault_type).unwrap();
+        assert_eq!(below.price, 970_000);
+        assert_eq!(below.ema_price, 990_000);
+
+        vault_type.is_pegged = false;
+        assert_eq!(apply_price_bounds(with_ema(1_050_000, 1_020_000), &vault_type).unwrap().price, 1_050_000);
+    }
+
+    #[test]
+    fn price_band_rejects_prices_outside_it() {
+        let mut vault_type = vault_type(0, 0);
+        vault_type.is_pegged = true;
+        vault_type.min_price = 900_000;
+        vault_type.max_price = 1_100_000;
+
+        assert_eq!(
+            apply_price_bounds(oracle_price(899_999, 0), &vault_type).err(),
+            Some(AegisError::OraclePriceOutOfBand.into())
+        );
+        assert_eq!(
+            apply_price_bounds(oracle_price(1_100_001, 0), &vault_type).err(),
+            Some(AegisError::OraclePriceOutOfBand.into())
+        );
+        assert_eq!(apply_price_bounds(oracle_price(900_000, 0), &vault_type).unwrap().price, 900_000);
+        assert_eq!(apply_price_bounds(oracle_price(1_100_000, 0), &vault_type).unwrap().price, PEGGED_PRICE_CAP);
+
+        // 0 leaves that side unbounded
+        vault_type.min_price = 0;
+        vault_type.max_price = 0;
+        assert_eq!(apply_price_bounds(oracle_price(1, 0), &vault_type).unwrap().price, 1);
+    }
+
+    #[test]
+    fn price_band_must_be_ordered() {
+        validate_price_band(900_000, 1_100_000).unwrap();
+        validate_price_band(0, 1_100_000).unwrap();
+        validate_price_band(900_000, 0).unwrap();
+        assert_eq!(
+            validate_price_band(1_100_000, 900_000).unwrap_err(),
+            AegisError::InvalidOracleConfig.into()
+        );
+    }
 }
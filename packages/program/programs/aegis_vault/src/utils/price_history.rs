@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use crate::constants::oracle::{ORACLE_SOURCE_GUARDIAN_OVERRIDE, PRICE_HISTORY_CAPACITY};
+use crate::errors::codes::AegisError;
+use crate::state::{PriceHistory, PriceObservation, VaultType};
+use crate::utils::oracle::OraclePrice;
+
+/// Append an observation, unless the previous one is less than `min_interval_seconds` old.
+/// Returns whether the observation was recorded.
+pub fn push_observation(history: &mut PriceHistory, price: u64, timestamp: i64) -> bool {
+    if history.len > 0 {
+        let last = history.observations[(history.head as usize + PRICE_HISTORY_CAPACITY - 1) % PRICE_HISTORY_CAPACITY];
+        if timestamp < last.timestamp.saturating_add(history.min_interval_seconds as i64) {
+            return false;
+        }
+    }
+
+    history.observations[history.head as usize] = PriceObservation { price, timestamp };
+    history.head = ((history.head as usize + 1) % PRICE_HISTORY_CAPACITY) as u16;
+    history.len = (history.len + 1).min(PRICE_HISTORY_CAPACITY as u16);
+    true
+}
+
+/// Time-weighted average price over the `window` seconds ending at `now`. Each observation
+/// holds until the next one, the latest until `now`. None if the history doesn't reach back
+/// to the start of the window.
+pub fn twap(history: &PriceHistory, window: u64, now: i64) -> Result<Option<u64>> {
+    let window = i64::try_from(window).map_err(|_| error!(AegisError::MathOverflow))?;
+    let start = now.saturating_sub(window);
+
+    let mut end = now;
+    let mut weighted: u128 = 0;
+    for i in 1..=history.len as usize {
+        let observation =
+            history.observations[(history.head as usize + PRICE_HISTORY_CAPACITY - i) % PRICE_HISTORY_CAPACITY];
+        let from = observation.timestamp.clamp(start, end);
+        weighted = weighted
+            .checked_add((observation.price as u128) * ((end - from) as u128))
+            .ok_or(AegisError::MathOverflow)?;
+
+        if observation.timestamp <= start {
+            let average = weighted / window.max(1) as u128;
+            return Ok(Some(u64::try_from(average).map_err(|_| error!(AegisError::MathOverflow))?));
+        }
+        end = from;
+    }
+    Ok(None)
+}
+
+/// Check a TWAP window against what a price history can cover: a full buffer of
+/// observations at least `min_interval_seconds` apart reaches back (capacity - 1) intervals.
+pub fn validate_twap_window(window: u64, min_interval_seconds: u32) -> Result<()> {
+    require!(
+        window <= min_interval_seconds as u64 * (PRICE_HISTORY_CAPACITY as u64 - 1),
+        AegisError::InvalidOracleConfig
+    );
+    Ok(())
+}
+
+/// The vault type's attached price history, read from `remaining_accounts`.
+pub fn load_price_history(remaining_accounts: &[AccountInfo], vault_type: &VaultType) -> Result<PriceHistory> {
+    let history_info = remaining_accounts
+        .iter()
+        .find(|account| {
+            vault_type.price_history != Pubkey::default() && account.key() == vault_type.price_history
+        })
+        .ok_or(AegisError::PriceHistoryInsufficient)?;
+    require!(history_info.owner == &crate::ID, AegisError::InvalidOracleAccount);
+
+    PriceHistory::try_deserialize(&mut &history_info.data.borrow()[..])
+        .map_err(|_| error!(AegisError::InvalidOracleAccount))
+}
+
+/// TWAP over the vault type's configured window, read from its price history in
+/// `remaining_accounts`. None while no history is attached or it doesn't cover the
+/// window yet; an attached history must be passed.
+pub fn find_twap(remaining_accounts: &[AccountInfo], vault_type: &VaultType, now: i64) -> Result<Option<u64>> {
+    if vault_type.price_history == Pubkey::default() {
+        return Ok(None);
+    }
+    let history = load_price_history(remaining_accounts, vault_type)?;
+    twap(&history, vault_type.twap_window_seconds, now)
+}
+
+/// TWAP over the vault type's configured window, read from its price history in `remaining_accounts`.
+pub fn get_twap(remaining_accounts: &[AccountInfo], vault_type: &VaultType, now: i64) -> Result<u64> {
+    find_twap(remaining_accounts, vault_type, now)?
+        .ok_or(error!(AegisError::PriceHistoryInsufficient))
+}
+
+/// Record an accepted price in the vault type's price history, when it is passed
+/// (writable) in `remaining_accounts`. Guardian override prices are not oracle
+/// observations and never enter the history.
+pub fn record_price_observation(
+    remaining_accounts: &[AccountInfo],
+    vault_type: &VaultType,
+    oracle_price: &OraclePrice,
+    now: i64,
+) -> Result<()> {
+    if oracle_price.source == ORACLE_SOURCE_GUARDIAN_OVERRIDE {
+        return Ok(());
+    }
+    let Some(history_info) = remaining_accounts.iter().find(|account| {
+        vault_type.price_history != Pubkey::default()
+            && account.key() == vault_type.price_history
+            && account.is_writable
+    }) else {
+        return Ok(());
+    };
+    require!(history_info.owner == &crate::ID, AegisError::InvalidOracleAccount);
+
+    let mut data = history_info.try_borrow_mut_data()?;
+    let mut history = PriceHistory::try_deserialize(&mut &data[..])
+        .map_err(|_| error!(AegisError::InvalidOracleAccount))?;
+
+    if push_observation(&mut history, oracle_price.price, now) {
+        history.try_serialize(&mut &mut data[..])?;
+    }
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+use crate::constants::oracle::{MAX_PRICE_OVERRIDE_DEVIATION_BPS, PRICE_OVERRIDE_LOG_CAPACITY};
+use crate::errors::codes::AegisError;
+use crate::state::{PriceOverrideEntry, PriceOverrideLog, VaultType};
+
+/// Check an override price against the vault's last good (accepted) price.
+pub fn validate_override_price(vault_type: &VaultType, price: u64) -> Result<()> {
+    let reference = vault_type.last_accepted_price;
+    require!(price > 0 && reference > 0, AegisError::PriceOverrideOutOfBounds);
+
+    let deviation_bps = (price.abs_diff(reference) as u128)
+        .checked_mul(10_000)
+        .ok_or(AegisError::MathOverflow)?
+        .checked_div(reference as u128)
+        .ok_or(AegisError::MathOverflow)?;
+    require!(
+        deviation_bps <= MAX_PRICE_OVERRIDE_DEVIATION_BPS as u128,
+        AegisError::PriceOverrideOutOfBounds
+    );
+    Ok(())
+}
+
+/// Append an entry to the override log, overwriting the oldest once full.
+pub fn log_price_override(log: &mut PriceOverrideLog, entry: PriceOverrideEntry) {
+    log.entries[log.head as usize] = entry;
+    log.head = ((log.head as usize + 1) % PRICE_OVERRIDE_LOG_CAPACITY) as u8;
+    log.len = (log.len + 1).min(PRICE_OVERRIDE_LOG_CAPACITY as u8);
+}
@@ -0,0 +1,177 @@
+use anchor_lang::prelude::*;
+use crate::constants::liquidation::REDISTRIBUTION_PRECISION;
+use crate::errors::codes::AegisError;
+use crate::state::{Position, VaultType};
+use crate::utils::interest::add_position_debt;
+
+/// Stake for a position holding `collateral`. Liquity's correction keeps positions opened
+/// after a redistribution from diluting the pending gains of older positions.
+fn compute_stake(vault_type: &VaultType, collateral: u64) -> Result<u64> {
+    if vault_type.total_collateral_snapshot == 0 {
+        return Ok(collateral);
+    }
+
+    let stake = (collateral as u128)
+        .checked_mul(vault_type.total_stakes_snapshot as u128)
+        .ok_or(AegisError::MathOverflow)?
+        .checked_div(vault_type.total_collateral_snapshot as u128)
+        .ok_or(AegisError::MathOverflow)?;
+    u64::try_from(stake).map_err(|_| error!(AegisError::MathOverflow))
+}
+
+fn update_stake(position: &mut Position, vault_type: &mut VaultType) -> Result<()> {
+    let stake = compute_stake(vault_type, position.collateral_amount)?;
+    vault_type.total_stakes = vault_type.total_stakes
+        .saturating_sub(position.stake)
+        .checked_add(stake)
+        .ok_or(AegisError::MathOverflow)?;
+    position.stake = stake;
+    Ok(())
+}
+
+fn pending_share(stake: u64, accumulator: u128, snapshot: u128) -> Result<u64> {
+    let share = (stake as u128)
+        .checked_mul(accumulator - snapshot)
+        .ok_or(AegisError::MathOverflow)?
+        / REDISTRIBUTION_PRECISION;
+    u64::try_from(share).map_err(|_| error!(AegisError::MathOverflow))
+}
+
+/// Collateral and normalized debt redistributed to a position since its snapshots.
+pub fn pending_redistribution(position: &Position, vault_type: &VaultType) -> Result<(u64, u64)> {
+    Ok((
+        pending_share(position.stake, vault_type.l_coll, position.l_coll_snapshot)?,
+        pending_share(position.stake, vault_type.l_debt, position.l_debt_snapshot)?,
+    ))
+}
+
+/// Move a position's share of redistributed collateral and debt onto it (Liquity
+/// `applyPendingRewards`). Call before reading the position's collateral or debt.
+/// Vault totals already include redistributed amounts, so only the position changes.
+pub fn apply_pending_redistribution(position: &mut Position, vault_type: &mut VaultType) -> Result<()> {
+    let (collateral, debt) = pending_redistribution(position, vault_type)?;
+
+    position.l_coll_snapshot = vault_type.l_coll;
+    position.l_debt_snapshot = vault_type.l_debt;
+    if collateral == 0 && debt == 0 {
+        return Ok(());
+    }
+
+    position.collateral_amount = position.collateral_amount
+        .checked_add(collateral)
+        .ok_or(AegisError::MathOverflow)?;
+    position.debt_amount = position.debt_amount
+        .checked_add(debt)
+        .ok_or(AegisError::MathOverflow)?;
+    update_stake(position, vault_type)
+}
+
+/// Count a position created before the vault type tracked stakes and normalized debt:
+/// its collateral and debt join the vault totals and it is staked from now on, without
+/// a share of earlier redistributions. The legacy debt is an absolute amount, so it is
+/// normalized at the vault type's current cumulative rate.
+pub fn track_migrated_position(position: &mut Position, vault_type: &mut VaultType) -> Result<()> {
+    vault_type.total_collateral = vault_type.total_collateral
+        .checked_add(position.collateral_amount)
+        .ok_or(AegisError::MathOverflow)?;
+
+    let legacy_debt = position.debt_amount;
+    position.debt_amount = 0;
+    add_position_debt(position, vault_type, legacy_debt)?;
+
+    position.l_coll_snapshot = vault_type.l_coll;
+    position.l_debt_snapshot = vault_type.l_debt;
+    update_stake(position, vault_type)
+}
+
+/// Add collateral to a position and the vault type's total, restaking the position.
+pub fn add_position_collateral(
+    position: &mut Position,
+    vault_type: &mut VaultType,
+    amount: u64,
+) -> Result<()> {
+    position.collateral_amount = position.collateral_amount
+        .checked_add(amount)
+        .ok_or(AegisError::MathOverflow)?;
+    vault_type.total_collateral = vault_type.total_collateral
+        .checked_add(amount)
+        .ok_or(AegisError::MathOverflow)?;
+    update_stake(position, vault_type)
+}
+
+/// Remove collateral from a position and the vault type's total, restaking the position.
+pub fn remove_position_collateral(
+    position: &mut Position,
+    vault_type: &mut VaultType,
+    amount: u64,
+) -> Result<()> {
+    position.collateral_amount = position.collateral_amount
+        .checked_sub(amount)
+        .ok_or(AegisError::MathOverflow)?;
+    vault_type.total_collateral = vault_type.total_collateral.saturating_sub(amount);
+    update_stake(position, vault_type)
+}
+
+/// Spread a position's collateral and normalized debt over every other position in the
+/// vault type pro-rata to stake, via the `l_coll` and `l_debt` accumulators (Liquity
+/// `redistributeDebtAndColl`). Returns the redistributed (collateral, normalized debt).
+pub fn redistribute(position: &mut Position, vault_type: &mut VaultType) -> Result<(u64, u64)> {
+    let other_stakes = vault_type.total_stakes.saturating_sub(position.stake);
+    require!(other_stakes > 0, AegisError::NoStakesToRedistribute);
+
+    let collateral = position.collateral_amount;
+    let debt = position.debt_amount;
+
+    let coll_per_stake = (collateral as u128)
+        .checked_mul(REDISTRIBUTION_PRECISION)
+        .ok_or(AegisError::MathOverflow)?
+        / other_stakes as u128;
+    let debt_per_stake = (debt as u128)
+        .checked_mul(REDISTRIBUTION_PRECISION)
+        .ok_or(AegisError::MathOverflow)?
+        / other_stakes as u128;
+    vault_type.l_coll = vault_type.l_coll
+        .checked_add(coll_per_stake)
+        .ok_or(AegisError::MathOverflow)?;
+    vault_type.l_debt = vault_type.l_debt
+        .checked_add(debt_per_stake)
+        .ok_or(AegisError::MathOverflow)?;
+
+    // The collateral and debt stay in the vault totals, now pending for other positions
+    position.collateral_amount = 0;
+    position.debt_amount = 0;
+    vault_type.total_stakes = other_stakes;
+    position.stake = 0;
+    position.l_coll_snapshot = vault_type.l_coll;
+    position.l_debt_snapshot = vault_type.l_debt;
+
+    vault_type.total_stakes_snapshot = vault_type.total_stakes;
+    vault_type.total_collateral_snapshot = vault_type.total_collateral;
+    Ok((collateral, debt))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::RATE_PRECISION;
+    use crate::utils::interest::position_debt;
+
+    #[test]
+    fn track_migrated_position_normalizes_the_legacy_debt() {
+        let mut vault_type = VaultType::try_from_slice(&[0u8; VaultType::LEN - 8]).unwrap();
+        // Fees accrued for a while after migrate_vault_type reset the rate
+        vault_type.cumulative_rate = RATE_PRECISION * 3 / 2;
+        let mut position = Position::try_from_slice(&[0u8; Position::LEN - 8]).unwrap();
+        position.collateral_amount = 1_000;
+        position.debt_amount = 1_000;
+
+        track_migrated_position(&mut position, &mut vault_type).unwrap();
+
+        // 1_000 / 1.5 rounds up to 667 normalized, which is owed as 1_001
+        assert_eq!(position.debt_amount, 667);
+        assert_eq!(vault_type.total_normalized_debt, 667);
+        assert_eq!(position_debt(&position, &vault_type).unwrap(), 1_001);
+        assert_eq!(vault_type.total_collateral, 1_000);
+        assert_eq!(position.stake, 1_000);
+    }
+}
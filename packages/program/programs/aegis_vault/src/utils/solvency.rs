@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token::TokenAccount;
+use crate::errors::codes::AegisError;
+use crate::state::{ProtocolState, VaultType};
+
+/// Recognize `amount` of unbacked debt: it leaves `total_protocol_debt`, the surplus
+/// buffer absorbs as much as it can and the rest is added to the deficit.
+/// Returns the part covered by the buffer.
+pub fn record_bad_debt(protocol: &mut ProtocolState, amount: u64) -> Result<u64> {
+    let covered = amount.min(protocol.surplus_buffer);
+    protocol.surplus_buffer -= covered;
+    protocol.deficit = protocol.deficit
+        .checked_add(amount - covered)
+        .ok_or(AegisError::MathOverflow)?;
+    protocol.total_bad_debt_written_off = protocol.total_bad_debt_written_off
+        .checked_add(amount)
+        .ok_or(AegisError::MathOverflow)?;
+
+    // Saturating: per-position rounding can leave dust
+    protocol.total_protocol_debt = protocol.total_protocol_debt.saturating_sub(amount);
+    Ok(covered)
+}
+
+/// Apply collected fees to solvency: first cover the deficit, then fill the surplus
+/// buffer up to its cap. Returns the remainder for the treasury.
+pub fn retain_surplus(protocol: &mut ProtocolState, fees: u64) -> u64 {
+    let healed = fees.min(protocol.deficit);
+    protocol.deficit -= healed;
+
+    let remaining = fees - healed;
+    let retained = remaining.min(protocol.surplus_buffer_cap.saturating_sub(protocol.surplus_buffer));
+    protocol.surplus_buffer += retained;
+
+    remaining - retained
+}
+
+/// Flat keeper reward for liquidating a position with `debt`, split into the part minted
+/// from the surplus buffer and the part drawn from the treasury's delegated allowance.
+/// Only complete liquidations of positions at or below the vault's keeper reward debt
+/// limit qualify.
+pub fn keeper_reward_sources(
+    protocol: &mut ProtocolState,
+    vault_type: &VaultType,
+    debt: u64,
+    complete: bool,
+    treasury_allowance: u64,
+) -> (u64, u64) {
+    if vault_type.keeper_reward == 0 || !complete || debt > vault_type.keeper_reward_max_debt {
+        return (0, 0);
+    }
+
+    let from_surplus = vault_type.keeper_reward.min(protocol.surplus_buffer);
+    protocol.surplus_buffer -= from_surplus;
+
+    let from_treasury = (vault_type.keeper_reward - from_surplus).min(treasury_allowance);
+    (from_surplus, from_treasury)
+}
+
+/// AGSUSD the treasury account lets the mint authority spend on keeper rewards.
+pub fn treasury_allowance(treasury_account: &TokenAccount, mint_authority: &Pubkey) -> u64 {
+    if treasury_account.delegate == COption::Some(*mint_authority) {
+        treasury_account.delegated_amount.min(treasury_account.amount)
+    } else {
+        0
+    }
+}
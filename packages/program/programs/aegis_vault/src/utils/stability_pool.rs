@@ -0,0 +1,204 @@
+use anchor_lang::prelude::*;
+use crate::constants::liquidation::{
+    STABILITY_POOL_PRECISION, STABILITY_POOL_SCALE_FACTOR, STABILITY_POOL_SUM_CAPACITY,
+};
+use crate::errors::codes::AegisError;
+use crate::state::{EpochScaleSum, StabilityDeposit, StabilityPool};
+
+/// Index of the sum recorded for `epoch` and `scale`.
+fn epoch_scale_index(pool: &StabilityPool, epoch: u64, scale: u64) -> Option<usize> {
+    pool.sums[..pool.sum_count as usize]
+        .iter()
+        .position(|entry| entry.epoch == epoch && entry.scale == scale)
+}
+
+/// Collateral gain sum S recorded for `epoch` and `scale`, 0 if that scale was never reached.
+fn epoch_scale_sum(pool: &StabilityPool, epoch: u64, scale: u64) -> u128 {
+    epoch_scale_index(pool, epoch, scale).map_or(0, |index| pool.sums[index].sum)
+}
+
+/// Drop the sums no deposit can read anymore. A deposit reads the sum of its snapshot's
+/// epoch and scale and the next scale's (later scales count as zero), so a sum is kept
+/// while a snapshot references it or the scale before it. The current sum is always kept.
+fn prune_epoch_scales(pool: &mut StabilityPool) {
+    let count = pool.sum_count as usize;
+    let sums = pool.sums;
+    let referenced = |epoch: u64, scale: u64| {
+        sums[..count]
+            .iter()
+            .any(|entry| entry.epoch == epoch && entry.scale == scale && entry.snapshot_count > 0)
+    };
+
+    let mut kept = 0;
+    for (index, entry) in sums[..count].iter().enumerate() {
+        let needed = index == count - 1
+            || entry.snapshot_count > 0
+            || (entry.scale > 0 && referenced(entry.epoch, entry.scale - 1));
+        if needed {
+            pool.sums[kept] = *entry;
+            kept += 1;
+        }
+    }
+    for entry in pool.sums[kept..count].iter_mut() {
+        *entry = EpochScaleSum::default();
+    }
+    pool.sum_count = kept as u8;
+}
+
+/// Start recording a new (epoch, scale) sum. Fails when the history is still full after
+/// pruning, so no depositor's gains are ever dropped.
+fn push_epoch_scale(pool: &mut StabilityPool) -> Result<()> {
+    prune_epoch_scales(pool);
+    let index = pool.sum_count as usize;
+    require!(index < STABILITY_POOL_SUM_CAPACITY, AegisError::StabilityPoolHistoryFull);
+
+    pool.sums[index] = EpochScaleSum {
+        epoch: pool.current_epoch,
+        scale: pool.current_scale,
+        sum: 0,
+        snapshot_count: 0,
+    };
+    pool.sum_count += 1;
+    Ok(())
+}
+
+/// Deposit left after the liquidations since the depositor's snapshot.
+/// Deposits from an earlier epoch, or more than one scale back, have been used up.
+pub fn compounded_deposit(pool: &StabilityPool, deposit: &StabilityDeposit) -> Result<u64> {
+    if deposit.initial_value == 0 || deposit.snapshot_epoch < pool.current_epoch {
+        return Ok(0);
+    }
+
+    let scale_diff = pool.current_scale - deposit.snapshot_scale;
+    let ratio_divisor = match scale_diff {
+        0 => deposit.snapshot_product,
+        1 => deposit.snapshot_product
+            .checked_mul(STABILITY_POOL_SCALE_FACTOR)
+            .ok_or(AegisError::MathOverflow)?,
+        _ => return Ok(0),
+    };
+
+    let compounded = (deposit.initial_value as u128)
+        .checked_mul(pool.product)
+        .ok_or(AegisError::MathOverflow)?
+        .checked_div(ratio_divisor)
+        .ok_or(AegisError::MathOverflow)?;
+
+    Ok(u64::try_from(compounded).unwrap_or(0).min(deposit.initial_value))
+}
+
+/// Collateral earned by a deposit since its snapshot: the sum accrued in the snapshot's
+/// scale plus the next scale's sum, which is recorded 1e9 times larger.
+pub fn collateral_gain(pool: &StabilityPool, deposit: &StabilityDeposit) -> Result<u64> {
+    if deposit.initial_value == 0 {
+        return Ok(0);
+    }
+
+    let first = epoch_scale_sum(pool, deposit.snapshot_epoch, deposit.snapshot_scale)
+        .saturating_sub(deposit.snapshot_sum);
+    let second = epoch_scale_sum(pool, deposit.snapshot_epoch, deposit.snapshot_scale + 1)
+        / STABILITY_POOL_SCALE_FACTOR;
+
+    let gain = first
+        .checked_add(second)
+        .ok_or(AegisError::MathOverflow)?
+        .checked_mul(deposit.initial_value as u128)
+        .ok_or(AegisError::MathOverflow)?
+        .checked_div(deposit.snapshot_product)
+        .ok_or(AegisError::MathOverflow)?;
+
+    u64::try_from(gain).map_err(|_| error!(AegisError::MathOverflow))
+}
+
+/// Snapshot the pool's current P and S for a deposit now worth `value`, moving the
+/// deposit's reference from its previous snapshot's sum to the current one.
+pub fn update_deposit_snapshot(pool: &mut StabilityPool, deposit: &mut StabilityDeposit, value: u64) {
+    if deposit.initial_value > 0 {
+        if let Some(index) = epoch_scale_index(pool, deposit.snapshot_epoch, deposit.snapshot_scale) {
+            pool.sums[index].snapshot_count = pool.sums[index].snapshot_count.saturating_sub(1);
+        }
+    }
+
+    deposit.initial_value = value;
+    deposit.snapshot_product = pool.product;
+    deposit.snapshot_sum = epoch_scale_sum(pool, pool.current_epoch, pool.current_scale);
+    deposit.snapshot_epoch = pool.current_epoch;
+    deposit.snapshot_scale = pool.current_scale;
+
+    if value > 0 {
+        let current = pool.sum_count as usize - 1;
+        pool.sums[current].snapshot_count += 1;
+    }
+    prune_epoch_scales(pool);
+}
+
+/// Cancel `debt` against pool deposits and hand `collateral` to depositors pro-rata
+/// (Liquity `offset`). Deposits shrink by P, collateral accrues into S.
+pub fn offset(pool: &mut StabilityPool, debt: u64, collateral: u64) -> Result<()> {
+    let total = pool.total_deposits;
+    require!(total > 0 && debt > 0 && debt <= total, AegisError::StabilityPoolEmpty);
+
+    // Collateral per unit deposited, added to S weighted by the current P; S stays at
+    // P's 1e18 scale so it can't overflow u128
+    let sum_increase = (collateral as u128)
+        .checked_mul(pool.product)
+        .ok_or(AegisError::MathOverflow)?
+        .checked_div(total as u128)
+        .ok_or(AegisError::MathOverflow)?;
+    let current = pool.sum_count as usize - 1;
+    pool.sums[current].sum = pool.sums[current].sum
+        .checked_add(sum_increase)
+        .ok_or(AegisError::MathOverflow)?;
+
+    if debt == total {
+        // The pool is emptied: every deposit is used up and a new epoch starts
+        pool.current_epoch = pool.current_epoch
+            .checked_add(1)
+            .ok_or(AegisError::MathOverflow)?;
+        pool.current_scale = 0;
+        pool.product = STABILITY_POOL_PRECISION;
+        push_epoch_scale(pool)?;
+    } else {
+        // Loss per unit deposited, rounded up so compounded deposits never exceed the pool
+        let loss_per_unit = (debt as u128)
+            .checked_mul(STABILITY_POOL_PRECISION)
+            .ok_or(AegisError::MathOverflow)?
+            .checked_div(total as u128)
+            .ok_or(AegisError::MathOverflow)?
+            .checked_add(1)
+            .ok_or(AegisError::MathOverflow)?;
+        let remaining = pool.product
+            .checked_mul(STABILITY_POOL_PRECISION - loss_per_unit)
+            .ok_or(AegisError::MathOverflow)?;
+
+        let new_product = remaining / STABILITY_POOL_PRECISION;
+        if new_product < STABILITY_POOL_SCALE_FACTOR {
+            // Rescale P by 1e9 to keep precision
+            pool.product = remaining / (STABILITY_POOL_PRECISION / STABILITY_POOL_SCALE_FACTOR);
+            pool.current_scale = pool.current_scale
+                .checked_add(1)
+                .ok_or(AegisError::MathOverflow)?;
+            push_epoch_scale(pool)?;
+        } else {
+            pool.product = new_product;
+        }
+        require!(pool.product > 0, AegisError::MathOverflow);
+    }
+
+    pool.total_deposits = total - debt;
+    pool.total_collateral = pool.total_collateral
+        .checked_add(collateral)
+        .ok_or(AegisError::MathOverflow)?;
+    Ok(())
+}
+
+/// Deposits in a vault type's stability pool account, zero while the pool isn't initialized.
+pub fn stability_pool_deposits(pool_info: &AccountInfo) -> Result<u64> {
+    if pool_info.data_is_empty() {
+        return Ok(0);
+    }
+    require!(pool_info.owner == &crate::ID, AegisError::Unauthorized);
+
+    let pool = StabilityPool::try_deserialize(&mut &pool_info.data.borrow()[..])?;
+    Ok(pool.total_deposits)
+}
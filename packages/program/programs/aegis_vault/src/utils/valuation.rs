@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use crate::errors::codes::AegisError;
+
+fn pow10(exponent: u32) -> Result<u128> {
+    10u128
+        .checked_pow(exponent)
+        .ok_or(error!(AegisError::MathOverflow))
+}
+
+/// Rescale a Pyth value with exponent `expo` to `target_decimals` using integer math.
+pub fn scale_price(value: u64, expo: i32, target_decimals: i32) -> Result<u64> {
+    let shift = target_decimals
+        .checked_add(expo)
+        .ok_or(AegisError::MathOverflow)?;
+
+    let scaled = if shift >= 0 {
+        (value as u128)
+            .checked_mul(pow10(shift as u32)?)
+            .ok_or(AegisError::MathOverflow)?
+    } else {
+        (value as u128)
+            .checked_div(pow10(shift.unsigned_abs())?)
+            .ok_or(AegisError::MathOverflow)?
+    };
+
+    u64::try_from(scaled).map_err(|_| error!(AegisError::MathOverflow))
+}
+
+/// Rescale a fixed-point value with `decimals` decimals (e.g. Switchboard's 18) to `target_decimals`.
+pub fn scale_fixed(value: u128, decimals: u32, target_decimals: u32) -> Result<u64> {
+    let scaled = if target_decimals >= decimals {
+        value
+            .checked_mul(pow10(target_decimals - decimals)?)
+            .ok_or(AegisError::MathOverflow)?
+    } else {
+        value
+            .checked_div(pow10(decimals - target_decimals)?)
+            .ok_or(AegisError::MathOverflow)?
+    };
+
+    u64::try_from(scaled).map_err(|_| error!(AegisError::MathOverflow))
+}
+
+/// USD value (6 decimals) of `amount` collateral base units at `price` per whole token.
+pub fn collateral_value(amount: u64, price: u64, collateral_decimals: u8) -> Result<u64> {
+    let value = (amount as u128)
+        .checked_mul(price as u128)
+        .ok_or(AegisError::MathOverflow)?
+        .checked_div(pow10(collateral_decimals as u32)?)
+        .ok_or(AegisError::MathOverflow)?;
+
+    u64::try_from(value).map_err(|_| error!(AegisError::MathOverflow))
+}
+
+/// Collateral base units worth `value` USD (6 decimals) at `price` per whole token.
+pub fn collateral_for_value(value: u64, price: u64, collateral_decimals: u8) -> Result<u64> {
+    let amount = (value as u128)
+        .checked_mul(pow10(collateral_decimals as u32)?)
+        .ok_or(AegisError::MathOverflow)?
+        .checked_div(price as u128)
+        .ok_or(AegisError::MathOverflow)?;
+
+    u64::try_from(amount).map_err(|_| error!(AegisError::MathOverflow))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collateral_value_scales_by_the_mint_decimals() {
+        // 2.5 tokens at $150 is worth $375 whatever the mint's decimals
+        assert_eq!(collateral_value(2_500_000, 150_000_000, 6).unwrap(), 375_000_000);
+        assert_eq!(collateral_value(250_000_000, 150_000_000, 8).unwrap(), 375_000_000);
+        assert_eq!(collateral_value(2_500_000_000, 150_000_000, 9).unwrap(), 375_000_000);
+
+        // 0.5 BTC-like 8-decimal token at $60,000
+        assert_eq!(collateral_value(50_000_000, 60_000_000_000, 8).unwrap(), 30_000_000_000);
+    }
+
+    #[test]
+    fn collateral_for_value_inverts_collateral_value() {
+        assert_eq!(collateral_for_value(375_000_000, 150_000_000, 6).unwrap(), 2_500_000);
+        assert_eq!(collateral_for_value(375_000_000, 150_000_000, 8).unwrap(), 250_000_000);
+        assert_eq!(collateral_for_value(30_000_000_000, 60_000_000_000, 8).unwrap(), 50_000_000);
+
+        // Less than one base unit rounds down
+        assert_eq!(collateral_for_value(1, 150_000_000, 6).unwrap(), 0);
+        assert_eq!(collateral_for_value(1, 150_000_000, 8).unwrap(), 0);
+    }
+}
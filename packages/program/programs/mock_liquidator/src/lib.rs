@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+declare_id!("1D2ZwtBZVxD4Hc35jGhX8FSNb6qY9ZqmxaEuBttm6aE");
+
+pub const RESERVE_SEED: &[u8] = b"reserve";
+
+/// Stand-in for a DEX used as the `flash_liquidate` callback in tests: swaps the
+/// liquidator's seized collateral for AGSUSD from a pre-funded reserve at a fixed quote.
+#[program]
+pub mod mock_liquidator {
+    use super::*;
+
+    pub fn swap(ctx: Context<Swap>, collateral_amount: u64, stablecoin_amount: u64) -> Result<()> {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.liquidator_collateral_account.to_account_info(),
+            to: ctx.accounts.reserve_collateral_account.to_account_info(),
+            authority: ctx.accounts.liquidator.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+        token::transfer(cpi_ctx, collateral_amount)?;
+
+        let seeds = &[RESERVE_SEED, &[ctx.bumps.reserve_authority]];
+        let signer = &[&seeds[..]];
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.reserve_stablecoin_account.to_account_info(),
+            to: ctx.accounts.liquidator_stablecoin_account.to_account_info(),
+            authority: ctx.accounts.reserve_authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer);
+        token::transfer(cpi_ctx, stablecoin_amount)?;
+
+        msg!("MockSwap: collateral={} stablecoin={}", collateral_amount, stablecoin_amount);
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct Swap<'info> {
+    pub liquidator: Signer<'info>,
+
+    #[account(mut)]
+    pub liquidator_collateral_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub liquidator_stablecoin_account: Account<'info, TokenAccount>,
+
+    #[account(mut, constraint = reserve_collateral_account.owner == reserve_authority.key())]
+    pub reserve_collateral_account: Account<'info, TokenAccount>,
+
+    #[account(mut, constraint = reserve_stablecoin_account.owner == reserve_authority.key())]
+    pub reserve_stablecoin_account: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA that owns the reserve token accounts
+    #[account(seeds = [RESERVE_SEED], bump)]
+    pub reserve_authority: AccountInfo<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
@@ -56,6 +56,7 @@ describe("aegis_vault", () => {
     // Verify metrics
     expect(protocolState.totalProtocolDebt.toNumber()).to.equal(0);
     expect(protocolState.totalProtocolCollateralValue.toNumber()).to.equal(0);
+    expect(protocolState.totalStabilityFeesCollected.toNumber()).to.equal(0);
 
     // Verify metadata
     expect(protocolState.configVersion.toNumber()).to.equal(1);
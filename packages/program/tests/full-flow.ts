@@ -1,7 +1,7 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
-import { createMint, getAssociatedTokenAddress, getOrCreateAssociatedTokenAccount, mintTo, TOKEN_PROGRAM_ID } from "@solana/spl-token";
-import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import { createMint, getAccount, getAssociatedTokenAddress, getOrCreateAssociatedTokenAccount, mintTo, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { PublicKey, SystemProgram } from "@solana/web3.js";
 import { assert } from "chai";
 import { AegisVault } from "../target/types/aegis_vault";
 
@@ -19,10 +19,13 @@ describe("aegis_vault full flow", () => {
     let vaultAuthorityPda: PublicKey;
     let protocolStatePda: PublicKey;
     let mintAuthorityPda: PublicKey;
-    let oraclePriceAccount: Keypair;
+    let priceFeedPda: PublicKey;
 
     const VAULT_TYPE_SEED = "vault-type";
     const PROTOCOL_STATE_SEED = "protocol_state";
+    const PRICE_FEED_SEED = "price-feed";
+    // Pyth SOL/USD feed id
+    const SOL_USD_FEED_ID = "ef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d";
 
     before(async () => {
         // Find PDAs
@@ -35,23 +38,7 @@ describe("aegis_vault full flow", () => {
             program.programId
         );
 
-        // Create Mock Oracle
-        oraclePriceAccount = Keypair.generate();
-        // We need to write data to this account to mimic Pyth.
-        // For simplicity, we might skip strict Pyth validation if possible, 
-        // or we just create an account with enough size.
-        // The program checks owner?
-        // In `get_oracle_price`:
-        // let price_feed = load_price_feed_from_account_info(&oracle_account_info).unwrap();
-        // This requires valid Pyth data.
-        // Writing valid Pyth data is complex without the Pyth SDK helper.
-        // However, if we can't mock it easily, we might be stuck.
-        // Let's try to find if there's a helper or if we can just skip it by commenting out the check in program for testing?
-        // No, we should not modify program for testing if possible.
-
-        // Alternative: Use `mock-pyth` crate or similar?
-        // Or just assume we can't test oracle dependent logic easily without setup.
-        // But we need it for `createVaultType`.
+        // Prices come from the program-owned push feed, so no Pyth account is needed locally
     });
 
     it("Is initialized!", async () => {
@@ -116,9 +103,31 @@ describe("aegis_vault full flow", () => {
             [Buffer.from(VAULT_TYPE_SEED), collateralMint.toBuffer()],
             program.programId
         );
+        [priceFeedPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from(PRICE_FEED_SEED), vaultTypePda.toBuffer()],
+            program.programId
+        );
 
         const params = {
-            oraclePriceAccount: oraclePriceAccount.publicKey,
+            collateralDecimals: 9,
+            oracleSource: 1, // push feed
+            oraclePriceAccount: priceFeedPda,
+            oracleFeedId: Array.from(Buffer.from(SOL_USD_FEED_ID, "hex")),
+            oracleMaxAgeSeconds: new anchor.BN(0), // use protocol TTL
+            oraclePriceExponent: -8,
+            oracleVerificationLevel: 0, // Full
+            oracleMinSignatures: 0,
+            confMultiplierBps: 10000, // 1x confidence
+            maxConfRatioBps: 200, // 2%
+            pricingMode: 0, // spot
+            stakePoolAccount: PublicKey.default,
+            isPegged: false,
+            minPrice: new anchor.BN(0),
+            maxPrice: new anchor.BN(0),
+            secondaryOracleAccount: PublicKey.default,
+            secondaryOracleMaxAgeSeconds: new anchor.BN(0),
+            oracleAggregation: 0, // primary only
+            maxOracleDeviationBps: 0,
             ltvBps: new anchor.BN(15000),
             liqThresholdBps: new anchor.BN(12000),
             liqPenaltyBps: new anchor.BN(500),
@@ -126,12 +135,28 @@ describe("aegis_vault full flow", () => {
             mintFeeBps: 50,
             redeemFeeBps: 0,
             vaultDebtCeiling: new anchor.BN(1000000000),
+            closeFactorBps: 10000, // full liquidation
+            targetHealthBps: 0,
+            fullLiquidationHealthBps: 0,
+            liquidationDustThreshold: new anchor.BN(0),
+            liquidationMode: 0, // fixed discount
+            auctionStartPremiumBps: 0,
+            auctionFloorBps: 0,
+            auctionDurationSeconds: new anchor.BN(0),
+            liquidationProtocolShareBps: 5000,
+            keeperReward: new anchor.BN(0),
+            keeperRewardMaxDebt: new anchor.BN(0),
+            liquidationBonusMinBps: 0,
+            liquidationBonusMaxBps: 0, // fixed penalty
+            maxPriceMoveBpsPerMinute: 0, // circuit breaker disabled
+            twapWindowSeconds: new anchor.BN(0), // provider EMA
         };
 
         await program.methods
             .createVaultType(collateralMint, params)
             .accounts({
                 vaultType: vaultTypePda,
+                collateralMintAccount: collateralMint,
                 protocolState: protocolStatePda,
                 admin: wallet.publicKey,
                 systemProgram: SystemProgram.programId,
@@ -142,6 +167,32 @@ describe("aegis_vault full flow", () => {
         assert.ok(vault.collateralMint.equals(collateralMint));
     });
 
+    it("Pushes Oracle Price", async () => {
+        await program.methods
+            .initializePriceFeed(1000) // 10% max move per update
+            .accounts({
+                priceFeed: priceFeedPda,
+                vaultType: vaultTypePda,
+                protocolState: protocolStatePda,
+                admin: wallet.publicKey,
+                systemProgram: SystemProgram.programId,
+            } as any)
+            .rpc();
+
+        const now = await provider.connection.getBlockTime(await provider.connection.getSlot());
+        await program.methods
+            .pushPrice(new anchor.BN(150_000_000), new anchor.BN(50_000), new anchor.BN(now!)) // $150 +/- 0.05
+            .accounts({
+                priceFeed: priceFeedPda,
+                protocolState: protocolStatePda,
+                oracleUpdateAuthority: wallet.publicKey,
+            } as any)
+            .rpc();
+
+        const feed = await program.account.priceFeed.fetch(priceFeedPda);
+        assert.equal(feed.price.toNumber(), 150_000_000);
+    });
+
     it("Opens Position", async () => {
         const [position] = PublicKey.findProgramAddressSync(
             [Buffer.from("position"), wallet.publicKey.toBuffer(), vaultTypePda.toBuffer()],
@@ -207,7 +258,7 @@ describe("aegis_vault full flow", () => {
                 userCollateralAccount: userCollateralAccount.address,
                 vaultCollateralAccount: vaultCollateralAccount.address,
                 vaultAuthority,
-                oraclePriceAccount: oraclePriceAccount.publicKey,
+                oraclePriceAccount: priceFeedPda,
                 owner: wallet.publicKey,
                 tokenProgram: TOKEN_PROGRAM_ID,
             } as any)
@@ -239,7 +290,7 @@ describe("aegis_vault full flow", () => {
                 stablecoinMint,
                 userStablecoinAccount: userStablecoinAccount.address,
                 mintAuthority: mintAuthorityPda,
-                oraclePriceAccount: oraclePriceAccount.publicKey,
+                oraclePriceAccount: priceFeedPda,
                 owner: wallet.publicKey,
                 tokenProgram: TOKEN_PROGRAM_ID,
             } as any)
@@ -308,7 +359,7 @@ describe("aegis_vault full flow", () => {
                 userCollateralAccount,
                 vaultCollateralAccount,
                 vaultAuthority,
-                oraclePriceAccount: oraclePriceAccount.publicKey,
+                oraclePriceAccount: priceFeedPda,
                 owner: wallet.publicKey,
                 tokenProgram: TOKEN_PROGRAM_ID,
             } as any)
@@ -318,31 +369,133 @@ describe("aegis_vault full flow", () => {
         assert.equal(pos.collateralAmount.toNumber(), 900000000);
     });
 
-    // We need to mock Oracle for Vault Type creation.
-    // Since we can't easily mock Pyth data in JS without a library, 
-    // and we are running out of time/steps, 
-    // I will skip the parts that require Oracle for now in this test file, 
-    // or I will try to use a dummy account and expect failure if validation is strict.
-    // But wait, `createVaultType` stores the oracle address. It doesn't read it?
-    // `create_vault_type` instruction does NOT read the oracle account!
-    // It just stores the address in `VaultType` state.
-    // `depositCollateral` and `withdrawCollateral` READ it.
+    it("Accrues Stability Fees at the configured rate", async () => {
+        const [position] = PublicKey.findProgramAddressSync(
+            [Buffer.from("position"), wallet.publicKey.toBuffer(), vaultTypePda.toBuffer()],
+            program.programId
+        );
 
-    // So we can create vault type with a dummy oracle address!
-    // But `deposit` and `withdraw` will fail when they try to read price.
+        // Mirrors the program's per-second compounding in 1e18 fixed point
+        const RATE_PRECISION = new anchor.BN("1000000000000000000");
+        const SECONDS_PER_YEAR = 31_536_000;
+        const rateMul = (a: anchor.BN, b: anchor.BN) => a.mul(b).div(RATE_PRECISION);
+        const ratePow = (base: anchor.BN, exp: number) => {
+            let result = RATE_PRECISION;
+            while (exp > 0) {
+                if (exp % 2 === 1) result = rateMul(result, base);
+                exp = Math.floor(exp / 2);
+                if (exp > 0) base = rateMul(base, base);
+            }
+            return result;
+        };
+        const toDebt = (normalized: anchor.BN, rate: anchor.BN) =>
+            normalized.mul(rate).add(RATE_PRECISION.subn(1)).div(RATE_PRECISION);
+
+        const before = await program.account.vaultType.fetch(vaultTypePda);
+        await new Promise((resolve) => setTimeout(resolve, 2000));
+
+        await program.methods
+            .accrueVaultInterest()
+            .accounts({
+                vaultType: vaultTypePda,
+                protocolState: protocolStatePda,
+            } as any)
+            .rpc();
 
-    // To test `deposit` and `withdraw`, we need `get_oracle_price` to work.
-    // `get_oracle_price` calls `pyth_sdk_solana::load_price_feed_from_account_info`.
+        const after = await program.account.vaultType.fetch(vaultTypePda);
+        const elapsed = after.lastAccrualTs.sub(before.lastAccrualTs).toNumber();
+        assert.isAbove(elapsed, 0);
 
-    // If I can't mock Pyth, I can't test deposit/withdraw logic that depends on price (LTV checks).
-    // But `deposit` only checks price for logging?
-    // `let _price = get_oracle_price(...)`.
-    // Yes, it calls it. So it will fail.
+        // 100 bps a year, compounded every second since the last accrual
+        const perSecondRate = RATE_PRECISION.add(
+            new anchor.BN(100).mul(RATE_PRECISION).divn(10_000).divn(SECONDS_PER_YEAR)
+        );
+        const expectedRate = rateMul(before.cumulativeRate, ratePow(perSecondRate, elapsed));
+        assert.equal(after.cumulativeRate.toString(), expectedRate.toString());
+        assert.ok(after.cumulativeRate.gt(before.cumulativeRate));
+
+        // The vault's debt grows by the same factor and is held as unrealized fees
+        const accrued = toDebt(after.totalNormalizedDebt, after.cumulativeRate)
+            .sub(toDebt(before.totalNormalizedDebt, before.cumulativeRate));
+        assert.equal(
+            after.unrealizedStabilityFees.sub(before.unrealizedStabilityFees).toString(),
+            accrued.toString()
+        );
 
-    // I should probably comment out `get_oracle_price` calls in the program for local testing if I can't mock it.
-    // Or I can use a mock oracle program.
+        // The position owes its normalized debt at the new rate
+        const pos = await program.account.position.fetch(position);
+        assert.ok(toDebt(pos.debtAmount, after.cumulativeRate).gt(new anchor.BN(500000)));
+    });
 
-    // Given the constraints, I'll comment out `get_oracle_price` calls in `deposit_collateral.rs`, `withdraw_collateral.rs`, `mint_stablecoin.rs` TEMPORARILY to verify the rest of the logic.
-    // This is a common practice when oracle is external.
+    it("Collects Stability Fees to the treasury", async () => {
+        const [position] = PublicKey.findProgramAddressSync(
+            [Buffer.from("position"), wallet.publicKey.toBuffer(), vaultTypePda.toBuffer()],
+            program.programId
+        );
+        const updateVaultType = (params: Record<string, unknown>) =>
+            program.methods
+                .updateVaultType(params as any)
+                .accounts({
+                    vaultType: vaultTypePda,
+                    protocolState: protocolStatePda,
+                    admin: wallet.publicKey,
+                } as any)
+                .rpc();
+
+        const state = await program.account.protocolState.fetch(protocolStatePda);
+        const treasuryStablecoinAccount = (
+            await getOrCreateAssociatedTokenAccount(provider.connection, wallet.payer, stablecoinMint, state.treasuryPubkey)
+        ).address;
+        assert.ok(treasuryStablecoinAccount.equals(state.treasuryAta));
+        const treasuryBalance = async () =>
+            Number((await getAccount(provider.connection, treasuryStablecoinAccount)).amount);
+
+        // Enough debt for a 100% fee to accrue whole units within a few seconds
+        await program.methods
+            .mintStablecoin(new anchor.BN(80_000_000)) // 80 AGSUSD
+            .accounts({
+                position,
+                vaultType: vaultTypePda,
+                protocolState: protocolStatePda,
+                stablecoinMint,
+                userStablecoinAccount: await getAssociatedTokenAddress(stablecoinMint, wallet.publicKey),
+                treasuryStablecoinAccount,
+                mintAuthority: mintAuthorityPda,
+                oraclePriceAccount: priceFeedPda,
+                owner: wallet.publicKey,
+                tokenProgram: TOKEN_PROGRAM_ID,
+            } as any)
+            .rpc();
+        await updateVaultType({ stabilityFeeBps: 10000 });
+        await new Promise((resolve) => setTimeout(resolve, 3000));
+
+        const before = await program.account.protocolState.fetch(protocolStatePda);
+        const treasuryBefore = await treasuryBalance();
+
+        await program.methods
+            .collectStabilityFees()
+            .accounts({
+                vaultType: vaultTypePda,
+                protocolState: protocolStatePda,
+                stablecoinMint,
+                treasuryStablecoinAccount,
+                mintAuthority: mintAuthorityPda,
+                tokenProgram: TOKEN_PROGRAM_ID,
+            } as any)
+            .rpc();
+        await updateVaultType({ stabilityFeeBps: 100 });
+
+        const after = await program.account.protocolState.fetch(protocolStatePda);
+        const vault = await program.account.vaultType.fetch(vaultTypePda);
+        const collected = after.totalStabilityFeesCollected.sub(before.totalStabilityFeesCollected).toNumber();
+        assert.isAbove(collected, 0);
+        assert.equal(vault.unrealizedStabilityFees.toNumber(), 0);
+
+        // Whatever the deficit and surplus buffer don't take is minted to the treasury
+        const healed = before.deficit.sub(after.deficit).toNumber();
+        const retained = after.surplusBuffer.sub(before.surplusBuffer).toNumber();
+        assert.equal((await treasuryBalance()) - treasuryBefore, collected - healed - retained);
+        assert.isAbove((await treasuryBalance()) - treasuryBefore, 0);
+    });
 
 });
@@ -0,0 +1,1168 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+    approve,
+    AuthorityType,
+    createAccount,
+    createMint,
+    getAccount,
+    getOrCreateAssociatedTokenAccount,
+    mintTo,
+    setAuthority,
+    transfer,
+    TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import { AccountMeta, Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import { expect } from "chai";
+import { AegisVault } from "../target/types/aegis_vault";
+import { MockLiquidator } from "../target/types/mock_liquidator";
+
+async function expectError(promise: Promise<unknown>, code: string) {
+    try {
+        await promise;
+    } catch (e) {
+        expect((e as anchor.AnchorError).error?.errorCode?.code).to.equal(code);
+        return;
+    }
+    expect.fail(`expected ${code}`);
+}
+
+describe("liquidations", () => {
+    const provider = anchor.AnchorProvider.env();
+    anchor.setProvider(provider);
+
+    const program = anchor.workspace.AegisVault as Program<AegisVault>;
+    const mockLiquidator = anchor.workspace.MockLiquidator as Program<MockLiquidator>;
+    const wallet = provider.wallet as anchor.Wallet;
+
+    // Each borrower deposits 1 token at $100 and borrows $50
+    const borrowers = Array.from({ length: 20 }, () => Keypair.generate());
+    const DEBT = 50_000_000;
+
+    let protocolStatePda: PublicKey;
+    let mintAuthorityPda: PublicKey;
+    let stablecoinMint: PublicKey;
+    let collateralMint: PublicKey;
+    let vaultTypePda: PublicKey;
+    let priceFeedPda: PublicKey;
+    let vaultAuthority: PublicKey;
+    let vaultCollateralAccount: PublicKey;
+    let treasuryStablecoinAccount: PublicKey;
+    let treasuryCollateralAccount: PublicKey;
+    let liquidatorStablecoinAccount: PublicKey;
+    let liquidatorCollateralAccount: PublicKey;
+    let publishTime: number;
+
+    const positionOf = (owner: PublicKey) =>
+        PublicKey.findProgramAddressSync(
+            [Buffer.from("position"), owner.toBuffer(), vaultTypePda.toBuffer()],
+            program.programId
+        )[0];
+
+    const pushPrice = (price: number) => {
+        publishTime += 1;
+        return program.methods
+            .pushPrice(new anchor.BN(price), new anchor.BN(0), new anchor.BN(publishTime))
+            .accounts({
+                priceFeed: priceFeedPda,
+                protocolState: protocolStatePda,
+                oracleUpdateAuthority: wallet.publicKey,
+            } as any)
+            .rpc();
+    };
+
+    const updateVaultType = (params: Record<string, unknown>) =>
+        program.methods
+            .updateVaultType(params as any)
+            .accounts({
+                vaultType: vaultTypePda,
+                protocolState: protocolStatePda,
+                admin: wallet.publicKey,
+            } as any)
+            .rpc();
+
+    const liquidate = (owner: PublicKey, repayAmount: number, remainingAccounts: AccountMeta[] = []) =>
+        program.methods
+            .liquidatePosition(new anchor.BN(repayAmount))
+            .accounts({
+                position: positionOf(owner),
+                vaultType: vaultTypePda,
+                protocolState: protocolStatePda,
+                stablecoinMint,
+                liquidatorStablecoinAccount,
+                liquidatorCollateralAccount,
+                vaultCollateralAccount,
+                treasuryCollateralAccount,
+                vaultAuthority,
+                treasuryStablecoinAccount,
+                mintAuthority: mintAuthorityPda,
+                oraclePriceAccount: priceFeedPda,
+                liquidator: wallet.publicKey,
+                tokenProgram: TOKEN_PROGRAM_ID,
+            } as any)
+            .remainingAccounts(remainingAccounts)
+            .rpc();
+
+    const auctionOf = (owner: PublicKey) =>
+        PublicKey.findProgramAddressSync(
+            [Buffer.from("liquidation-auction"), positionOf(owner).toBuffer()],
+            program.programId
+        )[0];
+
+    const bid = (owner: PublicKey, collateralAmount: number, maxPrice: number) =>
+        program.methods
+            .bidLiquidationAuction(new anchor.BN(collateralAmount), new anchor.BN(maxPrice))
+            .accounts({
+                auction: auctionOf(owner),
+                position: positionOf(owner),
+                vaultType: vaultTypePda,
+                protocolState: protocolStatePda,
+                stablecoinMint,
+                bidderStablecoinAccount: liquidatorStablecoinAccount,
+                bidderCollateralAccount: liquidatorCollateralAccount,
+                vaultCollateralAccount,
+                vaultAuthority,
+                keeper: wallet.publicKey,
+                bidder: wallet.publicKey,
+                tokenProgram: TOKEN_PROGRAM_ID,
+            } as any)
+            .rpc();
+
+    const debtOf = async (owner: PublicKey) =>
+        (await program.account.position.fetch(positionOf(owner))).debtAmount.toNumber();
+
+    before(async () => {
+        [protocolStatePda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("protocol_state")],
+            program.programId
+        );
+        [mintAuthorityPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("mint_authority")],
+            program.programId
+        );
+
+        try {
+            await program.methods
+                .initializeProtocol(wallet.publicKey)
+                .accounts({ admin: wallet.publicKey } as any)
+                .rpc();
+        } catch (e) {
+            // Already initialized by another suite
+        }
+
+        stablecoinMint = await createMint(provider.connection, wallet.payer, wallet.publicKey, null, 6);
+        await program.methods
+            .setStablecoinMint(stablecoinMint)
+            .accounts({
+                protocolState: protocolStatePda,
+                adminPubkey: wallet.publicKey,
+            } as any)
+            .rpc();
+        await setAuthority(
+            provider.connection,
+            wallet.payer,
+            stablecoinMint,
+            wallet.publicKey,
+            AuthorityType.MintTokens,
+            mintAuthorityPda
+        );
+
+        collateralMint = await createMint(provider.connection, wallet.payer, wallet.publicKey, null, 9);
+        [vaultTypePda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("vault-type"), collateralMint.toBuffer()],
+            program.programId
+        );
+        [priceFeedPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("price-feed"), vaultTypePda.toBuffer()],
+            program.programId
+        );
+        [vaultAuthority] = PublicKey.findProgramAddressSync(
+            [Buffer.from("vault_authority"), vaultTypePda.toBuffer()],
+            program.programId
+        );
+
+        await program.methods
+            .createVaultType(collateralMint, {
+                collateralDecimals: 9,
+                oracleSource: 1, // push feed
+                oraclePriceAccount: priceFeedPda,
+                oracleFeedId: new Array(32).fill(0),
+                oracleMaxAgeSeconds: new anchor.BN(0),
+                oraclePriceExponent: 0,
+                oracleVerificationLevel: 0,
+                oracleMinSignatures: 0,
+                confMultiplierBps: 10000,
+                maxConfRatioBps: 0,
+                pricingMode: 0, // spot
+                stakePoolAccount: PublicKey.default,
+                isPegged: false,
+                minPrice: new anchor.BN(0),
+                maxPrice: new anchor.BN(0),
+                secondaryOracleAccount: PublicKey.default,
+                secondaryOracleMaxAgeSeconds: new anchor.BN(0),
+                oracleAggregation: 0,
+                maxOracleDeviationBps: 0,
+                ltvBps: new anchor.BN(5000),
+                liqThresholdBps: new anchor.BN(8000),
+                liqPenaltyBps: new anchor.BN(500),
+                stabilityFeeBps: 0,
+                mintFeeBps: 0,
+                redeemFeeBps: 0,
+                vaultDebtCeiling: new anchor.BN(1_000_000_000),
+                closeFactorBps: 5000, // at most half the debt per call
+                targetHealthBps: 0,
+                fullLiquidationHealthBps: 0,
+                liquidationDustThreshold: new anchor.BN(0),
+                liquidationMode: 0, // fixed discount
+                auctionStartPremiumBps: 0,
+                auctionFloorBps: 0,
+                auctionDurationSeconds: new anchor.BN(0),
+                liquidationProtocolShareBps: 5000,
+                keeperReward: new anchor.BN(0),
+                keeperRewardMaxDebt: new anchor.BN(0),
+                liquidationBonusMinBps: 0,
+                liquidationBonusMaxBps: 0, // fixed penalty
+                maxPriceMoveBpsPerMinute: 0, // circuit breaker disabled
+                twapWindowSeconds: new anchor.BN(0), // provider EMA
+            })
+            .accounts({
+                vaultType: vaultTypePda,
+                collateralMintAccount: collateralMint,
+                protocolState: protocolStatePda,
+                admin: wallet.publicKey,
+                systemProgram: SystemProgram.programId,
+            } as any)
+            .rpc();
+
+        await program.methods
+            .initializePriceFeed(10000)
+            .accounts({
+                priceFeed: priceFeedPda,
+                vaultType: vaultTypePda,
+                protocolState: protocolStatePda,
+                admin: wallet.publicKey,
+                systemProgram: SystemProgram.programId,
+            } as any)
+            .rpc();
+        publishTime = (await provider.connection.getBlockTime(await provider.connection.getSlot()))! - 60;
+        await pushPrice(100_000_000);
+
+        vaultCollateralAccount = (
+            await getOrCreateAssociatedTokenAccount(provider.connection, wallet.payer, collateralMint, vaultAuthority, true)
+        ).address;
+        treasuryStablecoinAccount = (
+            await getOrCreateAssociatedTokenAccount(provider.connection, wallet.payer, stablecoinMint, wallet.publicKey)
+        ).address;
+        treasuryCollateralAccount = (
+            await getOrCreateAssociatedTokenAccount(provider.connection, wallet.payer, collateralMint, wallet.publicKey)
+        ).address;
+        // The treasury is the wallet, so the liquidator uses separate token accounts
+        liquidatorStablecoinAccount = await createAccount(
+            provider.connection, wallet.payer, stablecoinMint, wallet.publicKey, Keypair.generate()
+        );
+        liquidatorCollateralAccount = await createAccount(
+            provider.connection, wallet.payer, collateralMint, wallet.publicKey, Keypair.generate()
+        );
+
+        for (const borrower of borrowers) {
+            const signature = await provider.connection.requestAirdrop(borrower.publicKey, LAMPORTS_PER_SOL);
+            await provider.connection.confirmTransaction(signature);
+
+            const position = positionOf(borrower.publicKey);
+            const userCollateralAccount = (
+                await getOrCreateAssociatedTokenAccount(provider.connection, wallet.payer, collateralMint, borrower.publicKey)
+            ).address;
+            await mintTo(provider.connection, wallet.payer, collateralMint, userCollateralAccount, wallet.publicKey, 1_000_000_000);
+
+            await program.methods
+                .openPosition()
+                .accounts({
+                    position,
+                    vaultType: vaultTypePda,
+                    owner: borrower.publicKey,
+                    systemProgram: SystemProgram.programId,
+                } as any)
+                .signers([borrower])
+                .rpc();
+            await program.methods
+                .depositCollateral(new anchor.BN(1_000_000_000))
+                .accounts({
+                    position,
+                    vaultType: vaultTypePda,
+                    protocolState: protocolStatePda,
+                    userCollateralAccount,
+                    vaultCollateralAccount,
+                    vaultAuthority,
+                    oraclePriceAccount: priceFeedPda,
+                    owner: borrower.publicKey,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                } as any)
+                .signers([borrower])
+                .rpc();
+            // Borrowed stablecoin funds the liquidator
+            await program.methods
+                .mintStablecoin(new anchor.BN(DEBT))
+                .accounts({
+                    position,
+                    vaultType: vaultTypePda,
+                    protocolState: protocolStatePda,
+                    stablecoinMint,
+                    userStablecoinAccount: liquidatorStablecoinAccount,
+                    treasuryStablecoinAccount,
+                    mintAuthority: mintAuthorityPda,
+                    oraclePriceAccount: priceFeedPda,
+                    owner: borrower.publicKey,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                } as any)
+                .signers([borrower])
+                .rpc();
+        }
+    });
+
+    it("Rejects invalid liquidation settings", async () => {
+        await expectError(updateVaultType({ closeFactorBps: 0 }), "InvalidLiquidationConfig");
+        await expectError(updateVaultType({ closeFactorBps: 10001 }), "InvalidLiquidationConfig");
+        await expectError(updateVaultType({ targetHealthBps: 9000 }), "InvalidLiquidationConfig");
+        await expectError(updateVaultType({ fullLiquidationHealthBps: 10000 }), "InvalidLiquidationConfig");
+    });
+
+    it("Caps a liquidation at the close factor", async () => {
+        // $60: health 60 * 0.8 / 50 = 0.96
+        await pushPrice(60_000_000);
+        await liquidate(borrowers[0].publicKey, DEBT);
+        expect(await debtOf(borrowers[0].publicKey)).to.equal(DEBT / 2);
+
+        // The rest of the position is healthy again
+        await expectError(liquidate(borrowers[0].publicKey, DEBT), "PositionHealthy");
+    });
+
+    it("Stops at the target health", async () => {
+        await updateVaultType({ targetHealthBps: 10500 });
+
+        // r = (1.05 * 50 - 60 * 0.8) / (1.05 - 1.05 * 0.8), rounded up
+        await liquidate(borrowers[1].publicKey, DEBT);
+        expect(await debtOf(borrowers[1].publicKey)).to.equal(DEBT - 21_428_572);
+    });
+
+    it("Fully liquidates positions below the full liquidation health", async () => {
+        await updateVaultType({ fullLiquidationHealthBps: 9000 });
+
+        // $55: health 55 * 0.8 / 50 = 0.88, and the collateral still covers the debt plus penalty
+        await pushPrice(55_000_000);
+        await liquidate(borrowers[2].publicKey, DEBT);
+        expect(await debtOf(borrowers[2].publicKey)).to.equal(0);
+    });
+
+    it("Fully liquidates when a partial liquidation would leave dust", async () => {
+        await updateVaultType({
+            targetHealthBps: 0,
+            fullLiquidationHealthBps: 0,
+            liquidationDustThreshold: new anchor.BN(DEBT / 2),
+        });
+
+        await liquidate(borrowers[3].publicKey, DEBT);
+        expect(await debtOf(borrowers[3].publicKey)).to.equal(0);
+    });
+
+    it("Sells seized collateral in a Dutch auction", async () => {
+        const owner = borrowers[4].publicKey;
+        await updateVaultType({
+            liquidationDustThreshold: new anchor.BN(0),
+            liquidationMode: 1, // auction
+            auctionStartPremiumBps: 11000,
+            auctionFloorBps: 9000,
+            auctionDurationSeconds: new anchor.BN(3600),
+        });
+        await expectError(liquidate(owner, DEBT), "LiquidationModeMismatch");
+
+        await pushPrice(45_000_000);
+
+        // $45: half the debt moves to the auction with collateral worth it plus the 5% penalty
+        await program.methods
+            .startLiquidationAuction()
+            .accounts({
+                auction: auctionOf(owner),
+                position: positionOf(owner),
+                vaultType: vaultTypePda,
+                protocolState: protocolStatePda,
+                oraclePriceAccount: priceFeedPda,
+                keeper: wallet.publicKey,
+                systemProgram: SystemProgram.programId,
+            } as any)
+            .rpc();
+
+        let auction = await program.account.liquidationAuction.fetch(auctionOf(owner));
+        expect(auction.debtRemaining.toNumber()).to.equal(DEBT / 2);
+        expect(auction.collateralAmount.toNumber()).to.equal(583_333_333);
+        expect(auction.startPrice.toNumber()).to.equal(49_500_000);
+        expect(auction.floorPrice.toNumber()).to.equal(40_500_000);
+        expect(await debtOf(owner)).to.equal(DEBT / 2);
+        const collateralAfterStart = (await program.account.position.fetch(positionOf(owner))).collateralAmount.toNumber();
+        expect(collateralAfterStart).to.equal(1_000_000_000 - 583_333_333);
+
+        await expectError(bid(owner, 100_000_000, 40_000_000), "AuctionPriceTooHigh");
+
+        // Buy a chunk of 0.1 tokens near the starting price
+        const balanceBefore = Number((await getAccount(provider.connection, liquidatorStablecoinAccount)).amount);
+        await bid(owner, 100_000_000, 49_500_000);
+        auction = await program.account.liquidationAuction.fetch(auctionOf(owner));
+        expect(auction.collateralAmount.toNumber()).to.equal(483_333_333);
+        expect(auction.debtRemaining.toNumber()).to.be.closeTo(DEBT / 2 - 4_950_000, 50_000);
+
+        // Buying the rest raises the remaining debt and returns the surplus to the position
+        await bid(owner, 1_000_000_000, 49_500_000);
+        expect(await program.account.liquidationAuction.fetchNullable(auctionOf(owner))).to.be.null;
+
+        const balanceAfter = Number((await getAccount(provider.connection, liquidatorStablecoinAccount)).amount);
+        expect(balanceBefore - balanceAfter).to.equal(DEBT / 2);
+        const position = await program.account.position.fetch(positionOf(owner));
+        expect(position.collateralAmount.toNumber()).to.be.greaterThan(collateralAfterStart);
+        expect(position.debtAmount.toNumber()).to.equal(DEBT / 2);
+    });
+
+    it("Settles an expired auction and writes off the debt it didn't raise", async () => {
+        const owner = borrowers[4].publicKey;
+        const balance = async (account: PublicKey) => Number((await getAccount(provider.connection, account)).amount);
+        const settle = () =>
+            program.methods
+                .settleLiquidationAuction()
+                .accounts({
+                    auction: auctionOf(owner),
+                    position: positionOf(owner),
+                    vaultType: vaultTypePda,
+                    protocolState: protocolStatePda,
+                    vaultCollateralAccount,
+                    treasuryCollateralAccount,
+                    vaultAuthority,
+                    keeper: wallet.publicKey,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                } as any)
+                .rpc();
+
+        // The rest of the position, still under water at $45, goes to a 3 second auction
+        await updateVaultType({ auctionDurationSeconds: new anchor.BN(3) });
+        await program.methods
+            .startLiquidationAuction()
+            .accounts({
+                auction: auctionOf(owner),
+                position: positionOf(owner),
+                vaultType: vaultTypePda,
+                protocolState: protocolStatePda,
+                oraclePriceAccount: priceFeedPda,
+                keeper: wallet.publicKey,
+                systemProgram: SystemProgram.programId,
+            } as any)
+            .rpc();
+        await expectError(settle(), "AuctionStillRunning");
+
+        const auction = await program.account.liquidationAuction.fetch(auctionOf(owner));
+        const collateralBefore = (await program.account.position.fetch(positionOf(owner))).collateralAmount.toNumber();
+        const treasuryBefore = await balance(treasuryCollateralAccount);
+        const before = await program.account.protocolState.fetch(protocolStatePda);
+
+        // Nobody bids: once expired the unraised debt is written off
+        await new Promise((resolve) => setTimeout(resolve, 4000));
+        await settle();
+        expect(await program.account.liquidationAuction.fetchNullable(auctionOf(owner))).to.be.null;
+
+        const after = await program.account.protocolState.fetch(protocolStatePda);
+        const writtenOff = after.totalBadDebtWrittenOff.sub(before.totalBadDebtWrittenOff).toNumber();
+        expect(writtenOff).to.equal(auction.debtRemaining.toNumber());
+        expect(before.totalProtocolDebt.sub(after.totalProtocolDebt).toNumber()).to.equal(writtenOff);
+
+        // The treasury takes the collateral backing the written off debt at the floor price,
+        // the position keeps anything beyond it
+        const toTreasury = (await balance(treasuryCollateralAccount)) - treasuryBefore;
+        const toPosition = (await program.account.position.fetch(positionOf(owner))).collateralAmount.toNumber() - collateralBefore;
+        const backing = auction.debtRemaining.mul(new anchor.BN(1_000_000_000)).div(auction.floorPrice);
+        expect(toTreasury).to.equal(anchor.BN.min(auction.collateralAmount, backing).toNumber());
+        expect(toTreasury + toPosition).to.equal(auction.collateralAmount.toNumber());
+
+        await updateVaultType({ auctionDurationSeconds: new anchor.BN(3600) });
+    });
+
+    it("Absorbs a liquidation with the stability pool", async () => {
+        const owner = borrowers[5].publicKey;
+        const depositor = Keypair.generate();
+        const [stabilityPool] = PublicKey.findProgramAddressSync(
+            [Buffer.from("stability-pool"), vaultTypePda.toBuffer()],
+            program.programId
+        );
+        const depositOf = (depositorKey: PublicKey) =>
+            PublicKey.findProgramAddressSync(
+                [Buffer.from("stability-deposit"), stabilityPool.toBuffer(), depositorKey.toBuffer()],
+                program.programId
+            )[0];
+
+        await program.methods
+            .initializeStabilityPool()
+            .accounts({
+                stabilityPool,
+                vaultType: vaultTypePda,
+                protocolState: protocolStatePda,
+                admin: wallet.publicKey,
+                systemProgram: SystemProgram.programId,
+            } as any)
+            .rpc();
+        const poolStablecoinAccount = (
+            await getOrCreateAssociatedTokenAccount(provider.connection, wallet.payer, stablecoinMint, stabilityPool, true)
+        ).address;
+
+        const signature = await provider.connection.requestAirdrop(depositor.publicKey, LAMPORTS_PER_SOL);
+        await provider.connection.confirmTransaction(signature);
+        const depositorStablecoinAccount = (
+            await getOrCreateAssociatedTokenAccount(provider.connection, wallet.payer, stablecoinMint, depositor.publicKey)
+        ).address;
+        const depositorCollateralAccount = (
+            await getOrCreateAssociatedTokenAccount(provider.connection, wallet.payer, collateralMint, depositor.publicKey)
+        ).address;
+        await transfer(
+            provider.connection, wallet.payer, liquidatorStablecoinAccount, depositorStablecoinAccount, wallet.publicKey, 10_000_000
+        );
+
+        const poolAccounts = (depositorKey: PublicKey, stablecoinAccount: PublicKey, collateralAccount: PublicKey) => ({
+            stabilityDeposit: depositOf(depositorKey),
+            stabilityPool,
+            vaultType: vaultTypePda,
+            protocolState: protocolStatePda,
+            userStablecoinAccount: stablecoinAccount,
+            poolStablecoinAccount,
+            userCollateralAccount: collateralAccount,
+            vaultCollateralAccount,
+            vaultAuthority,
+            owner: depositorKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+        });
+        const walletAccounts = poolAccounts(wallet.publicKey, liquidatorStablecoinAccount, liquidatorCollateralAccount);
+        const depositorAccounts = poolAccounts(depositor.publicKey, depositorStablecoinAccount, depositorCollateralAccount);
+
+        // 30 + 10 AGSUSD deposited, 3:1
+        for (const [signer, accounts, amount] of [
+            [wallet.payer, walletAccounts, 30_000_000],
+            [depositor, depositorAccounts, 10_000_000],
+        ] as [Keypair, ReturnType<typeof poolAccounts>, number][]) {
+            await program.methods
+                .openStabilityDeposit()
+                .accounts({
+                    stabilityDeposit: accounts.stabilityDeposit,
+                    stabilityPool,
+                    owner: signer.publicKey,
+                    systemProgram: SystemProgram.programId,
+                } as any)
+                .signers([signer])
+                .rpc();
+            await program.methods
+                .provideToStabilityPool(new anchor.BN(amount))
+                .accounts(accounts as any)
+                .signers([signer])
+                .rpc();
+        }
+
+        const liquidateWithPool = (positionOwner: PublicKey) =>
+            program.methods
+                .liquidateWithStabilityPool()
+                .accounts({
+                    position: positionOf(positionOwner),
+                    vaultType: vaultTypePda,
+                    protocolState: protocolStatePda,
+                    stabilityPool,
+                    stablecoinMint,
+                    poolStablecoinAccount,
+                    vaultCollateralAccount,
+                    treasuryCollateralAccount,
+                    vaultAuthority,
+                    oraclePriceAccount: priceFeedPda,
+                    keeperStablecoinAccount: liquidatorStablecoinAccount,
+                    treasuryStablecoinAccount,
+                    mintAuthority: mintAuthorityPda,
+                    keeper: wallet.publicKey,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                } as any)
+                .rpc();
+
+        // Auction-mode vault types don't sell collateral to the pool at the fixed discount
+        await expectError(liquidateWithPool(owner), "LiquidationModeMismatch");
+        await updateVaultType({ liquidationMode: 0 });
+
+        // $45: half the debt is cancelled against the pool, which receives collateral worth it
+        // plus 5%, less the protocol's half of the penalty
+        const treasuryCollateral = async () => Number((await getAccount(provider.connection, treasuryCollateralAccount)).amount);
+        let treasuryBefore = await treasuryCollateral();
+        await liquidateWithPool(owner);
+
+        expect(await debtOf(owner)).to.equal(DEBT / 2);
+        const pool = await program.account.stabilityPool.fetch(stabilityPool);
+        expect(pool.totalDeposits.toNumber()).to.equal(15_000_000);
+        expect(pool.totalCollateral.toNumber()).to.equal(569_444_444);
+        expect((await treasuryCollateral()) - treasuryBefore).to.equal(583_333_333 - 569_444_444);
+        expect(Number((await getAccount(provider.connection, poolStablecoinAccount)).amount)).to.equal(15_000_000);
+
+        // Depositors share the loss and the collateral pro-rata
+        const walletCollateralBefore = Number((await getAccount(provider.connection, liquidatorCollateralAccount)).amount);
+        await program.methods.withdrawFromStabilityPool(new anchor.BN(0)).accounts(walletAccounts as any).rpc();
+        const walletGain = Number((await getAccount(provider.connection, liquidatorCollateralAccount)).amount) - walletCollateralBefore;
+        expect(walletGain).to.be.closeTo(427_083_333, 2);
+        const walletDeposit = await program.account.stabilityDeposit.fetch(depositOf(wallet.publicKey));
+        expect(walletDeposit.initialValue.toNumber()).to.be.closeTo(11_250_000, 1);
+
+        await program.methods
+            .withdrawFromStabilityPool(new anchor.BN(DEBT))
+            .accounts(depositorAccounts as any)
+            .signers([depositor])
+            .rpc();
+        expect(Number((await getAccount(provider.connection, depositorStablecoinAccount)).amount)).to.be.closeTo(3_750_000, 1);
+        expect(Number((await getAccount(provider.connection, depositorCollateralAccount)).amount)).to.be.closeTo(142_361_111, 2);
+
+        // $10: the $10 of collateral only pays for $9.52 of debt with the penalty on top, so
+        // the pool cancels that much for all of it rather than the $11.25 it holds
+        await pushPrice(10_000_000);
+        const poolBefore = await program.account.stabilityPool.fetch(stabilityPool);
+        treasuryBefore = await treasuryCollateral();
+        await liquidateWithPool(borrowers[18].publicKey);
+        const poolAfter = await program.account.stabilityPool.fetch(stabilityPool);
+        expect(poolBefore.totalDeposits.sub(poolAfter.totalDeposits).toNumber()).to.equal(9_523_809);
+        const toPool = poolAfter.totalCollateral.sub(poolBefore.totalCollateral).toNumber();
+        const toTreasury = (await treasuryCollateral()) - treasuryBefore;
+        expect(toPool + toTreasury).to.equal(1_000_000_000);
+        expect(toTreasury).to.equal(23_809_550);
+        const position = await program.account.position.fetch(positionOf(borrowers[18].publicKey));
+        expect(position.collateralAmount.toNumber()).to.equal(0);
+        expect(position.debtAmount.toNumber()).to.equal(DEBT - 9_523_809);
+        await pushPrice(45_000_000);
+    });
+
+    it("Splits the penalty by the protocol share and pays a keeper reward", async () => {
+        const owner = borrowers[7].publicKey;
+        const balance = async (account: PublicKey) =>
+            Number((await getAccount(provider.connection, account)).amount);
+        const collectFees = () =>
+            program.methods
+                .collectStabilityFees()
+                .accounts({
+                    vaultType: vaultTypePda,
+                    protocolState: protocolStatePda,
+                    stablecoinMint,
+                    treasuryStablecoinAccount,
+                    mintAuthority: mintAuthorityPda,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                } as any)
+                .rpc();
+
+        await expectError(updateVaultType({ liquidationProtocolShareBps: 10001 }), "InvalidLiquidationConfig");
+
+        // Retain some stability fees in the surplus buffer
+        await program.methods
+            .setSurplusBufferCap(new anchor.BN(1_000_000_000_000))
+            .accounts({ protocolState: protocolStatePda, governancePubkey: wallet.publicKey } as any)
+            .rpc();
+        await updateVaultType({ stabilityFeeBps: 10000 });
+        await new Promise((resolve) => setTimeout(resolve, 2000));
+        await collectFees();
+        await updateVaultType({ stabilityFeeBps: 0 });
+        const bufferBefore = (await program.account.protocolState.fetch(protocolStatePda)).surplusBuffer.toNumber();
+        expect(bufferBefore).to.be.greaterThan(0);
+        expect(bufferBefore).to.be.lessThan(1_000_000);
+
+        // The treasury lets the protocol pay the rest of the reward
+        await approve(provider.connection, wallet.payer, treasuryStablecoinAccount, mintAuthorityPda, wallet.publicKey, 1_000_000);
+        await updateVaultType({
+            liquidationMode: 0, // fixed discount
+            liquidationProtocolShareBps: 10000,
+            keeperReward: new anchor.BN(1_000_000),
+            keeperRewardMaxDebt: new anchor.BN(DEBT + 10_000_000),
+        });
+
+        const liquidatorCollateralBefore = await balance(liquidatorCollateralAccount);
+        const treasuryCollateralBefore = await balance(treasuryCollateralAccount);
+        const liquidatorStablecoinBefore = await balance(liquidatorStablecoinAccount);
+        const treasuryStablecoinBefore = await balance(treasuryStablecoinAccount);
+
+        // $45: half the debt is repaid, the whole 5% penalty goes to the protocol
+        await liquidate(owner, DEBT);
+        expect(await debtOf(owner)).to.equal(DEBT / 2);
+        expect((await balance(liquidatorCollateralAccount)) - liquidatorCollateralBefore).to.be.closeTo(555_555_555, 1);
+        expect((await balance(treasuryCollateralAccount)) - treasuryCollateralBefore).to.be.closeTo(27_777_778, 1);
+
+        // The reward comes from the surplus buffer first, then the treasury allowance
+        expect((await balance(liquidatorStablecoinAccount)) - liquidatorStablecoinBefore).to.equal(1_000_000 - DEBT / 2);
+        expect(treasuryStablecoinBefore - (await balance(treasuryStablecoinAccount))).to.equal(1_000_000 - bufferBefore);
+        const protocol = await program.account.protocolState.fetch(protocolStatePda);
+        expect(protocol.surplusBuffer.toNumber()).to.equal(0);
+
+        // Repaying less than the call allows earns no reward, so it can't be farmed with tiny repays
+        const stablecoinBeforeTiny = await balance(liquidatorStablecoinAccount);
+        const treasuryBeforeTiny = await balance(treasuryStablecoinAccount);
+        await liquidate(owner, 1);
+        expect(stablecoinBeforeTiny - (await balance(liquidatorStablecoinAccount))).to.equal(1);
+        expect(await balance(treasuryStablecoinAccount)).to.equal(treasuryBeforeTiny);
+
+        await updateVaultType({
+            liquidationProtocolShareBps: 5000,
+            keeperReward: new anchor.BN(0),
+            keeperRewardMaxDebt: new anchor.BN(0),
+        });
+        await program.methods
+            .setSurplusBufferCap(new anchor.BN(0))
+            .accounts({ protocolState: protocolStatePda, governancePubkey: wallet.publicKey } as any)
+            .rpc();
+    });
+
+    it("Flash liquidates with collateral swapped in a callback", async () => {
+        const owner = borrowers[8].publicKey;
+        const balance = async (account: PublicKey) =>
+            Number((await getAccount(provider.connection, account)).amount);
+
+        // The mock callback swaps collateral for AGSUSD out of its own reserve
+        const [reserveAuthority] = PublicKey.findProgramAddressSync(
+            [Buffer.from("reserve")],
+            mockLiquidator.programId
+        );
+        const reserveCollateralAccount = await createAccount(
+            provider.connection, wallet.payer, collateralMint, reserveAuthority, Keypair.generate()
+        );
+        const reserveStablecoinAccount = await createAccount(
+            provider.connection, wallet.payer, stablecoinMint, reserveAuthority, Keypair.generate()
+        );
+        await transfer(
+            provider.connection, wallet.payer, liquidatorStablecoinAccount, reserveStablecoinAccount, wallet.publicKey, DEBT
+        );
+
+        // The keeper starts without any AGSUSD
+        const keeperStablecoinAccount = await createAccount(
+            provider.connection, wallet.payer, stablecoinMint, wallet.publicKey, Keypair.generate()
+        );
+        const keeperCollateralAccount = await createAccount(
+            provider.connection, wallet.payer, collateralMint, wallet.publicKey, Keypair.generate()
+        );
+
+        const flashLiquidate = (callbackProgram: PublicKey, collateralAmount: number, stablecoinAmount: number) =>
+            program.methods
+                .flashLiquidate(
+                    new anchor.BN(DEBT),
+                    mockLiquidator.coder.instruction.encode("swap", {
+                        collateralAmount: new anchor.BN(collateralAmount),
+                        stablecoinAmount: new anchor.BN(stablecoinAmount),
+                    })
+                )
+                .accounts({
+                    position: positionOf(owner),
+                    vaultType: vaultTypePda,
+                    protocolState: protocolStatePda,
+                    stablecoinMint,
+                    liquidatorStablecoinAccount: keeperStablecoinAccount,
+                    liquidatorCollateralAccount: keeperCollateralAccount,
+                    vaultCollateralAccount,
+                    treasuryCollateralAccount,
+                    vaultAuthority,
+                    treasuryStablecoinAccount,
+                    mintAuthority: mintAuthorityPda,
+                    oraclePriceAccount: priceFeedPda,
+                    callbackProgram,
+                    liquidator: wallet.publicKey,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                } as any)
+                .remainingAccounts([
+                    { pubkey: wallet.publicKey, isSigner: true, isWritable: false },
+                    { pubkey: keeperCollateralAccount, isSigner: false, isWritable: true },
+                    { pubkey: keeperStablecoinAccount, isSigner: false, isWritable: true },
+                    { pubkey: reserveCollateralAccount, isSigner: false, isWritable: true },
+                    { pubkey: reserveStablecoinAccount, isSigner: false, isWritable: true },
+                    { pubkey: reserveAuthority, isSigner: false, isWritable: false },
+                    { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+                ])
+                .rpc();
+
+        await expectError(flashLiquidate(program.programId, 0, 0), "InvalidCallbackProgram");
+
+        // $45: 25 AGSUSD must come back, the callback only returns 20
+        await expectError(flashLiquidate(mockLiquidator.programId, 500_000_000, 20_000_000), "FlashLiquidationNotRepaid");
+        expect(await debtOf(owner)).to.equal(DEBT);
+
+        // Sell 0.5 of the ~0.569 tokens received for exactly the repay amount
+        await flashLiquidate(mockLiquidator.programId, 500_000_000, DEBT / 2);
+        expect(await debtOf(owner)).to.equal(DEBT / 2);
+        expect(await balance(keeperStablecoinAccount)).to.equal(0);
+        expect(await balance(keeperCollateralAccount)).to.be.closeTo(69_444_444, 1);
+        expect(await balance(reserveStablecoinAccount)).to.equal(DEBT / 2);
+        expect(await balance(reserveCollateralAccount)).to.equal(500_000_000);
+    });
+
+    it("Liquidates a batch of positions against one oracle read", async () => {
+        // borrowers[2] was fully liquidated, so it is healthy
+        const owners = [borrowers[9].publicKey, borrowers[2].publicKey, borrowers[10].publicKey];
+        const batch = (positions = owners) =>
+            program.methods
+                .liquidateBatch(new anchor.BN(DEBT))
+                .accounts({
+                    vaultType: vaultTypePda,
+                    protocolState: protocolStatePda,
+                    stablecoinMint,
+                    liquidatorStablecoinAccount,
+                    liquidatorCollateralAccount,
+                    vaultCollateralAccount,
+                    treasuryCollateralAccount,
+                    vaultAuthority,
+                    treasuryStablecoinAccount,
+                    mintAuthority: mintAuthorityPda,
+                    oraclePriceAccount: priceFeedPda,
+                    liquidator: wallet.publicKey,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                } as any)
+                .remainingAccounts(
+                    positions.map((owner) => ({ pubkey: positionOf(owner), isSigner: false, isWritable: true }))
+                );
+
+        // Listing a position twice would pay its keeper reward twice
+        await expectError(batch([owners[0], owners[0]]).rpc(), "DuplicateBatchPosition");
+
+        // $45: each unhealthy position is capped at the close factor
+        const results = await batch().view();
+        expect(results.map((r: any) => r.status)).to.deep.equal([0, 1, 0]);
+        expect(results[0].position.toBase58()).to.equal(positionOf(owners[0]).toBase58());
+        expect(results[0].debtRepaid.toNumber()).to.equal(DEBT / 2);
+        expect(results[0].collateralSeized.toNumber()).to.equal(583_333_333);
+        expect(results[1].debtRepaid.toNumber()).to.equal(0);
+
+        const balanceBefore = Number((await getAccount(provider.connection, liquidatorStablecoinAccount)).amount);
+        await batch().rpc();
+        expect(await debtOf(owners[0])).to.equal(DEBT / 2);
+        expect(await debtOf(owners[1])).to.equal(0);
+        expect(await debtOf(owners[2])).to.equal(DEBT / 2);
+        const balanceAfter = Number((await getAccount(provider.connection, liquidatorStablecoinAccount)).amount);
+        expect(balanceBefore - balanceAfter).to.equal(DEBT);
+    });
+
+    it("Scales the liquidation bonus with how far health is below 1.0", async () => {
+        const seized = async (owner: PublicKey) => {
+            const before = (await program.account.position.fetch(positionOf(owner))).collateralAmount.toNumber();
+            await liquidate(owner, DEBT);
+            const position = await program.account.position.fetch(positionOf(owner));
+            expect(position.debtAmount.toNumber()).to.equal(DEBT / 2);
+            return before - position.collateralAmount.toNumber();
+        };
+
+        await expectError(
+            updateVaultType({ liquidationBonusMinBps: 1000, liquidationBonusMaxBps: 500 }),
+            "InvalidLiquidationConfig"
+        );
+        await expectError(updateVaultType({ liquidationBonusMaxBps: 5001 }), "InvalidLiquidationConfig");
+
+        // $45: health 0.72, so the bonus is 28% instead of the fixed 5%
+        await updateVaultType({ liquidationBonusMinBps: 200, liquidationBonusMaxBps: 3000 });
+        expect(await seized(borrowers[11].publicKey)).to.be.closeTo(711_111_111, 1);
+
+        // Capped at the maximum
+        await updateVaultType({ liquidationBonusMaxBps: 1000 });
+        expect(await seized(borrowers[12].publicKey)).to.be.closeTo(611_111_111, 1);
+
+        // Full liquidation at $45 with the 28% bonus would seize $64 of collateral from a
+        // position holding $45: everything is seized and the repay shrinks to $45 / 1.28,
+        // with the bonus paid inside that total
+        await updateVaultType({ liquidationBonusMaxBps: 3000, closeFactorBps: 10000 });
+        const balance = async (account: PublicKey) =>
+            Number((await getAccount(provider.connection, account)).amount);
+        const liquidatorBefore = await balance(liquidatorCollateralAccount);
+        const treasuryBefore = await balance(treasuryCollateralAccount);
+        await liquidate(borrowers[17].publicKey, DEBT);
+        const position = await program.account.position.fetch(positionOf(borrowers[17].publicKey));
+        expect(position.collateralAmount.toNumber()).to.equal(0);
+        expect(position.debtAmount.toNumber()).to.be.closeTo(DEBT - 35_156_250, 1);
+        const toLiquidator = (await balance(liquidatorCollateralAccount)) - liquidatorBefore;
+        const toTreasury = (await balance(treasuryCollateralAccount)) - treasuryBefore;
+        expect(toLiquidator + toTreasury).to.equal(1_000_000_000);
+        // Half of the 28% bonus: 1 - 1 / 1.28 = 21.875% of the collateral
+        expect(toTreasury).to.be.closeTo(109_375_000, 1);
+        await updateVaultType({ liquidationBonusMaxBps: 1000, closeFactorBps: 5000 });
+
+        // $62: health 0.992, a 0.8% shortfall is raised to the 2% minimum
+        await pushPrice(62_000_000);
+        expect(await seized(borrowers[13].publicKey)).to.be.closeTo(411_290_323, 1);
+
+        await updateVaultType({ liquidationBonusMinBps: 0, liquidationBonusMaxBps: 0 });
+    });
+
+    it("Keeps liquidating while the circuit breaker is tripped", async () => {
+        const mint = (owner: Keypair) =>
+            program.methods
+                .mintStablecoin(new anchor.BN(1))
+                .accounts({
+                    position: positionOf(owner.publicKey),
+                    vaultType: vaultTypePda,
+                    protocolState: protocolStatePda,
+                    stablecoinMint,
+                    userStablecoinAccount: liquidatorStablecoinAccount,
+                    treasuryStablecoinAccount,
+                    mintAuthority: mintAuthorityPda,
+                    oraclePriceAccount: priceFeedPda,
+                    owner: owner.publicKey,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                } as any)
+                .signers([owner])
+                .rpc();
+
+        // 1% per minute from the last accepted $62
+        await updateVaultType({ maxPriceMoveBpsPerMinute: 100 });
+
+        // $55 (-11%): the liquidation trips the breaker and still goes through
+        await pushPrice(55_000_000);
+        await liquidate(borrowers[15].publicKey, DEBT);
+        expect(await debtOf(borrowers[15].publicKey)).to.equal(DEBT / 2);
+        let vault = await program.account.vaultType.fetch(vaultTypePda);
+        expect(vault.isMintPaused).to.be.true;
+        expect(vault.circuitBreakerTripPrice.toNumber()).to.equal(55_000_000);
+        expect(vault.lastAcceptedPrice.toNumber()).to.equal(62_000_000);
+
+        // Minting stays paused, later liquidations don't
+        await expectError(mint(borrowers[16]), "VaultMintPaused");
+        await liquidate(borrowers[16].publicKey, DEBT);
+        expect(await debtOf(borrowers[16].publicKey)).to.equal(DEBT / 2);
+
+        await program.methods
+            .resetCircuitBreaker()
+            .accounts({
+                vaultType: vaultTypePda,
+                protocolState: protocolStatePda,
+                guardianPubkey: wallet.publicKey,
+            } as any)
+            .rpc();
+        await updateVaultType({ maxPriceMoveBpsPerMinute: 0 });
+        vault = await program.account.vaultType.fetch(vaultTypePda);
+        expect(vault.isMintPaused).to.be.false;
+    });
+
+    it("Falls back to the provider EMA while the price history can't cover the TWAP window", async () => {
+        const owner = borrowers[19];
+        const [priceHistoryPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("price-history"), vaultTypePda.toBuffer()],
+            program.programId
+        );
+        const history: AccountMeta[] = [{ pubkey: priceHistoryPda, isSigner: false, isWritable: false }];
+
+        // The TWAP can only be enabled once a price history is attached
+        await expectError(updateVaultType({ twapWindowSeconds: new anchor.BN(600) }), "PriceHistoryInsufficient");
+        await program.methods
+            .initializePriceHistory(60)
+            .accounts({
+                priceHistory: priceHistoryPda,
+                vaultType: vaultTypePda,
+                protocolState: protocolStatePda,
+                admin: wallet.publicKey,
+                systemProgram: SystemProgram.programId,
+            } as any)
+            .rpc();
+        await program.methods
+            .updateVaultType({ twapWindowSeconds: new anchor.BN(600) } as any)
+            .accounts({
+                vaultType: vaultTypePda,
+                protocolState: protocolStatePda,
+                admin: wallet.publicKey,
+            } as any)
+            .remainingAccounts(history)
+            .rpc();
+
+        // Minting needs the TWAP, liquidations don't wait for the history to fill up
+        await expectError(
+            program.methods
+                .mintStablecoin(new anchor.BN(1))
+                .accounts({
+                    position: positionOf(owner.publicKey),
+                    vaultType: vaultTypePda,
+                    protocolState: protocolStatePda,
+                    stablecoinMint,
+                    userStablecoinAccount: liquidatorStablecoinAccount,
+                    treasuryStablecoinAccount,
+                    mintAuthority: mintAuthorityPda,
+                    oraclePriceAccount: priceFeedPda,
+                    owner: owner.publicKey,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                } as any)
+                .remainingAccounts(history)
+                .signers([owner])
+                .rpc(),
+            "PriceHistoryInsufficient"
+        );
+        await liquidate(owner.publicKey, DEBT, history);
+        expect(await debtOf(owner.publicKey)).to.equal(DEBT / 2);
+
+        await updateVaultType({ twapWindowSeconds: new anchor.BN(0) });
+    });
+
+    it("Writes off bad debt and covers it from fees", async () => {
+        const owner = borrowers[6].publicKey;
+        const writeOff = () =>
+            program.methods
+                .writeOffBadDebt()
+                .accounts({
+                    position: positionOf(owner),
+                    vaultType: vaultTypePda,
+                    protocolState: protocolStatePda,
+                    keeper: wallet.publicKey,
+                } as any)
+                .rpc();
+
+        await expectError(writeOff(), "PositionNotInsolvent");
+
+        const balance = async (account: PublicKey) =>
+            Number((await getAccount(provider.connection, account)).amount);
+        const liquidatorCollateralBefore = await balance(liquidatorCollateralAccount);
+        const treasuryCollateralBefore = await balance(treasuryCollateralAccount);
+        const liquidatorStablecoinBefore = await balance(liquidatorStablecoinAccount);
+
+        // $20, below debt / collateral: the close factor allows half the debt, but the
+        // $20 of collateral only pays for $19.05 of it with the 5% penalty on top
+        await updateVaultType({ liquidationMode: 0 });
+        await pushPrice(20_000_000);
+        await liquidate(owner, DEBT);
+        const repaid = 19_047_619;
+        let position = await program.account.position.fetch(positionOf(owner));
+        expect(position.collateralAmount.toNumber()).to.equal(0);
+        expect(position.debtAmount.toNumber()).to.be.closeTo(DEBT - repaid, 1);
+        expect(liquidatorStablecoinBefore - (await balance(liquidatorStablecoinAccount))).to.equal(repaid);
+
+        // Exactly the position's collateral leaves the vault, split inside that total
+        const toLiquidator = (await balance(liquidatorCollateralAccount)) - liquidatorCollateralBefore;
+        const toTreasury = (await balance(treasuryCollateralAccount)) - treasuryCollateralBefore;
+        expect(toLiquidator + toTreasury).to.equal(1_000_000_000);
+        expect(toTreasury).to.be.closeTo(23_809_525, 1);
+
+        const before = await program.account.protocolState.fetch(protocolStatePda);
+        await writeOff();
+        const after = await program.account.protocolState.fetch(protocolStatePda);
+
+        position = await program.account.position.fetch(positionOf(owner));
+        expect(position.debtAmount.toNumber()).to.equal(0);
+        const writtenOff = after.totalBadDebtWrittenOff.sub(before.totalBadDebtWrittenOff).toNumber();
+        expect(writtenOff).to.be.closeTo(DEBT - repaid, 2);
+        expect(before.totalProtocolDebt.sub(after.totalProtocolDebt).toNumber()).to.equal(writtenOff);
+        // Absorbed by the surplus buffer first, the rest is deficit
+        const covered = before.surplusBuffer.sub(after.surplusBuffer).toNumber();
+        expect(after.deficit.sub(before.deficit).toNumber()).to.equal(writtenOff - covered);
+        await expectError(writeOff(), "PositionNotInsolvent");
+
+        // Collected stability fees cover the deficit before reaching the treasury
+        await updateVaultType({ stabilityFeeBps: 10000 });
+        await new Promise((resolve) => setTimeout(resolve, 2000));
+        const treasuryBefore = Number((await getAccount(provider.connection, treasuryStablecoinAccount)).amount);
+        await program.methods
+            .collectStabilityFees()
+            .accounts({
+                vaultType: vaultTypePda,
+                protocolState: protocolStatePda,
+                stablecoinMint,
+                treasuryStablecoinAccount,
+                mintAuthority: mintAuthorityPda,
+                tokenProgram: TOKEN_PROGRAM_ID,
+            } as any)
+            .rpc();
+        await updateVaultType({ stabilityFeeBps: 0 });
+
+        const healed = await program.account.protocolState.fetch(protocolStatePda);
+        expect(healed.deficit.lt(after.deficit)).to.be.true;
+        expect(Number((await getAccount(provider.connection, treasuryStablecoinAccount)).amount)).to.equal(treasuryBefore);
+    });
+
+    it("Redistributes an under water position to the other positions", async () => {
+        const PRECISION = new anchor.BN("1000000000000000000");
+        const owner = borrowers[14].publicKey;
+        const walletPosition = positionOf(wallet.publicKey);
+        const [stabilityPool] = PublicKey.findProgramAddressSync(
+            [Buffer.from("stability-pool"), vaultTypePda.toBuffer()],
+            program.programId
+        );
+        const redistribute = (positionOwner: PublicKey) =>
+            program.methods
+                .redistributePosition()
+                .accounts({
+                    position: positionOf(positionOwner),
+                    vaultType: vaultTypePda,
+                    protocolState: protocolStatePda,
+                    stabilityPool,
+                    oraclePriceAccount: priceFeedPda,
+                    keeper: wallet.publicKey,
+                } as any)
+                .rpc();
+        const deposit = () =>
+            program.methods
+                .depositCollateral(new anchor.BN(1_000_000_000))
+                .accounts({
+                    position: walletPosition,
+                    vaultType: vaultTypePda,
+                    protocolState: protocolStatePda,
+                    userCollateralAccount: liquidatorCollateralAccount,
+                    vaultCollateralAccount,
+                    vaultAuthority,
+                    oraclePriceAccount: priceFeedPda,
+                    owner: wallet.publicKey,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                } as any)
+                .rpc();
+
+        // A debt-free position that receives a share
+        await program.methods
+            .openPosition()
+            .accounts({
+                position: walletPosition,
+                vaultType: vaultTypePda,
+                owner: wallet.publicKey,
+                systemProgram: SystemProgram.programId,
+            } as any)
+            .rpc();
+        await deposit();
+
+        // Written off positions have no collateral left to redistribute
+        await expectError(redistribute(borrowers[6].publicKey), "PositionNotUnderwater");
+
+        // $20: 1 token no longer covers the 50 debt, but it's left to the stability pool
+        // while the pool holds enough to absorb it
+        const poolAccounts = {
+            stabilityDeposit: PublicKey.findProgramAddressSync(
+                [Buffer.from("stability-deposit"), stabilityPool.toBuffer(), wallet.publicKey.toBuffer()],
+                program.programId
+            )[0],
+            stabilityPool,
+            vaultType: vaultTypePda,
+            protocolState: protocolStatePda,
+            userStablecoinAccount: liquidatorStablecoinAccount,
+            poolStablecoinAccount: (
+                await getOrCreateAssociatedTokenAccount(provider.connection, wallet.payer, stablecoinMint, stabilityPool, true)
+            ).address,
+            userCollateralAccount: liquidatorCollateralAccount,
+            vaultCollateralAccount,
+            vaultAuthority,
+            owner: wallet.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+        };
+        await program.methods.provideToStabilityPool(new anchor.BN(DEBT)).accounts(poolAccounts as any).rpc();
+        await expectError(redistribute(owner), "StabilityPoolCanAbsorb");
+        await program.methods.withdrawFromStabilityPool(new anchor.BN(DEBT)).accounts(poolAccounts as any).rpc();
+
+        const before = await program.account.vaultType.fetch(vaultTypePda);
+        const position = await program.account.position.fetch(positionOf(owner));
+        expect(position.stake.toNumber()).to.equal(1_000_000_000);
+        await redistribute(owner);
+
+        const after = await program.account.vaultType.fetch(vaultTypePda);
+        const emptied = await program.account.position.fetch(positionOf(owner));
+        expect(emptied.collateralAmount.toNumber()).to.equal(0);
+        expect(emptied.debtAmount.toNumber()).to.equal(0);
+        expect(emptied.stake.toNumber()).to.equal(0);
+        const otherStakes = before.totalStakes.sub(position.stake);
+        expect(after.totalStakes.toString()).to.equal(otherStakes.toString());
+        expect(after.totalCollateral.toString()).to.equal(before.totalCollateral.toString());
+        expect(after.totalNormalizedDebt.toString()).to.equal(before.totalNormalizedDebt.toString());
+        const lColl = position.collateralAmount.mul(PRECISION).div(otherStakes);
+        const lDebt = position.debtAmount.mul(PRECISION).div(otherStakes);
+        expect(after.lColl.toString()).to.equal(before.lColl.add(lColl).toString());
+        expect(after.lDebt.toString()).to.equal(before.lDebt.add(lDebt).toString());
+
+        // The wallet's share is applied on its next touch
+        await deposit();
+        const walletAfter = await program.account.position.fetch(walletPosition);
+        const share = (accumulator: anchor.BN) => new anchor.BN(1_000_000_000).mul(accumulator).div(PRECISION);
+        expect(walletAfter.collateralAmount.toString()).to.equal(share(lColl).add(new anchor.BN(2_000_000_000)).toString());
+        expect(walletAfter.debtAmount.toString()).to.equal(share(lDebt).toString());
+        expect(walletAfter.lCollSnapshot.toString()).to.equal(after.lColl.toString());
+
+        await expectError(redistribute(owner), "PositionNotUnderwater");
+    });
+});
@@ -0,0 +1,234 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { getOrCreateAssociatedTokenAccount, mintTo, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import { expect } from "chai";
+import { AegisVault } from "../target/types/aegis_vault";
+
+// Locally constructed fixtures loaded by the test validator (see Anchor.toml):
+// - pyth-sol-usd: Pyth PriceUpdateV2, SOL/USD $150.00 +/- 0.05, published 2025-01-01T00:00:00Z
+// - lst-mint: 9 decimal LST mint whose authority is the all-ones seed keypair below
+// - lst-stake-pool: SPL stake pool for lst-mint, 1.1 SOL per pool token, updated in epoch 0
+// - lst-stake-pool-outdated: same pool, last updated in epoch 1
+const PYTH_SOL_USD = new PublicKey("BYfEm3V688PTMDVWRSHTEdCtByQfvczWByAMC6cahnDs");
+const LST_MINT = new PublicKey("CuWS6rbrdoCSLn6fY2QKqDDmemxpwgGUiLgrNitFunyx");
+const LST_STAKE_POOL = new PublicKey("3a3MVW7o5n7thxm3XGtVkmiGKvvbhRdjR5nhiJvL9Pqj");
+const LST_STAKE_POOL_OUTDATED = new PublicKey("DNK4Vw2qLG6Jy3Q7n2bjtp7xAvA4xWJPgbY246BYy1ZT");
+const LST_MINT_AUTHORITY = Keypair.fromSeed(new Uint8Array(32).fill(1));
+const SOL_USD_FEED_ID = "ef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d";
+
+const TEN_YEARS = new anchor.BN(10 * 365 * 24 * 60 * 60);
+const ORACLE_SOURCE_STAKE_POOL = 2;
+
+async function expectError(promise: Promise<unknown>, code: string) {
+    try {
+        await promise;
+    } catch (e) {
+        expect((e as anchor.AnchorError).error?.errorCode?.code).to.equal(code);
+        return;
+    }
+    expect.fail(`expected ${code}`);
+}
+
+describe("stake pool LST oracle", () => {
+    const provider = anchor.AnchorProvider.env();
+    anchor.setProvider(provider);
+
+    const program = anchor.workspace.AegisVault as Program<AegisVault>;
+    const wallet = provider.wallet as anchor.Wallet;
+
+    let protocolStatePda: PublicKey;
+    let vaultTypePda: PublicKey;
+    let vaultAuthority: PublicKey;
+    let position: PublicKey;
+    let userCollateralAccount: PublicKey;
+    let vaultCollateralAccount: PublicKey;
+
+    const vaultTypeParams = (stakePoolAccount: PublicKey) => ({
+        collateralDecimals: 9,
+        oracleSource: ORACLE_SOURCE_STAKE_POOL,
+        oraclePriceAccount: PYTH_SOL_USD,
+        oracleFeedId: Array.from(Buffer.from(SOL_USD_FEED_ID, "hex")),
+        oracleMaxAgeSeconds: TEN_YEARS,
+        oraclePriceExponent: -8,
+        oracleVerificationLevel: 0, // Full
+        oracleMinSignatures: 0,
+        confMultiplierBps: 10000,
+        maxConfRatioBps: 200, // 2%
+        pricingMode: 0, // spot
+        stakePoolAccount,
+        isPegged: false,
+        minPrice: new anchor.BN(0),
+        maxPrice: new anchor.BN(0),
+        secondaryOracleAccount: PublicKey.default,
+        secondaryOracleMaxAgeSeconds: new anchor.BN(0),
+        oracleAggregation: 0, // primary only
+        maxOracleDeviationBps: 0,
+        ltvBps: new anchor.BN(5000),
+        liqThresholdBps: new anchor.BN(8000),
+        liqPenaltyBps: new anchor.BN(500),
+        stabilityFeeBps: 0,
+        mintFeeBps: 0,
+        redeemFeeBps: 0,
+        vaultDebtCeiling: new anchor.BN(1_000_000_000),
+        closeFactorBps: 10000, // full liquidation
+        targetHealthBps: 0,
+        fullLiquidationHealthBps: 0,
+        liquidationDustThreshold: new anchor.BN(0),
+        liquidationMode: 0, // fixed discount
+        auctionStartPremiumBps: 0,
+        auctionFloorBps: 0,
+        auctionDurationSeconds: new anchor.BN(0),
+        liquidationProtocolShareBps: 5000,
+        keeperReward: new anchor.BN(0),
+        keeperRewardMaxDebt: new anchor.BN(0),
+        liquidationBonusMinBps: 0,
+        liquidationBonusMaxBps: 0, // fixed penalty
+        maxPriceMoveBpsPerMinute: 10000, // records the accepted price
+        twapWindowSeconds: new anchor.BN(0), // provider EMA
+    });
+
+    const createVaultType = (stakePoolAccount: PublicKey) =>
+        program.methods
+            .createVaultType(LST_MINT, vaultTypeParams(stakePoolAccount))
+            .accounts({
+                vaultType: vaultTypePda,
+                collateralMintAccount: LST_MINT,
+                protocolState: protocolStatePda,
+                admin: wallet.publicKey,
+                systemProgram: SystemProgram.programId,
+            } as any)
+            .rpc();
+
+    const updateVaultType = (changes: Record<string, unknown>) =>
+        program.methods
+            .updateVaultType(changes as any)
+            .accounts({
+                vaultType: vaultTypePda,
+                protocolState: protocolStatePda,
+                admin: wallet.publicKey,
+            } as any)
+            .rpc();
+
+    const deposit = (remainingAccounts: PublicKey[]) =>
+        program.methods
+            .depositCollateral(new anchor.BN(1_000_000_000))
+            .accounts({
+                position,
+                vaultType: vaultTypePda,
+                protocolState: protocolStatePda,
+                userCollateralAccount,
+                vaultCollateralAccount,
+                vaultAuthority,
+                oraclePriceAccount: PYTH_SOL_USD,
+                owner: wallet.publicKey,
+                tokenProgram: TOKEN_PROGRAM_ID,
+            } as any)
+            .remainingAccounts(
+                remainingAccounts.map((pubkey) => ({ pubkey, isSigner: false, isWritable: false }))
+            )
+            .rpc();
+
+    before(async () => {
+        [protocolStatePda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("protocol_state")],
+            program.programId
+        );
+
+        try {
+            await program.methods
+                .initializeProtocol(wallet.publicKey)
+                .accounts({ admin: wallet.publicKey } as any)
+                .rpc();
+        } catch (e) {
+            // Already initialized by another suite
+        }
+
+        [vaultTypePda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("vault-type"), LST_MINT.toBuffer()],
+            program.programId
+        );
+        [vaultAuthority] = PublicKey.findProgramAddressSync(
+            [Buffer.from("vault_authority"), vaultTypePda.toBuffer()],
+            program.programId
+        );
+        [position] = PublicKey.findProgramAddressSync(
+            [Buffer.from("position"), wallet.publicKey.toBuffer(), vaultTypePda.toBuffer()],
+            program.programId
+        );
+    });
+
+    it("Requires a stake pool account for the stake pool source", async () => {
+        await expectError(createVaultType(PublicKey.default), "InvalidOracleConfig");
+    });
+
+    it("Creates an LST vault type", async () => {
+        await createVaultType(LST_STAKE_POOL);
+
+        await program.methods
+            .openPosition()
+            .accounts({
+                position,
+                vaultType: vaultTypePda,
+                owner: wallet.publicKey,
+                systemProgram: SystemProgram.programId,
+            } as any)
+            .rpc();
+
+        userCollateralAccount = (
+            await getOrCreateAssociatedTokenAccount(provider.connection, wallet.payer, LST_MINT, wallet.publicKey)
+        ).address;
+        vaultCollateralAccount = (
+            await getOrCreateAssociatedTokenAccount(provider.connection, wallet.payer, LST_MINT, vaultAuthority, true)
+        ).address;
+        await mintTo(
+            provider.connection,
+            wallet.payer,
+            LST_MINT,
+            userCollateralAccount,
+            LST_MINT_AUTHORITY,
+            10_000_000_000
+        );
+
+        const vault = await program.account.vaultType.fetch(vaultTypePda);
+        expect(vault.stakePoolAccount.toBase58()).to.equal(LST_STAKE_POOL.toBase58());
+    });
+
+    it("Prices the LST at the stake pool exchange rate times SOL/USD", async () => {
+        await program.methods
+            .refreshPrice()
+            .accounts({
+                vaultType: vaultTypePda,
+                protocolState: protocolStatePda,
+                oraclePriceAccount: PYTH_SOL_USD,
+            } as any)
+            .remainingAccounts([{ pubkey: LST_STAKE_POOL, isSigner: false, isWritable: false }])
+            .rpc();
+
+        // $150.00 * 1.1 SOL per LST
+        const vault = await program.account.vaultType.fetch(vaultTypePda);
+        expect(vault.lastAcceptedPrice.toNumber()).to.equal(165_000_000);
+
+        await deposit([LST_STAKE_POOL]);
+        const pos = await program.account.position.fetch(position);
+        expect(pos.collateralAmount.toNumber()).to.equal(1_000_000_000);
+    });
+
+    it("Requires the stake pool account", async () => {
+        await expectError(deposit([]), "InvalidOracleAccount");
+    });
+
+    it("Rejects a stake pool that was not updated this epoch", async () => {
+        await updateVaultType({ stakePoolAccount: LST_STAKE_POOL_OUTDATED });
+        await expectError(deposit([LST_STAKE_POOL_OUTDATED]), "OracleStale");
+
+        await updateVaultType({ stakePoolAccount: LST_STAKE_POOL });
+    });
+
+    it("Rejects a stale SOL/USD price", async () => {
+        await updateVaultType({ oracleMaxAgeSeconds: new anchor.BN(1) });
+        await expectError(deposit([LST_STAKE_POOL]), "OracleStale");
+
+        await updateVaultType({ oracleMaxAgeSeconds: TEN_YEARS });
+    });
+});
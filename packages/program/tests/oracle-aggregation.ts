@@ -0,0 +1,285 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { createMint, getOrCreateAssociatedTokenAccount, mintTo, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { PublicKey, SystemProgram } from "@solana/web3.js";
+import { expect } from "chai";
+import { AegisVault } from "../target/types/aegis_vault";
+
+// Oracle accounts are locally constructed fixtures loaded by the test validator (see Anchor.toml):
+// - pyth-sol-usd: Pyth PriceUpdateV2, SOL/USD $150.00 +/- 0.05, fully verified
+// - switchboard-sol-usd: Switchboard pull feed, $150.50 +/- 0.10
+// - switchboard-sol-usd-deviated: Switchboard pull feed, $160.00 +/- 0.10
+// All are published at 2025-01-01T00:00:00Z, so freshness is controlled through the vault's max ages.
+const PYTH_SOL_USD = new PublicKey("BYfEm3V688PTMDVWRSHTEdCtByQfvczWByAMC6cahnDs");
+const SWITCHBOARD_SOL_USD = new PublicKey("EGz5p3T33tZbJqsLjMNZHHpieaYo1rmSZ55LfaqSsUQu");
+const SWITCHBOARD_SOL_USD_DEVIATED = new PublicKey("Db3kQND3c19sCqHLAUucAWUgnPpZPWXbVACWQJXkzDjN");
+const SOL_USD_FEED_ID = "ef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d";
+
+const TEN_YEARS = new anchor.BN(10 * 365 * 24 * 60 * 60);
+
+const AGGREGATION_FALLBACK = 1;
+const AGGREGATION_MEAN = 2;
+
+async function expectError(promise: Promise<unknown>, code: string) {
+    try {
+        await promise;
+    } catch (e) {
+        expect((e as anchor.AnchorError).error?.errorCode?.code).to.equal(code);
+        return;
+    }
+    expect.fail(`expected ${code}`);
+}
+
+describe("oracle aggregation", () => {
+    const provider = anchor.AnchorProvider.env();
+    anchor.setProvider(provider);
+
+    const program = anchor.workspace.AegisVault as Program<AegisVault>;
+    const wallet = provider.wallet as anchor.Wallet;
+
+    let protocolStatePda: PublicKey;
+    let vaultTypePda: PublicKey;
+    let vaultAuthority: PublicKey;
+    let position: PublicKey;
+    let collateralMint: PublicKey;
+    let userCollateralAccount: PublicKey;
+    let vaultCollateralAccount: PublicKey;
+
+    const updateVaultType = (changes: Record<string, unknown>) =>
+        program.methods
+            .updateVaultType(changes as any)
+            .accounts({
+                vaultType: vaultTypePda,
+                protocolState: protocolStatePda,
+                admin: wallet.publicKey,
+            } as any)
+            .rpc();
+
+    const deposit = (remainingAccounts: PublicKey[]) =>
+        program.methods
+            .depositCollateral(new anchor.BN(1_000_000))
+            .accounts({
+                position,
+                vaultType: vaultTypePda,
+                protocolState: protocolStatePda,
+                userCollateralAccount,
+                vaultCollateralAccount,
+                vaultAuthority,
+                oraclePriceAccount: PYTH_SOL_USD,
+                owner: wallet.publicKey,
+                tokenProgram: TOKEN_PROGRAM_ID,
+            } as any)
+            .remainingAccounts(
+                remainingAccounts.map((pubkey) => ({ pubkey, isSigner: false, isWritable: false }))
+            )
+            .rpc({ commitment: "confirmed" });
+
+    const priceView = (remainingAccounts: PublicKey[]) =>
+        program.methods
+            .getPriceView()
+            .accounts({
+                vaultType: vaultTypePda,
+                protocolState: protocolStatePda,
+                oraclePriceAccount: PYTH_SOL_USD,
+            } as any)
+            .remainingAccounts(
+                remainingAccounts.map((pubkey) => ({ pubkey, isSigner: false, isWritable: false }))
+            )
+            .view();
+
+    const errorCode = (name: string) => program.idl.errors.find((e) => e.name === name)!.code;
+
+    before(async () => {
+        [protocolStatePda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("protocol_state")],
+            program.programId
+        );
+
+        try {
+            await program.methods
+                .initializeProtocol(wallet.publicKey)
+                .accounts({ admin: wallet.publicKey } as any)
+                .rpc();
+        } catch (e) {
+            // Already initialized by another suite
+        }
+
+        collateralMint = await createMint(provider.connection, wallet.payer, wallet.publicKey, null, 9);
+        [vaultTypePda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("vault-type"), collateralMint.toBuffer()],
+            program.programId
+        );
+        [vaultAuthority] = PublicKey.findProgramAddressSync(
+            [Buffer.from("vault_authority"), vaultTypePda.toBuffer()],
+            program.programId
+        );
+        [position] = PublicKey.findProgramAddressSync(
+            [Buffer.from("position"), wallet.publicKey.toBuffer(), vaultTypePda.toBuffer()],
+            program.programId
+        );
+
+        await program.methods
+            .createVaultType(collateralMint, {
+                collateralDecimals: 9,
+                oracleSource: 0, // Pyth
+                oraclePriceAccount: PYTH_SOL_USD,
+                oracleFeedId: Array.from(Buffer.from(SOL_USD_FEED_ID, "hex")),
+                oracleMaxAgeSeconds: TEN_YEARS,
+                oraclePriceExponent: -8,
+                oracleVerificationLevel: 0, // Full
+                oracleMinSignatures: 0,
+                confMultiplierBps: 10000,
+                maxConfRatioBps: 200, // 2%
+                pricingMode: 0, // spot
+                stakePoolAccount: PublicKey.default,
+                isPegged: false,
+                minPrice: new anchor.BN(0),
+                maxPrice: new anchor.BN(0),
+                secondaryOracleAccount: SWITCHBOARD_SOL_USD,
+                secondaryOracleMaxAgeSeconds: TEN_YEARS,
+                oracleAggregation: AGGREGATION_FALLBACK,
+                maxOracleDeviationBps: 200, // 2%
+                ltvBps: new anchor.BN(15000),
+                liqThresholdBps: new anchor.BN(12000),
+                liqPenaltyBps: new anchor.BN(500),
+                stabilityFeeBps: 0,
+                mintFeeBps: 0,
+                redeemFeeBps: 0,
+                vaultDebtCeiling: new anchor.BN(1_000_000_000),
+                closeFactorBps: 10000, // full liquidation
+                targetHealthBps: 0,
+                fullLiquidationHealthBps: 0,
+                liquidationDustThreshold: new anchor.BN(0),
+                liquidationMode: 0, // fixed discount
+                auctionStartPremiumBps: 0,
+                auctionFloorBps: 0,
+                auctionDurationSeconds: new anchor.BN(0),
+                liquidationProtocolShareBps: 5000,
+                keeperReward: new anchor.BN(0),
+                keeperRewardMaxDebt: new anchor.BN(0),
+                liquidationBonusMinBps: 0,
+                liquidationBonusMaxBps: 0, // fixed penalty
+                maxPriceMoveBpsPerMinute: 0, // circuit breaker disabled
+                twapWindowSeconds: new anchor.BN(0), // provider EMA
+            })
+            .accounts({
+                vaultType: vaultTypePda,
+                collateralMintAccount: collateralMint,
+                protocolState: protocolStatePda,
+                admin: wallet.publicKey,
+                systemProgram: SystemProgram.programId,
+            } as any)
+            .rpc();
+
+        await program.methods
+            .openPosition()
+            .accounts({
+                position,
+                vaultType: vaultTypePda,
+                owner: wallet.publicKey,
+                systemProgram: SystemProgram.programId,
+            } as any)
+            .rpc();
+
+        userCollateralAccount = (
+            await getOrCreateAssociatedTokenAccount(provider.connection, wallet.payer, collateralMint, wallet.publicKey)
+        ).address;
+        vaultCollateralAccount = (
+            await getOrCreateAssociatedTokenAccount(provider.connection, wallet.payer, collateralMint, vaultAuthority, true)
+        ).address;
+        await mintTo(provider.connection, wallet.payer, collateralMint, userCollateralAccount, wallet.publicKey, 1_000_000_000);
+    });
+
+    it("Stores the secondary oracle configuration", async () => {
+        const vault = await program.account.vaultType.fetch(vaultTypePda);
+        expect(vault.secondaryOracleAccount.toBase58()).to.equal(SWITCHBOARD_SOL_USD.toBase58());
+        expect(vault.oracleAggregation).to.equal(AGGREGATION_FALLBACK);
+        expect(vault.maxOracleDeviationBps).to.equal(200);
+    });
+
+    it("Rejects fallback aggregation without a secondary oracle", async () => {
+        await expectError(
+            updateVaultType({ secondaryOracleAccount: PublicKey.default }),
+            "InvalidOracleConfig"
+        );
+    });
+
+    it("Uses the primary price when both sources agree", async () => {
+        await deposit([SWITCHBOARD_SOL_USD]);
+
+        const pos = await program.account.position.fetch(position);
+        expect(pos.collateralAmount.toNumber()).to.equal(1_000_000);
+    });
+
+    it("Reports the price view", async () => {
+        const view = await priceView([SWITCHBOARD_SOL_USD]);
+        expect(view.price.toNumber()).to.equal(150_000_000);
+        expect(view.exponent).to.equal(-6);
+        expect(view.source).to.equal(0); // Pyth
+        expect(view.isValid).to.be.true;
+        expect(view.errorCode).to.equal(0);
+    });
+
+    it("Uses the primary price when the secondary is not passed", async () => {
+        await deposit([]);
+    });
+
+    it("Rejects sources that deviate by more than the configured bps", async () => {
+        await updateVaultType({ secondaryOracleAccount: SWITCHBOARD_SOL_USD_DEVIATED });
+        await expectError(deposit([SWITCHBOARD_SOL_USD_DEVIATED]), "OracleDeviationTooHigh");
+
+        // A wider band accepts the same pair
+        await updateVaultType({ maxOracleDeviationBps: 1000 });
+        await deposit([SWITCHBOARD_SOL_USD_DEVIATED]);
+
+        await updateVaultType({ secondaryOracleAccount: SWITCHBOARD_SOL_USD, maxOracleDeviationBps: 200 });
+    });
+
+    it("Ignores feeds other than the configured secondary", async () => {
+        await updateVaultType({ oracleMaxAgeSeconds: new anchor.BN(1) });
+        await expectError(deposit([SWITCHBOARD_SOL_USD_DEVIATED]), "OracleStale");
+
+        // The view still shows the stale price, flagged with the reason
+        const view = await priceView([]);
+        expect(view.price.toNumber()).to.equal(150_000_000);
+        expect(view.stalenessSeconds.toNumber()).to.be.greaterThan(1);
+        expect(view.isValid).to.be.false;
+        expect(view.errorCode).to.equal(errorCode("OracleStale"));
+    });
+
+    it("Falls back to the secondary when the primary is stale", async () => {
+        const signature = await deposit([SWITCHBOARD_SOL_USD]);
+
+        const tx = await provider.connection.getTransaction(signature, {
+            commitment: "confirmed",
+            maxSupportedTransactionVersion: 0,
+        });
+        expect(tx?.meta?.logMessages?.some((log) => log.includes("OracleFallback"))).to.be.true;
+    });
+
+    it("Fails when both sources are stale", async () => {
+        await updateVaultType({ secondaryOracleMaxAgeSeconds: new anchor.BN(1) });
+        await expectError(deposit([SWITCHBOARD_SOL_USD]), "OracleStale");
+
+        await updateVaultType({ secondaryOracleMaxAgeSeconds: TEN_YEARS });
+    });
+
+    it("Requires both sources in mean mode", async () => {
+        await updateVaultType({ oracleAggregation: AGGREGATION_MEAN });
+
+        // Primary is still stale
+        await expectError(deposit([SWITCHBOARD_SOL_USD]), "OracleStale");
+
+        await updateVaultType({ oracleMaxAgeSeconds: TEN_YEARS });
+        await expectError(deposit([]), "InvalidOracleAccount");
+        await deposit([SWITCHBOARD_SOL_USD]);
+    });
+
+    it("Rejects a price update for another feed", async () => {
+        const otherFeedId = Array.from(Buffer.alloc(32, 7));
+        await updateVaultType({ oracleFeedId: otherFeedId });
+        await expectError(deposit([SWITCHBOARD_SOL_USD]), "OracleFeedMismatch");
+
+        await updateVaultType({ oracleFeedId: Array.from(Buffer.from(SOL_USD_FEED_ID, "hex")) });
+    });
+});
@@ -0,0 +1,428 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { createMint } from "@solana/spl-token";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import { expect } from "chai";
+import { AegisVault } from "../target/types/aegis_vault";
+
+async function expectError(promise: Promise<unknown>, code: string) {
+    try {
+        await promise;
+    } catch (e) {
+        expect((e as anchor.AnchorError).error?.errorCode?.code).to.equal(code);
+        return;
+    }
+    expect.fail(`expected ${code}`);
+}
+
+describe("push price feed", () => {
+    const provider = anchor.AnchorProvider.env();
+    anchor.setProvider(provider);
+
+    const program = anchor.workspace.AegisVault as Program<AegisVault>;
+    const wallet = provider.wallet as anchor.Wallet;
+
+    let protocolStatePda: PublicKey;
+    let collateralMint: PublicKey;
+    let vaultTypePda: PublicKey;
+    let priceFeedPda: PublicKey;
+    let publishTime: number;
+
+    const vaultTypeParams = (oraclePriceAccount: PublicKey) => ({
+        collateralDecimals: 9,
+        oracleSource: 1, // push feed
+        oraclePriceAccount,
+        oracleFeedId: new Array(32).fill(0),
+        oracleMaxAgeSeconds: new anchor.BN(0),
+        oraclePriceExponent: 0,
+        oracleVerificationLevel: 0,
+        oracleMinSignatures: 0,
+        confMultiplierBps: 10000,
+        maxConfRatioBps: 0,
+        pricingMode: 0, // spot
+        stakePoolAccount: PublicKey.default,
+        isPegged: false,
+        minPrice: new anchor.BN(0),
+        maxPrice: new anchor.BN(0),
+        secondaryOracleAccount: PublicKey.default,
+        secondaryOracleMaxAgeSeconds: new anchor.BN(0),
+        oracleAggregation: 0,
+        maxOracleDeviationBps: 0,
+        ltvBps: new anchor.BN(5000),
+        liqThresholdBps: new anchor.BN(8000),
+        liqPenaltyBps: new anchor.BN(500),
+        stabilityFeeBps: 0,
+        mintFeeBps: 0,
+        redeemFeeBps: 0,
+        vaultDebtCeiling: new anchor.BN(1_000_000_000),
+        closeFactorBps: 10000, // full liquidation
+        targetHealthBps: 0,
+        fullLiquidationHealthBps: 0,
+        liquidationDustThreshold: new anchor.BN(0),
+        liquidationMode: 0, // fixed discount
+        auctionStartPremiumBps: 0,
+        auctionFloorBps: 0,
+        auctionDurationSeconds: new anchor.BN(0),
+        liquidationProtocolShareBps: 5000,
+        keeperReward: new anchor.BN(0),
+        keeperRewardMaxDebt: new anchor.BN(0),
+        liquidationBonusMinBps: 0,
+        liquidationBonusMaxBps: 0, // fixed penalty
+        maxPriceMoveBpsPerMinute: 0, // circuit breaker disabled
+        twapWindowSeconds: new anchor.BN(0), // provider EMA
+    });
+
+    const pushPrice = (price: number, conf: number, time: number, authority?: Keypair) =>
+        program.methods
+            .pushPrice(new anchor.BN(price), new anchor.BN(conf), new anchor.BN(time))
+            .accounts({
+                priceFeed: priceFeedPda,
+                protocolState: protocolStatePda,
+                oracleUpdateAuthority: authority ? authority.publicKey : wallet.publicKey,
+            } as any)
+            .signers(authority ? [authority] : [])
+            .rpc();
+
+    before(async () => {
+        [protocolStatePda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("protocol_state")],
+            program.programId
+        );
+
+        try {
+            await program.methods
+                .initializeProtocol(wallet.publicKey)
+                .accounts({ admin: wallet.publicKey } as any)
+                .rpc();
+        } catch (e) {
+            // Already initialized by another suite
+        }
+
+        collateralMint = await createMint(provider.connection, wallet.payer, wallet.publicKey, null, 9);
+        [vaultTypePda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("vault-type"), collateralMint.toBuffer()],
+            program.programId
+        );
+        [priceFeedPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("price-feed"), vaultTypePda.toBuffer()],
+            program.programId
+        );
+
+        publishTime = (await provider.connection.getBlockTime(await provider.connection.getSlot()))! - 60;
+    });
+
+    it("Rejects a push feed vault type that does not use its own feed", async () => {
+        await expectError(
+            program.methods
+                .createVaultType(collateralMint, vaultTypeParams(Keypair.generate().publicKey))
+                .accounts({
+                    vaultType: vaultTypePda,
+                    collateralMintAccount: collateralMint,
+                    protocolState: protocolStatePda,
+                    admin: wallet.publicKey,
+                    systemProgram: SystemProgram.programId,
+                } as any)
+                .rpc(),
+            "InvalidOracleConfig"
+        );
+    });
+
+    it("Creates a push feed vault type and its feed", async () => {
+        await program.methods
+            .createVaultType(collateralMint, vaultTypeParams(priceFeedPda))
+            .accounts({
+                vaultType: vaultTypePda,
+                collateralMintAccount: collateralMint,
+                protocolState: protocolStatePda,
+                admin: wallet.publicKey,
+                systemProgram: SystemProgram.programId,
+            } as any)
+            .rpc();
+
+        await program.methods
+            .initializePriceFeed(1000) // 10%
+            .accounts({
+                priceFeed: priceFeedPda,
+                vaultType: vaultTypePda,
+                protocolState: protocolStatePda,
+                admin: wallet.publicKey,
+                systemProgram: SystemProgram.programId,
+            } as any)
+            .rpc();
+
+        const feed = await program.account.priceFeed.fetch(priceFeedPda);
+        expect(feed.vaultType.toBase58()).to.equal(vaultTypePda.toBase58());
+        expect(feed.price.toNumber()).to.equal(0);
+        expect(feed.maxMoveBps).to.equal(1000);
+    });
+
+    it("Only accepts prices from the oracle update authority", async () => {
+        await expectError(pushPrice(2_000_000, 1_000, publishTime, Keypair.generate()), "Unauthorized");
+    });
+
+    it("Accepts the first price without a move bound", async () => {
+        await pushPrice(2_000_000, 1_000, publishTime); // $2.00
+
+        const feed = await program.account.priceFeed.fetch(priceFeedPda);
+        expect(feed.price.toNumber()).to.equal(2_000_000);
+        expect(feed.conf.toNumber()).to.equal(1_000);
+        expect(feed.publishTime.toNumber()).to.equal(publishTime);
+    });
+
+    it("Rejects prices that are not newer than the current one", async () => {
+        await expectError(pushPrice(2_010_000, 1_000, publishTime), "InvalidPublishTime");
+    });
+
+    it("Rejects moves beyond the max move bound", async () => {
+        await expectError(pushPrice(2_300_000, 1_000, publishTime + 10), "PriceMoveTooLarge"); // +15%
+        await expectError(pushPrice(1_700_000, 1_000, publishTime + 10), "PriceMoveTooLarge"); // -15%
+    });
+
+    it("Accepts moves within the max move bound", async () => {
+        await pushPrice(2_200_000, 1_000, publishTime + 10); // +10%
+
+        const feed = await program.account.priceFeed.fetch(priceFeedPda);
+        expect(feed.price.toNumber()).to.equal(2_200_000);
+    });
+
+    it("Lets the admin widen the max move bound", async () => {
+        await program.methods
+            .setPriceFeedMaxMove(5000)
+            .accounts({
+                priceFeed: priceFeedPda,
+                protocolState: protocolStatePda,
+                admin: wallet.publicKey,
+            } as any)
+            .rpc();
+
+        await pushPrice(3_000_000, 1_000, publishTime + 20); // +36%
+    });
+
+    it("Trips the circuit breaker on abnormal moves until the guardian resets it", async () => {
+        const refreshPrice = () =>
+            program.methods
+                .refreshPrice()
+                .accounts({
+                    vaultType: vaultTypePda,
+                    protocolState: protocolStatePda,
+                    oraclePriceAccount: priceFeedPda,
+                } as any)
+                .rpc();
+
+        await program.methods
+            .updateVaultType({ maxPriceMoveBpsPerMinute: 100 } as any) // 1% per minute
+            .accounts({
+                vaultType: vaultTypePda,
+                protocolState: protocolStatePda,
+                admin: wallet.publicKey,
+            } as any)
+            .rpc();
+
+        // First refresh sets the baseline at $3.00
+        await refreshPrice();
+        let vault = await program.account.vaultType.fetch(vaultTypePda);
+        expect(vault.lastAcceptedPrice.toNumber()).to.equal(3_000_000);
+
+        // +10% within a minute trips the breaker and pauses minting
+        await pushPrice(3_300_000, 1_000, publishTime + 30);
+        await refreshPrice();
+        vault = await program.account.vaultType.fetch(vaultTypePda);
+        expect(vault.isMintPaused).to.be.true;
+        expect(vault.circuitBreakerTripPrice.toNumber()).to.equal(3_300_000);
+        expect(vault.lastAcceptedPrice.toNumber()).to.equal(3_000_000);
+
+        const intruder = Keypair.generate();
+        await expectError(
+            program.methods
+                .resetCircuitBreaker()
+                .accounts({
+                    vaultType: vaultTypePda,
+                    protocolState: protocolStatePda,
+                    guardianPubkey: intruder.publicKey,
+                } as any)
+                .signers([intruder])
+                .rpc(),
+            "Unauthorized"
+        );
+
+        // The guardian (admin by default) clears it and the next price becomes the baseline
+        await program.methods
+            .resetCircuitBreaker()
+            .accounts({
+                vaultType: vaultTypePda,
+                protocolState: protocolStatePda,
+                guardianPubkey: wallet.publicKey,
+            } as any)
+            .rpc();
+        // The last good price stays as the guardian override reference
+        vault = await program.account.vaultType.fetch(vaultTypePda);
+        expect(vault.lastAcceptedPrice.toNumber()).to.equal(3_000_000);
+        await refreshPrice();
+        vault = await program.account.vaultType.fetch(vaultTypePda);
+        expect(vault.isMintPaused).to.be.false;
+        expect(vault.lastAcceptedPrice.toNumber()).to.equal(3_300_000);
+    });
+
+    it("Records accepted prices and requires the history to cover the TWAP window", async () => {
+        const [priceHistoryPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("price-history"), vaultTypePda.toBuffer()],
+            program.programId
+        );
+        const errorCode = (name: string) => program.idl.errors.find((e) => e.name === name)!.code;
+        const priceView = () =>
+            program.methods
+                .getPriceView()
+                .accounts({
+                    vaultType: vaultTypePda,
+                    protocolState: protocolStatePda,
+                    oraclePriceAccount: priceFeedPda,
+                } as any)
+                .remainingAccounts([{ pubkey: priceHistoryPda, isSigner: false, isWritable: false }])
+                .view();
+        const recordPrice = () =>
+            program.methods
+                .recordPrice()
+                .accounts({
+                    priceHistory: priceHistoryPda,
+                    vaultType: vaultTypePda,
+                    protocolState: protocolStatePda,
+                    oraclePriceAccount: priceFeedPda,
+                } as any)
+                .rpc();
+
+        await program.methods
+            .updateVaultType({ maxPriceMoveBpsPerMinute: 0 } as any)
+            .accounts({
+                vaultType: vaultTypePda,
+                protocolState: protocolStatePda,
+                admin: wallet.publicKey,
+            } as any)
+            .rpc();
+
+        const initializePriceHistory = (minIntervalSeconds: number) =>
+            program.methods
+                .initializePriceHistory(minIntervalSeconds)
+                .accounts({
+                    priceHistory: priceHistoryPda,
+                    vaultType: vaultTypePda,
+                    protocolState: protocolStatePda,
+                    admin: wallet.publicKey,
+                    systemProgram: SystemProgram.programId,
+                } as any)
+                .rpc();
+        const setTwapWindow = (seconds: number) =>
+            program.methods
+                .updateVaultType({ twapWindowSeconds: new anchor.BN(seconds) } as any)
+                .accounts({
+                    vaultType: vaultTypePda,
+                    protocolState: protocolStatePda,
+                    admin: wallet.publicKey,
+                } as any)
+                .remainingAccounts([{ pubkey: priceHistoryPda, isSigner: false, isWritable: false }])
+                .rpc();
+
+        // The TWAP can't be enabled before a history is attached
+        await expectError(setTwapWindow(2), "PriceHistoryInsufficient");
+        await expectError(initializePriceHistory(0), "InvalidOracleConfig");
+        await initializePriceHistory(60);
+        // 48 observations 60 seconds apart reach back 47 minutes at most
+        await expectError(setTwapWindow(47 * 60 + 1), "InvalidOracleConfig");
+        await setTwapWindow(47 * 60);
+        await setTwapWindow(2);
+        const vault = await program.account.vaultType.fetch(vaultTypePda);
+        expect(vault.priceHistory.toBase58()).to.equal(priceHistoryPda.toBase58());
+
+        await recordPrice();
+        const history = await program.account.priceHistory.fetch(priceHistoryPda);
+        expect(history.len).to.equal(1);
+        expect(history.observations[0].price.toNumber()).to.equal(3_300_000);
+
+        await expectError(recordPrice(), "PriceObservationTooSoon");
+
+        // The single observation does not reach back 2 seconds yet
+        let view = await priceView();
+        expect(view.errorCode).to.equal(errorCode("PriceHistoryInsufficient"));
+
+        await new Promise((resolve) => setTimeout(resolve, 3000));
+        view = await priceView();
+        expect(view.isValid).to.be.true;
+    });
+
+    it("Lets the guardian override the price until it expires", async () => {
+        const [priceOverrideLogPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("price-override-log"), vaultTypePda.toBuffer()],
+            program.programId
+        );
+        const [priceHistoryPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("price-history"), vaultTypePda.toBuffer()],
+            program.programId
+        );
+        const priceView = () =>
+            program.methods
+                .getPriceView()
+                .accounts({
+                    vaultType: vaultTypePda,
+                    protocolState: protocolStatePda,
+                    oraclePriceAccount: priceFeedPda,
+                } as any)
+                .remainingAccounts([{ pubkey: priceHistoryPda, isSigner: false, isWritable: false }])
+                .view();
+        const setPriceOverride = (price: number, duration: number, guardian?: Keypair) =>
+            program.methods
+                .setPriceOverride(new anchor.BN(price), new anchor.BN(duration))
+                .accounts({
+                    vaultType: vaultTypePda,
+                    priceOverrideLog: priceOverrideLogPda,
+                    protocolState: protocolStatePda,
+                    guardianPubkey: guardian ? guardian.publicKey : wallet.publicKey,
+                } as any)
+                .signers(guardian ? [guardian] : [])
+                .rpc();
+
+        await program.methods
+            .initializePriceOverrideLog()
+            .accounts({
+                priceOverrideLog: priceOverrideLogPda,
+                vaultType: vaultTypePda,
+                protocolState: protocolStatePda,
+                admin: wallet.publicKey,
+                systemProgram: SystemProgram.programId,
+            } as any)
+            .rpc();
+
+        // Bounded by 20% around the last good price of $3.30
+        await expectError(setPriceOverride(5_000_000, 2), "PriceOverrideOutOfBounds");
+        await expectError(setPriceOverride(3_000_000, 0), "InvalidOverrideDuration");
+        await expectError(setPriceOverride(3_000_000, 7 * 60 * 60), "InvalidOverrideDuration");
+        await expectError(setPriceOverride(3_000_000, 2, Keypair.generate()), "Unauthorized");
+
+        await setPriceOverride(3_000_000, 2);
+        let view = await priceView();
+        expect(view.price.toNumber()).to.equal(3_000_000);
+        expect(view.source).to.equal(5); // guardian override
+        expect(view.isValid).to.be.true;
+
+        const log = await program.account.priceOverrideLog.fetch(priceOverrideLogPda);
+        expect(log.len).to.equal(1);
+        expect(log.entries[0].price.toNumber()).to.equal(3_000_000);
+        expect(log.entries[0].referencePrice.toNumber()).to.equal(3_300_000);
+        expect(log.entries[0].guardian.toBase58()).to.equal(wallet.publicKey.toBase58());
+
+        // Expired: back to the push feed
+        await new Promise((resolve) => setTimeout(resolve, 3000));
+        view = await priceView();
+        expect(view.price.toNumber()).to.equal(3_300_000);
+        expect(view.source).to.equal(1); // push feed
+
+        await program.methods
+            .clearPriceOverride()
+            .accounts({
+                vaultType: vaultTypePda,
+                priceOverrideLog: priceOverrideLogPda,
+                protocolState: protocolStatePda,
+                guardianPubkey: wallet.publicKey,
+            } as any)
+            .rpc();
+        expect((await program.account.priceOverrideLog.fetch(priceOverrideLogPda)).len).to.equal(2);
+    });
+});
@@ -0,0 +1,370 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { createMint, getOrCreateAssociatedTokenAccount, mintTo, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import {
+    Ed25519Program,
+    Keypair,
+    PublicKey,
+    SystemProgram,
+    SYSVAR_INSTRUCTIONS_PUBKEY,
+    TransactionInstruction,
+} from "@solana/web3.js";
+import { expect } from "chai";
+import { AegisVault } from "../target/types/aegis_vault";
+
+const ORACLE_SOURCE_SIGNED_ATTESTATION = 4;
+const FEED_ID = Buffer.alloc(32, 42);
+
+async function expectError(promise: Promise<unknown>, code: string) {
+    try {
+        await promise;
+    } catch (e) {
+        expect((e as anchor.AnchorError).error?.errorCode?.code).to.equal(code);
+        return;
+    }
+    expect.fail(`expected ${code}`);
+}
+
+// prefix || feed_id || price u64 || conf u64 || timestamp i64, little endian, prices in 6 decimals
+function attestation(signer: Keypair, price: number, conf: number, timestamp: number, feedId = FEED_ID) {
+    const message = Buffer.concat([
+        Buffer.from("aegis-px"),
+        feedId,
+        new anchor.BN(price).toArrayLike(Buffer, "le", 8),
+        new anchor.BN(conf).toArrayLike(Buffer, "le", 8),
+        new anchor.BN(timestamp).toTwos(64).toArrayLike(Buffer, "le", 8),
+    ]);
+    return Ed25519Program.createInstructionWithPrivateKey({ privateKey: signer.secretKey, message });
+}
+
+describe("signed price attestations", () => {
+    const provider = anchor.AnchorProvider.env();
+    anchor.setProvider(provider);
+
+    const program = anchor.workspace.AegisVault as Program<AegisVault>;
+    const wallet = provider.wallet as anchor.Wallet;
+
+    const signers = [Keypair.generate(), Keypair.generate(), Keypair.generate()];
+
+    let protocolStatePda: PublicKey;
+    let collateralMint: PublicKey;
+    let vaultTypePda: PublicKey;
+    let attestationConfigPda: PublicKey;
+    let vaultAuthority: PublicKey;
+    let position: PublicKey;
+    let userCollateralAccount: PublicKey;
+    let vaultCollateralAccount: PublicKey;
+    let now: number;
+
+    const initializeAttestationConfig = (keys: PublicKey[], threshold: number) =>
+        program.methods
+            .initializeAttestationConfig(keys, threshold)
+            .accounts({
+                attestationConfig: attestationConfigPda,
+                vaultType: vaultTypePda,
+                protocolState: protocolStatePda,
+                admin: wallet.publicKey,
+                systemProgram: SystemProgram.programId,
+            } as any)
+            .rpc();
+
+    // The config records the attestation consumed from each signer
+    const oracleAccounts = () => [
+        { pubkey: SYSVAR_INSTRUCTIONS_PUBKEY, isSigner: false, isWritable: false },
+        { pubkey: attestationConfigPda, isSigner: false, isWritable: true },
+    ];
+
+    const deposit = (attestations: TransactionInstruction[]) =>
+        program.methods
+            .depositCollateral(new anchor.BN(1_000_000))
+            .accounts({
+                position,
+                vaultType: vaultTypePda,
+                protocolState: protocolStatePda,
+                userCollateralAccount,
+                vaultCollateralAccount,
+                vaultAuthority,
+                oraclePriceAccount: attestationConfigPda,
+                owner: wallet.publicKey,
+                tokenProgram: TOKEN_PROGRAM_ID,
+            } as any)
+            .remainingAccounts(oracleAccounts())
+            .preInstructions(attestations)
+            .rpc();
+
+    before(async () => {
+        [protocolStatePda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("protocol_state")],
+            program.programId
+        );
+
+        try {
+            await program.methods
+                .initializeProtocol(wallet.publicKey)
+                .accounts({ admin: wallet.publicKey } as any)
+                .rpc();
+        } catch (e) {
+            // Already initialized by another suite
+        }
+
+        collateralMint = await createMint(provider.connection, wallet.payer, wallet.publicKey, null, 9);
+        [vaultTypePda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("vault-type"), collateralMint.toBuffer()],
+            program.programId
+        );
+        [attestationConfigPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("attestation-config"), vaultTypePda.toBuffer()],
+            program.programId
+        );
+        [vaultAuthority] = PublicKey.findProgramAddressSync(
+            [Buffer.from("vault_authority"), vaultTypePda.toBuffer()],
+            program.programId
+        );
+        [position] = PublicKey.findProgramAddressSync(
+            [Buffer.from("position"), wallet.publicKey.toBuffer(), vaultTypePda.toBuffer()],
+            program.programId
+        );
+
+        await program.methods
+            .createVaultType(collateralMint, {
+                collateralDecimals: 9,
+                oracleSource: ORACLE_SOURCE_SIGNED_ATTESTATION,
+                oraclePriceAccount: attestationConfigPda,
+                oracleFeedId: Array.from(FEED_ID),
+                oracleMaxAgeSeconds: new anchor.BN(60),
+                oraclePriceExponent: 0,
+                oracleVerificationLevel: 0,
+                oracleMinSignatures: 0,
+                confMultiplierBps: 10000,
+                maxConfRatioBps: 200, // 2%
+                pricingMode: 0, // spot
+                stakePoolAccount: PublicKey.default,
+                isPegged: false,
+                minPrice: new anchor.BN(0),
+                maxPrice: new anchor.BN(0),
+                secondaryOracleAccount: PublicKey.default,
+                secondaryOracleMaxAgeSeconds: new anchor.BN(0),
+                oracleAggregation: 0, // primary only
+                maxOracleDeviationBps: 0,
+                ltvBps: new anchor.BN(5000),
+                liqThresholdBps: new anchor.BN(8000),
+                liqPenaltyBps: new anchor.BN(500),
+                stabilityFeeBps: 0,
+                mintFeeBps: 0,
+                redeemFeeBps: 0,
+                vaultDebtCeiling: new anchor.BN(1_000_000_000),
+                closeFactorBps: 10000, // full liquidation
+                targetHealthBps: 0,
+                fullLiquidationHealthBps: 0,
+                liquidationDustThreshold: new anchor.BN(0),
+                liquidationMode: 0, // fixed discount
+                auctionStartPremiumBps: 0,
+                auctionFloorBps: 0,
+                auctionDurationSeconds: new anchor.BN(0),
+                liquidationProtocolShareBps: 5000,
+                keeperReward: new anchor.BN(0),
+                keeperRewardMaxDebt: new anchor.BN(0),
+                liquidationBonusMinBps: 0,
+                liquidationBonusMaxBps: 0, // fixed penalty
+                maxPriceMoveBpsPerMinute: 0, // circuit breaker disabled
+                twapWindowSeconds: new anchor.BN(0), // provider EMA
+            })
+            .accounts({
+                vaultType: vaultTypePda,
+                collateralMintAccount: collateralMint,
+                protocolState: protocolStatePda,
+                admin: wallet.publicKey,
+                systemProgram: SystemProgram.programId,
+            } as any)
+            .rpc();
+
+        await program.methods
+            .openPosition()
+            .accounts({
+                position,
+                vaultType: vaultTypePda,
+                owner: wallet.publicKey,
+                systemProgram: SystemProgram.programId,
+            } as any)
+            .rpc();
+
+        userCollateralAccount = (
+            await getOrCreateAssociatedTokenAccount(provider.connection, wallet.payer, collateralMint, wallet.publicKey)
+        ).address;
+        vaultCollateralAccount = (
+            await getOrCreateAssociatedTokenAccount(provider.connection, wallet.payer, collateralMint, vaultAuthority, true)
+        ).address;
+        await mintTo(provider.connection, wallet.payer, collateralMint, userCollateralAccount, wallet.publicKey, 1_000_000_000);
+
+        now = (await provider.connection.getBlockTime(await provider.connection.getSlot()))!;
+    });
+
+    it("Rejects invalid signer sets", async () => {
+        const keys = signers.map((signer) => signer.publicKey);
+        await expectError(initializeAttestationConfig(keys, 0), "InvalidAttestationSigners");
+        await expectError(initializeAttestationConfig(keys, 4), "InvalidAttestationSigners");
+        await expectError(initializeAttestationConfig([keys[0], keys[0]], 1), "InvalidAttestationSigners");
+    });
+
+    it("Configures a 2-of-3 signer set", async () => {
+        await initializeAttestationConfig(
+            signers.map((signer) => signer.publicKey),
+            2
+        );
+
+        const config = await program.account.attestationConfig.fetch(attestationConfigPda);
+        expect(config.signerCount).to.equal(3);
+        expect(config.threshold).to.equal(2);
+        expect(config.vaultType.toBase58()).to.equal(vaultTypePda.toBase58());
+    });
+
+    it("Prices at the median of the attestations verified in the transaction", async () => {
+        const attestations = [
+            attestation(signers[0], 2_000_000, 1_000, now),
+            attestation(signers[1], 2_010_000, 1_000, now),
+            attestation(signers[2], 1_990_000, 2_000, now - 5),
+        ];
+
+        const view = await program.methods
+            .getPriceView()
+            .accounts({
+                vaultType: vaultTypePda,
+                protocolState: protocolStatePda,
+                oraclePriceAccount: attestationConfigPda,
+            } as any)
+            .remainingAccounts(oracleAccounts())
+            .preInstructions(attestations)
+            .view();
+        expect(view.price.toNumber()).to.equal(2_000_000);
+        expect(view.conf.toNumber()).to.equal(2_000);
+        expect(view.publishTime.toNumber()).to.equal(now - 5);
+        expect(view.source).to.equal(ORACLE_SOURCE_SIGNED_ATTESTATION);
+        expect(view.isValid).to.be.true;
+
+        await deposit(attestations);
+        const pos = await program.account.position.fetch(position);
+        expect(pos.collateralAmount.toNumber()).to.equal(1_000_000);
+    });
+
+    it("Rejects an attestation older than the last one consumed from its signer", async () => {
+        let config = await program.account.attestationConfig.fetch(attestationConfigPda);
+        expect(config.lastAttestationTs.slice(0, 3).map((ts) => ts.toNumber())).to.deep.equal([now, now, now - 5]);
+
+        // Both signers were consumed at `now`; their earlier attestations are still fresh
+        await expectError(
+            deposit([
+                attestation(signers[0], 1_900_000, 1_000, now - 10),
+                attestation(signers[1], 1_900_000, 1_000, now - 10),
+            ]),
+            "AttestationReplayed"
+        );
+
+        // The last consumed timestamp itself is accepted
+        await deposit([
+            attestation(signers[0], 2_000_000, 1_000, now),
+            attestation(signers[2], 2_000_000, 1_000, now - 5),
+        ]);
+        config = await program.account.attestationConfig.fetch(attestationConfigPda);
+        expect(config.lastAttestationTs.slice(0, 3).map((ts) => ts.toNumber())).to.deep.equal([now, now, now - 5]);
+    });
+
+    it("Requires the threshold of distinct configured signers", async () => {
+        await expectError(
+            deposit([attestation(signers[0], 2_000_000, 1_000, now)]),
+            "AttestationThresholdNotMet"
+        );
+
+        // The same signer twice counts once
+        await expectError(
+            deposit([
+                attestation(signers[0], 2_000_000, 1_000, now),
+                attestation(signers[0], 2_000_000, 1_000, now - 1),
+            ]),
+            "AttestationThresholdNotMet"
+        );
+
+        // Unknown signers don't count
+        await expectError(
+            deposit([
+                attestation(signers[0], 2_000_000, 1_000, now),
+                attestation(Keypair.generate(), 2_000_000, 1_000, now),
+            ]),
+            "AttestationThresholdNotMet"
+        );
+    });
+
+    it("Ignores attestations for another feed", async () => {
+        await expectError(
+            deposit([
+                attestation(signers[0], 2_000_000, 1_000, now),
+                attestation(signers[1], 2_000_000, 1_000, now, Buffer.alloc(32, 7)),
+            ]),
+            "AttestationThresholdNotMet"
+        );
+    });
+
+    it("Requires the instructions sysvar", async () => {
+        await expectError(
+            program.methods
+                .depositCollateral(new anchor.BN(1_000_000))
+                .accounts({
+                    position,
+                    vaultType: vaultTypePda,
+                    protocolState: protocolStatePda,
+                    userCollateralAccount,
+                    vaultCollateralAccount,
+                    vaultAuthority,
+                    oraclePriceAccount: attestationConfigPda,
+                    owner: wallet.publicKey,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                } as any)
+                .preInstructions([
+                    attestation(signers[0], 2_000_000, 1_000, now),
+                    attestation(signers[1], 2_000_000, 1_000, now),
+                ])
+                .rpc(),
+            "InvalidOracleAccount"
+        );
+    });
+
+    it("Rejects stale attestations and wide confidence", async () => {
+        await expectError(
+            deposit([
+                attestation(signers[0], 2_000_000, 1_000, now - 3600),
+                attestation(signers[1], 2_000_000, 1_000, now),
+            ]),
+            "OracleStale"
+        );
+        await expectError(
+            deposit([
+                attestation(signers[0], 2_000_000, 100_000, now),
+                attestation(signers[1], 2_000_000, 1_000, now),
+            ]),
+            "OracleConfidenceTooWide"
+        );
+    });
+
+    it("Rotates the signer set", async () => {
+        const replacement = Keypair.generate();
+        await program.methods
+            .setAttestationSigners([signers[0].publicKey, replacement.publicKey], 2)
+            .accounts({
+                attestationConfig: attestationConfigPda,
+                protocolState: protocolStatePda,
+                admin: wallet.publicKey,
+            } as any)
+            .rpc();
+
+        await expectError(
+            deposit([
+                attestation(signers[0], 2_000_000, 1_000, now),
+                attestation(signers[1], 2_000_000, 1_000, now),
+            ]),
+            "AttestationThresholdNotMet"
+        );
+        await deposit([
+            attestation(signers[0], 2_000_000, 1_000, now),
+            attestation(replacement, 2_000_000, 1_000, now),
+        ]);
+    });
+});
@@ -524,27 +524,34 @@ export class AegisClient {
   }
 
   /**
-   * Fetch Oracle Price from Pyth Account via Program View
-   * @param oraclePubkey - The Pyth Oracle Price Account Public Key
+   * Fetch a vault type's Oracle Price via Program View
+   * @param vaultTypePubkey - The Vault Type Public Key
    * @returns The current price as a number, or null if unavailable
    */
-  public async getOraclePrice(oraclePubkey: PublicKey): Promise<number | null> {
+  public async getOraclePrice(vaultTypePubkey: PublicKey): Promise<number | null> {
     try {
       const [protocolState] = this.getProtocolStatePDA();
+      const vaultType = await this.program.account.vaultType.fetch(vaultTypePubkey);
 
-      const priceBn = await this.program.methods
-        .getLatestPrice()
+      const priceView = await this.program.methods
+        .getPriceView()
         .accounts({
+          vaultType: vaultTypePubkey,
           protocolState,
-          oraclePriceAccount: oraclePubkey,
+          oraclePriceAccount: vaultType.oraclePriceAccount,
         } as any)
+        .remainingAccounts(
+          [vaultType.stakePoolAccount, vaultType.secondaryOracleAccount, vaultType.priceHistory]
+            .filter((pubkey) => !pubkey.equals(PublicKey.default))
+            .map((pubkey) => ({ pubkey, isSigner: false, isWritable: false }))
+        )
         .view();
 
       // Convert from 6 decimals (program standard) to float
-      return priceBn.toNumber() / 1_000_000;
+      return priceView.price.toNumber() / 1_000_000;
     } catch (e: any) {
       console.error("=== Oracle Price Fetch Error ===");
-      console.error("Oracle Account:", oraclePubkey.toString());
+      console.error("Vault Type:", vaultTypePubkey.toString());
       console.error("Error:", e);
       console.error("Error Message:", e.message);
 
@@ -14,18 +14,46 @@ export type AegisVault = {
   },
   "instructions": [
     {
-      "name": "addRole",
+      "name": "accrueVaultInterest",
       "discriminator": [
-        45,
-        20,
-        52,
-        132,
-        56,
-        24,
-        179,
-        37
+        126,
+        9,
+        170,
+        28,
+        84,
+        14,
+        85,
+        42
       ],
       "accounts": [
+        {
+          "name": "vaultType",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  45,
+                  116,
+                  121,
+                  112,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault_type.collateral_mint",
+                "account": "vaultType"
+              }
+            ]
+          }
+        },
         {
           "name": "protocolState",
           "writable": true,
@@ -52,65 +80,23 @@ export type AegisVault = {
               }
             ]
           }
-        },
-        {
-          "name": "adminPubkey",
-          "signer": true,
-          "relations": [
-            "protocolState"
-          ]
-        },
-        {
-          "name": "targetAccount"
         }
       ],
-      "args": [
-        {
-          "name": "roleType",
-          "type": "u8"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "createVaultType",
+      "name": "addRole",
       "discriminator": [
-        189,
-        181,
-        162,
-        156,
-        111,
-        162,
-        38,
-        254
+        45,
+        20,
+        52,
+        132,
+        56,
+        24,
+        179,
+        37
       ],
       "accounts": [
-        {
-          "name": "vaultType",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  118,
-                  97,
-                  117,
-                  108,
-                  116,
-                  45,
-                  116,
-                  121,
-                  112,
-                  101
-                ]
-              },
-              {
-                "kind": "arg",
-                "path": "collateralMint"
-              }
-            ]
-          }
-        },
         {
           "name": "protocolState",
           "writable": true,
@@ -139,55 +125,59 @@ export type AegisVault = {
           }
         },
         {
-          "name": "admin",
-          "writable": true,
-          "signer": true
+          "name": "adminPubkey",
+          "signer": true,
+          "relations": [
+            "protocolState"
+          ]
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "targetAccount"
         }
       ],
       "args": [
         {
-          "name": "collateralMint",
-          "type": "pubkey"
-        },
-        {
-          "name": "params",
-          "type": {
-            "defined": {
-              "name": "createVaultTypeParams"
-            }
-          }
+          "name": "roleType",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "depositCollateral",
+      "name": "bidLiquidationAuction",
       "discriminator": [
-        156,
-        131,
-        142,
-        116,
-        146,
-        247,
-        162,
-        120
+        6,
+        223,
+        30,
+        228,
+        147,
+        197,
+        27,
+        49
       ],
       "accounts": [
         {
-          "name": "position",
+          "name": "auction",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  111,
-                  115,
+                  108,
+                  105,
+                  113,
+                  117,
                   105,
+                  100,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  45,
+                  97,
+                  117,
+                  99,
                   116,
                   105,
                   111,
@@ -196,26 +186,40 @@ export type AegisVault = {
               },
               {
                 "kind": "account",
-                "path": "owner"
-              },
-              {
-                "kind": "account",
-                "path": "vaultType"
+                "path": "auction.position",
+                "account": "liquidationAuction"
               }
             ]
           }
         },
+        {
+          "name": "position",
+          "writable": true,
+          "relations": [
+            "auction"
+          ]
+        },
         {
           "name": "vaultType",
+          "writable": true,
           "relations": [
-            "position"
+            "auction"
           ]
         },
         {
-          "name": "protocolState"
+          "name": "protocolState",
+          "writable": true
         },
         {
-          "name": "userCollateralAccount",
+          "name": "stablecoinMint",
+          "writable": true
+        },
+        {
+          "name": "bidderStablecoinAccount",
+          "writable": true
+        },
+        {
+          "name": "bidderCollateralAccount",
           "writable": true
         },
         {
@@ -254,15 +258,16 @@ export type AegisVault = {
           }
         },
         {
-          "name": "oraclePriceAccount"
-        },
-        {
-          "name": "owner",
-          "signer": true,
+          "name": "keeper",
+          "writable": true,
           "relations": [
-            "position"
+            "auction"
           ]
         },
+        {
+          "name": "bidder",
+          "signer": true
+        },
         {
           "name": "tokenProgram",
           "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
@@ -270,72 +275,58 @@ export type AegisVault = {
       ],
       "args": [
         {
-          "name": "amount",
+          "name": "collateralAmount",
+          "type": "u64"
+        },
+        {
+          "name": "maxPrice",
           "type": "u64"
         }
       ]
     },
     {
-      "name": "getLatestPrice",
+      "name": "clearPriceOverride",
       "discriminator": [
-        195,
-        49,
-        189,
-        171,
-        219,
-        140,
-        79,
-        207
+        226,
+        108,
+        48,
+        229,
+        139,
+        136,
+        190,
+        245
       ],
       "accounts": [
         {
-          "name": "protocolState",
+          "name": "vaultType",
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  114,
-                  111,
-                  116,
-                  111,
-                  99,
-                  111,
+                  118,
+                  97,
+                  117,
                   108,
-                  95,
-                  115,
                   116,
-                  97,
+                  45,
                   116,
+                  121,
+                  112,
                   101
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "vault_type.collateral_mint",
+                "account": "vaultType"
               }
             ]
           }
         },
         {
-          "name": "oraclePriceAccount"
-        }
-      ],
-      "args": [],
-      "returns": "u64"
-    },
-    {
-      "name": "initializeProtocol",
-      "discriminator": [
-        188,
-        233,
-        252,
-        106,
-        134,
-        146,
-        202,
-        91
-      ],
-      "accounts": [
-        {
-          "name": "protocolState",
+          "name": "priceOverrideLog",
           "writable": true,
           "pda": {
             "seeds": [
@@ -344,14 +335,48 @@ export type AegisVault = {
                 "value": [
                   112,
                   114,
-                  111,
-                  116,
-                  111,
+                  105,
                   99,
+                  101,
+                  45,
                   111,
+                  118,
+                  101,
+                  114,
+                  114,
+                  105,
+                  100,
+                  101,
+                  45,
                   108,
-                  95,
-                  115,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultType"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  115,
                   116,
                   97,
                   116,
@@ -362,109 +387,104 @@ export type AegisVault = {
           }
         },
         {
-          "name": "admin",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "guardianPubkey",
+          "signer": true,
+          "relations": [
+            "protocolState"
+          ]
         }
       ],
-      "args": [
-        {
-          "name": "treasuryPubkey",
-          "type": "pubkey"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "liquidatePosition",
+      "name": "collectStabilityFees",
       "discriminator": [
-        187,
-        74,
-        229,
-        149,
-        102,
-        81,
-        221,
-        68
+        76,
+        35,
+        232,
+        179,
+        98,
+        139,
+        201,
+        158
       ],
       "accounts": [
         {
-          "name": "position",
+          "name": "vaultType",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  111,
-                  115,
-                  105,
+                  118,
+                  97,
+                  117,
+                  108,
                   116,
-                  105,
-                  111,
-                  110
+                  45,
+                  116,
+                  121,
+                  112,
+                  101
                 ]
               },
               {
                 "kind": "account",
-                "path": "position.owner",
-                "account": "position"
-              },
-              {
-                "kind": "account",
-                "path": "vaultType"
+                "path": "vault_type.collateral_mint",
+                "account": "vaultType"
               }
             ]
           }
         },
-        {
-          "name": "vaultType",
-          "relations": [
-            "position"
-          ]
-        },
         {
           "name": "protocolState",
-          "writable": true
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
         },
         {
           "name": "stablecoinMint",
           "writable": true
         },
         {
-          "name": "liquidatorStablecoinAccount",
-          "writable": true
-        },
-        {
-          "name": "liquidatorCollateralAccount",
-          "writable": true
-        },
-        {
-          "name": "vaultCollateralAccount",
-          "writable": true
-        },
-        {
-          "name": "treasuryCollateralAccount",
+          "name": "treasuryStablecoinAccount",
           "docs": [
-            "Treasury collateral account to receive protocol's share of penalty"
+            "Treasury stablecoin account to receive the surplus"
           ],
           "writable": true
         },
         {
-          "name": "vaultAuthority",
+          "name": "mintAuthority",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  118,
-                  97,
-                  117,
-                  108,
+                  109,
+                  105,
+                  110,
                   116,
                   95,
                   97,
@@ -477,44 +497,123 @@ export type AegisVault = {
                   116,
                   121
                 ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createVaultType",
+      "discriminator": [
+        189,
+        181,
+        162,
+        156,
+        111,
+        162,
+        38,
+        254
+      ],
+      "accounts": [
+        {
+          "name": "vaultType",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  45,
+                  116,
+                  121,
+                  112,
+                  101
+                ]
               },
               {
-                "kind": "account",
-                "path": "vaultType"
+                "kind": "arg",
+                "path": "collateralMint"
               }
             ]
           }
         },
         {
-          "name": "oraclePriceAccount"
+          "name": "collateralMintAccount"
         },
         {
-          "name": "liquidator",
+          "name": "protocolState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "writable": true,
           "signer": true
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         }
       ],
       "args": [
         {
-          "name": "repayAmount",
-          "type": "u64"
+          "name": "collateralMint",
+          "type": "pubkey"
+        },
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "createVaultTypeParams"
+            }
+          }
         }
       ]
     },
     {
-      "name": "mintStablecoin",
+      "name": "depositCollateral",
       "discriminator": [
-        196,
-        235,
-        215,
-        70,
-        211,
-        5,
-        214,
-        238
+        156,
+        131,
+        142,
+        116,
+        146,
+        247,
+        162,
+        120
       ],
       "accounts": [
         {
@@ -548,6 +647,7 @@ export type AegisVault = {
         },
         {
           "name": "vaultType",
+          "writable": true,
           "relations": [
             "position"
           ]
@@ -557,22 +657,43 @@ export type AegisVault = {
           "writable": true
         },
         {
-          "name": "stablecoinMint",
+          "name": "userCollateralAccount",
           "writable": true
         },
         {
-          "name": "userStablecoinAccount",
+          "name": "vaultCollateralAccount",
           "writable": true
         },
         {
-          "name": "treasuryStablecoinAccount",
-          "docs": [
-            "Treasury stablecoin account to receive fees"
-          ],
-          "writable": true
-        },
-        {
-          "name": "mintAuthority"
+          "name": "vaultAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultType"
+              }
+            ]
+          }
         },
         {
           "name": "oraclePriceAccount"
@@ -597,16 +718,16 @@ export type AegisVault = {
       ]
     },
     {
-      "name": "openPosition",
+      "name": "flashLiquidate",
       "discriminator": [
-        135,
-        128,
-        47,
-        77,
-        15,
-        152,
-        240,
-        49
+        242,
+        208,
+        101,
+        68,
+        1,
+        25,
+        165,
+        205
       ],
       "accounts": [
         {
@@ -629,7 +750,8 @@ export type AegisVault = {
               },
               {
                 "kind": "account",
-                "path": "owner"
+                "path": "position.owner",
+                "account": "position"
               },
               {
                 "kind": "account",
@@ -639,148 +761,112 @@ export type AegisVault = {
           }
         },
         {
-          "name": "vaultType"
+          "name": "vaultType",
+          "writable": true,
+          "relations": [
+            "position"
+          ]
         },
         {
-          "name": "owner",
-          "writable": true,
-          "signer": true
+          "name": "protocolState",
+          "writable": true
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "removeRole",
-      "discriminator": [
-        74,
-        69,
-        168,
-        163,
-        248,
-        3,
-        130,
-        0
-      ],
-      "accounts": [
+          "name": "stablecoinMint",
+          "writable": true
+        },
         {
-          "name": "protocolState",
-          "writable": true,
+          "name": "liquidatorStablecoinAccount",
+          "writable": true
+        },
+        {
+          "name": "liquidatorCollateralAccount",
+          "writable": true
+        },
+        {
+          "name": "vaultCollateralAccount",
+          "writable": true
+        },
+        {
+          "name": "treasuryCollateralAccount",
+          "docs": [
+            "Treasury collateral account to receive protocol's share of penalty"
+          ],
+          "writable": true
+        },
+        {
+          "name": "vaultAuthority",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  114,
-                  111,
-                  116,
-                  111,
-                  99,
-                  111,
+                  118,
+                  97,
+                  117,
                   108,
-                  95,
-                  115,
                   116,
+                  95,
                   97,
+                  117,
                   116,
-                  101
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultType"
               }
             ]
           }
         },
         {
-          "name": "adminPubkey",
-          "signer": true,
-          "relations": [
-            "protocolState"
-          ]
-        }
-      ],
-      "args": [
-        {
-          "name": "roleType",
-          "type": "u8"
-        }
-      ]
-    },
-    {
-      "name": "repayStablecoin",
-      "discriminator": [
-        111,
-        17,
-        16,
-        248,
-        213,
-        201,
-        133,
-        107
-      ],
-      "accounts": [
+          "name": "treasuryStablecoinAccount",
+          "docs": [
+            "Treasury stablecoin account, pays keeper rewards from its delegated allowance"
+          ],
+          "writable": true
+        },
         {
-          "name": "position",
-          "writable": true,
+          "name": "mintAuthority",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  111,
-                  115,
+                  109,
                   105,
+                  110,
                   116,
-                  105,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
                   111,
-                  110
+                  114,
+                  105,
+                  116,
+                  121
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "owner"
-              },
-              {
-                "kind": "account",
-                "path": "vaultType"
               }
             ]
           }
         },
         {
-          "name": "vaultType",
-          "relations": [
-            "position"
-          ]
-        },
-        {
-          "name": "protocolState",
-          "writable": true
-        },
-        {
-          "name": "stablecoinMint",
-          "writable": true
-        },
-        {
-          "name": "userStablecoinAccount",
-          "writable": true
+          "name": "oraclePriceAccount"
         },
         {
-          "name": "treasuryStablecoinAccount",
-          "docs": [
-            "Treasury stablecoin account to receive fees"
-          ],
-          "writable": true
+          "name": "callbackProgram"
         },
         {
-          "name": "owner",
-          "signer": true,
-          "relations": [
-            "position"
-          ]
+          "name": "liquidator",
+          "signer": true
         },
         {
           "name": "tokenProgram",
@@ -789,82 +875,57 @@ export type AegisVault = {
       ],
       "args": [
         {
-          "name": "amount",
+          "name": "repayAmount",
           "type": "u64"
+        },
+        {
+          "name": "callbackData",
+          "type": "bytes"
         }
       ]
     },
     {
-      "name": "setCollateralRatioBps",
+      "name": "getPriceView",
       "discriminator": [
-        143,
-        179,
-        226,
-        207,
-        4,
-        137,
-        191,
-        9
+        117,
+        228,
+        31,
+        181,
+        204,
+        63,
+        61,
+        200
       ],
       "accounts": [
         {
-          "name": "protocolState",
-          "writable": true,
+          "name": "vaultType",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  114,
-                  111,
-                  116,
-                  111,
-                  99,
-                  111,
+                  118,
+                  97,
+                  117,
                   108,
-                  95,
-                  115,
                   116,
-                  97,
+                  45,
                   116,
+                  121,
+                  112,
                   101
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "vault_type.collateral_mint",
+                "account": "vaultType"
               }
             ]
           }
         },
-        {
-          "name": "governancePubkey",
-          "signer": true,
-          "relations": [
-            "protocolState"
-          ]
-        }
-      ],
-      "args": [
-        {
-          "name": "newCollateralRatioBps",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "setDefaultVaultDebtCeiling",
-      "discriminator": [
-        213,
-        250,
-        103,
-        148,
-        29,
-        220,
-        240,
-        81
-      ],
-      "accounts": [
         {
           "name": "protocolState",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -890,91 +951,93 @@ export type AegisVault = {
           }
         },
         {
-          "name": "governancePubkey",
-          "signer": true,
-          "relations": [
-            "protocolState"
-          ]
+          "name": "oraclePriceAccount"
         }
       ],
-      "args": [
-        {
-          "name": "newCeiling",
-          "type": "u64"
+      "args": [],
+      "returns": {
+        "defined": {
+          "name": "priceView"
         }
-      ]
+      }
     },
     {
-      "name": "setGlobalDebtCeiling",
+      "name": "initializeAttestationConfig",
       "discriminator": [
-        23,
-        14,
-        56,
-        201,
-        136,
-        38,
+        21,
+        215,
+        77,
+        52,
+        10,
+        8,
         72,
-        19
+        128
       ],
       "accounts": [
         {
-          "name": "protocolState",
+          "name": "attestationConfig",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  114,
-                  111,
+                  97,
+                  116,
+                  116,
+                  101,
+                  115,
+                  116,
+                  97,
                   116,
+                  105,
                   111,
+                  110,
+                  45,
                   99,
                   111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultType"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultType",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
                   108,
-                  95,
-                  115,
                   116,
-                  97,
+                  45,
                   116,
+                  121,
+                  112,
                   101
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "vault_type.collateral_mint",
+                "account": "vaultType"
               }
             ]
           }
         },
-        {
-          "name": "governancePubkey",
-          "signer": true,
-          "relations": [
-            "protocolState"
-          ]
-        }
-      ],
-      "args": [
-        {
-          "name": "newGlobalDebtCeiling",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "setLiquidationPenaltyBps",
-      "discriminator": [
-        79,
-        20,
-        67,
-        76,
-        67,
-        122,
-        64,
-        217
-      ],
-      "accounts": [
         {
           "name": "protocolState",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -1000,35 +1063,43 @@ export type AegisVault = {
           }
         },
         {
-          "name": "governancePubkey",
-          "signer": true,
-          "relations": [
-            "protocolState"
-          ]
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         }
       ],
       "args": [
         {
-          "name": "newLiquidationPenaltyBps",
-          "type": "u64"
+          "name": "signers",
+          "type": {
+            "vec": "pubkey"
+          }
+        },
+        {
+          "name": "threshold",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "setLiquidationThresholdBps",
+      "name": "initializePriceFeed",
       "discriminator": [
-        240,
-        171,
-        134,
-        1,
-        231,
-        151,
-        240,
-        201
+        68,
+        180,
+        81,
+        20,
+        102,
+        213,
+        145,
+        233
       ],
       "accounts": [
         {
-          "name": "protocolState",
+          "name": "priceFeed",
           "writable": true,
           "pda": {
             "seeds": [
@@ -1037,54 +1108,52 @@ export type AegisVault = {
                 "value": [
                   112,
                   114,
-                  111,
-                  116,
-                  111,
+                  105,
                   99,
-                  111,
+                  101,
+                  45,
+                  102,
+                  101,
+                  101,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultType"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultType",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
                   108,
-                  95,
-                  115,
                   116,
-                  97,
+                  45,
                   116,
+                  121,
+                  112,
                   101
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "vault_type.collateral_mint",
+                "account": "vaultType"
               }
             ]
           }
         },
-        {
-          "name": "governancePubkey",
-          "signer": true,
-          "relations": [
-            "protocolState"
-          ]
-        }
-      ],
-      "args": [
-        {
-          "name": "newLiquidationThresholdBps",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "setMintFeeBps",
-      "discriminator": [
-        75,
-        255,
-        91,
-        143,
-        214,
-        189,
-        40,
-        99
-      ],
-      "accounts": [
         {
           "name": "protocolState",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -1110,35 +1179,37 @@ export type AegisVault = {
           }
         },
         {
-          "name": "governancePubkey",
-          "signer": true,
-          "relations": [
-            "protocolState"
-          ]
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         }
       ],
       "args": [
         {
-          "name": "newMintFeeBps",
+          "name": "maxMoveBps",
           "type": "u16"
         }
       ]
     },
     {
-      "name": "setOracleTtlSeconds",
+      "name": "initializePriceHistory",
       "discriminator": [
-        78,
-        147,
-        211,
-        13,
-        178,
-        215,
-        149,
-        179
+        169,
+        234,
+        21,
+        25,
+        245,
+        103,
+        208,
+        136
       ],
       "accounts": [
         {
-          "name": "protocolState",
+          "name": "priceHistory",
           "writable": true,
           "pda": {
             "seeds": [
@@ -1147,109 +1218,56 @@ export type AegisVault = {
                 "value": [
                   112,
                   114,
-                  111,
-                  116,
-                  111,
+                  105,
                   99,
+                  101,
+                  45,
+                  104,
+                  105,
+                  115,
+                  116,
                   111,
-                  108,
-                  95,
-                  115,
-                  116,
-                  97,
-                  116,
-                  101
+                  114,
+                  121
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultType"
               }
             ]
           }
         },
         {
-          "name": "governancePubkey",
-          "signer": true,
-          "relations": [
-            "protocolState"
-          ]
-        }
-      ],
-      "args": [
-        {
-          "name": "newTtlSeconds",
-          "type": "i64"
-        }
-      ]
-    },
-    {
-      "name": "setRedeemFeeBps",
-      "discriminator": [
-        11,
-        181,
-        246,
-        199,
-        69,
-        139,
-        87,
-        153
-      ],
-      "accounts": [
-        {
-          "name": "protocolState",
+          "name": "vaultType",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  114,
-                  111,
-                  116,
-                  111,
-                  99,
-                  111,
+                  118,
+                  97,
+                  117,
                   108,
-                  95,
-                  115,
                   116,
-                  97,
+                  45,
                   116,
+                  121,
+                  112,
                   101
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "vault_type.collateral_mint",
+                "account": "vaultType"
               }
             ]
           }
         },
-        {
-          "name": "governancePubkey",
-          "signer": true,
-          "relations": [
-            "protocolState"
-          ]
-        }
-      ],
-      "args": [
-        {
-          "name": "newRedeemFeeBps",
-          "type": "u16"
-        }
-      ]
-    },
-    {
-      "name": "setStabilityFeeBps",
-      "discriminator": [
-        115,
-        202,
-        86,
-        19,
-        8,
-        114,
-        237,
-        145
-      ],
-      "accounts": [
         {
           "name": "protocolState",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -1275,35 +1293,37 @@ export type AegisVault = {
           }
         },
         {
-          "name": "governancePubkey",
-          "signer": true,
-          "relations": [
-            "protocolState"
-          ]
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         }
       ],
       "args": [
         {
-          "name": "newStabilityFeeBps",
-          "type": "u16"
+          "name": "minIntervalSeconds",
+          "type": "u32"
         }
       ]
     },
     {
-      "name": "setStablecoinMint",
+      "name": "initializePriceOverrideLog",
       "discriminator": [
-        147,
-        26,
-        168,
-        171,
-        29,
-        135,
-        26,
-        92
+        4,
+        193,
+        134,
+        62,
+        174,
+        73,
+        75,
+        192
       ],
       "accounts": [
         {
-          "name": "protocolState",
+          "name": "priceOverrideLog",
           "writable": true,
           "pda": {
             "seeds": [
@@ -1312,54 +1332,33 @@ export type AegisVault = {
                 "value": [
                   112,
                   114,
-                  111,
-                  116,
-                  111,
+                  105,
                   99,
+                  101,
+                  45,
                   111,
+                  118,
+                  101,
+                  114,
+                  114,
+                  105,
+                  100,
+                  101,
+                  45,
                   108,
-                  95,
-                  115,
-                  116,
-                  97,
-                  116,
-                  101
+                  111,
+                  103
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultType"
               }
             ]
           }
         },
-        {
-          "name": "adminPubkey",
-          "signer": true,
-          "relations": [
-            "protocolState"
-          ]
-        }
-      ],
-      "args": [
-        {
-          "name": "stablecoinMint",
-          "type": "pubkey"
-        }
-      ]
-    },
-    {
-      "name": "toggleVaultActive",
-      "discriminator": [
-        158,
-        40,
-        6,
-        178,
-        102,
-        199,
-        254,
-        73
-      ],
-      "accounts": [
         {
           "name": "vaultType",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -1413,22 +1412,27 @@ export type AegisVault = {
         },
         {
           "name": "admin",
+          "writable": true,
           "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         }
       ],
       "args": []
     },
     {
-      "name": "updateFeatureFlags",
+      "name": "initializeProtocol",
       "discriminator": [
-        139,
-        88,
-        184,
-        214,
-        40,
-        6,
-        55,
-        247
+        188,
+        233,
+        252,
+        106,
+        134,
+        146,
+        202,
+        91
       ],
       "accounts": [
         {
@@ -1459,95 +1463,95 @@ export type AegisVault = {
           }
         },
         {
-          "name": "guardianPubkey",
-          "signer": true,
-          "relations": [
-            "protocolState"
-          ]
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         }
       ],
       "args": [
         {
-          "name": "params",
-          "type": {
-            "defined": {
-              "name": "updateFeatureFlagsParams"
-            }
-          }
+          "name": "treasuryPubkey",
+          "type": "pubkey"
         }
       ]
     },
     {
-      "name": "updateOracleAuthority",
+      "name": "initializeStabilityPool",
       "discriminator": [
-        69,
-        65,
-        130,
-        209,
-        32,
-        196,
-        23,
-        43
+        220,
+        103,
+        253,
+        62,
+        133,
+        245,
+        74,
+        222
       ],
       "accounts": [
         {
-          "name": "protocolState",
+          "name": "stabilityPool",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  114,
-                  111,
-                  116,
-                  111,
-                  99,
-                  111,
-                  108,
-                  95,
                   115,
                   116,
                   97,
+                  98,
+                  105,
+                  108,
+                  105,
                   116,
-                  101
+                  121,
+                  45,
+                  112,
+                  111,
+                  111,
+                  108
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultType"
               }
             ]
           }
         },
         {
-          "name": "adminPubkey",
-          "signer": true,
-          "relations": [
-            "protocolState"
-          ]
-        }
-      ],
-      "args": [
-        {
-          "name": "newAuthority",
-          "type": "pubkey"
-        }
-      ]
-    },
-    {
-      "name": "updateTreasury",
-      "discriminator": [
-        60,
-        16,
-        243,
-        66,
-        96,
-        59,
-        254,
-        131
-      ],
-      "accounts": [
+          "name": "vaultType",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  45,
+                  116,
+                  121,
+                  112,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault_type.collateral_mint",
+                "account": "vaultType"
+              }
+            ]
+          }
+        },
         {
           "name": "protocolState",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -1573,36 +1577,63 @@ export type AegisVault = {
           }
         },
         {
-          "name": "governancePubkey",
-          "signer": true,
-          "relations": [
-            "protocolState"
-          ]
-        }
-      ],
-      "args": [
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
         {
-          "name": "newTreasury",
-          "type": "pubkey"
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "updateVaultType",
+      "name": "liquidateBatch",
       "discriminator": [
-        143,
-        242,
-        47,
-        136,
-        79,
-        121,
-        83,
-        103
+        104,
+        103,
+        84,
+        137,
+        51,
+        141,
+        21,
+        168
       ],
       "accounts": [
         {
           "name": "vaultType",
-          "writable": true,
+          "writable": true
+        },
+        {
+          "name": "protocolState",
+          "writable": true
+        },
+        {
+          "name": "stablecoinMint",
+          "writable": true
+        },
+        {
+          "name": "liquidatorStablecoinAccount",
+          "writable": true
+        },
+        {
+          "name": "liquidatorCollateralAccount",
+          "writable": true
+        },
+        {
+          "name": "vaultCollateralAccount",
+          "writable": true
+        },
+        {
+          "name": "treasuryCollateralAccount",
+          "docs": [
+            "Treasury collateral account to receive protocol's share of penalty"
+          ],
+          "writable": true
+        },
+        {
+          "name": "vaultAuthority",
           "pda": {
             "seeds": [
               {
@@ -1613,74 +1644,95 @@ export type AegisVault = {
                   117,
                   108,
                   116,
-                  45,
+                  95,
+                  97,
+                  117,
                   116,
-                  121,
-                  112,
-                  101
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
                 ]
               },
               {
                 "kind": "account",
-                "path": "vault_type.collateral_mint",
-                "account": "vaultType"
+                "path": "vaultType"
               }
             ]
           }
         },
         {
-          "name": "protocolState",
+          "name": "treasuryStablecoinAccount",
+          "docs": [
+            "Treasury stablecoin account, pays keeper rewards from its delegated allowance"
+          ],
+          "writable": true
+        },
+        {
+          "name": "mintAuthority",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  114,
-                  111,
+                  109,
+                  105,
+                  110,
                   116,
-                  111,
-                  99,
-                  111,
-                  108,
                   95,
-                  115,
-                  116,
                   97,
+                  117,
                   116,
-                  101
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
                 ]
               }
             ]
           }
         },
         {
-          "name": "admin",
+          "name": "oraclePriceAccount"
+        },
+        {
+          "name": "liquidator",
           "signer": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
         }
       ],
       "args": [
         {
-          "name": "params",
-          "type": {
-            "defined": {
-              "name": "updateVaultTypeParams"
-            }
+          "name": "maxRepayPerPosition",
+          "type": "u64"
+        }
+      ],
+      "returns": {
+        "vec": {
+          "defined": {
+            "name": "batchLiquidationResult"
           }
         }
-      ]
+      }
     },
     {
-      "name": "withdrawCollateral",
+      "name": "liquidatePosition",
       "discriminator": [
-        115,
-        135,
-        168,
-        106,
-        139,
-        214,
-        138,
-        150
+        187,
+        74,
+        229,
+        149,
+        102,
+        81,
+        221,
+        68
       ],
       "accounts": [
         {
@@ -1703,7 +1755,8 @@ export type AegisVault = {
               },
               {
                 "kind": "account",
-                "path": "owner"
+                "path": "position.owner",
+                "account": "position"
               },
               {
                 "kind": "account",
@@ -1714,21 +1767,38 @@ export type AegisVault = {
         },
         {
           "name": "vaultType",
+          "writable": true,
           "relations": [
             "position"
           ]
         },
         {
-          "name": "protocolState"
+          "name": "protocolState",
+          "writable": true
         },
         {
-          "name": "userCollateralAccount",
+          "name": "stablecoinMint",
+          "writable": true
+        },
+        {
+          "name": "liquidatorStablecoinAccount",
+          "writable": true
+        },
+        {
+          "name": "liquidatorCollateralAccount",
           "writable": true
         },
         {
           "name": "vaultCollateralAccount",
           "writable": true
         },
+        {
+          "name": "treasuryCollateralAccount",
+          "docs": [
+            "Treasury collateral account to receive protocol's share of penalty"
+          ],
+          "writable": true
+        },
         {
           "name": "vaultAuthority",
           "pda": {
@@ -1760,15 +1830,45 @@ export type AegisVault = {
             ]
           }
         },
+        {
+          "name": "treasuryStablecoinAccount",
+          "docs": [
+            "Treasury stablecoin account, pays keeper rewards from its delegated allowance"
+          ],
+          "writable": true
+        },
+        {
+          "name": "mintAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
         {
           "name": "oraclePriceAccount"
         },
         {
-          "name": "owner",
-          "signer": true,
-          "relations": [
-            "position"
-          ]
+          "name": "liquidator",
+          "signer": true
         },
         {
           "name": "tokenProgram",
@@ -1777,13 +1877,3093 @@ export type AegisVault = {
       ],
       "args": [
         {
-          "name": "amount",
+          "name": "repayAmount",
           "type": "u64"
         }
       ]
-    }
-  ],
-  "accounts": [
+    },
+    {
+      "name": "liquidateWithStabilityPool",
+      "discriminator": [
+        180,
+        9,
+        249,
+        70,
+        200,
+        92,
+        139,
+        89
+      ],
+      "accounts": [
+        {
+          "name": "position",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "position.owner",
+                "account": "position"
+              },
+              {
+                "kind": "account",
+                "path": "vaultType"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultType",
+          "writable": true,
+          "relations": [
+            "position",
+            "stabilityPool"
+          ]
+        },
+        {
+          "name": "protocolState",
+          "writable": true
+        },
+        {
+          "name": "stabilityPool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  98,
+                  105,
+                  108,
+                  105,
+                  116,
+                  121,
+                  45,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultType"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stablecoinMint",
+          "writable": true
+        },
+        {
+          "name": "poolStablecoinAccount",
+          "writable": true
+        },
+        {
+          "name": "vaultCollateralAccount",
+          "writable": true
+        },
+        {
+          "name": "treasuryCollateralAccount",
+          "docs": [
+            "Treasury collateral account to receive protocol's share of penalty"
+          ],
+          "writable": true
+        },
+        {
+          "name": "vaultAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultType"
+              }
+            ]
+          }
+        },
+        {
+          "name": "oraclePriceAccount"
+        },
+        {
+          "name": "keeperStablecoinAccount",
+          "docs": [
+            "Keeper's stablecoin account, receives the keeper reward"
+          ],
+          "writable": true
+        },
+        {
+          "name": "treasuryStablecoinAccount",
+          "docs": [
+            "Treasury stablecoin account, pays keeper rewards from its delegated allowance"
+          ],
+          "writable": true
+        },
+        {
+          "name": "mintAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "keeper",
+          "signer": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migratePosition",
+      "discriminator": [
+        15,
+        132,
+        59,
+        50,
+        199,
+        6,
+        251,
+        46
+      ],
+      "accounts": [
+        {
+          "name": "position",
+          "writable": true
+        },
+        {
+          "name": "vaultType",
+          "writable": true
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateVaultType",
+      "discriminator": [
+        25,
+        52,
+        8,
+        41,
+        29,
+        154,
+        96,
+        212
+      ],
+      "accounts": [
+        {
+          "name": "vaultType",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  45,
+                  116,
+                  121,
+                  112,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "collateralMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "collateralMint"
+        },
+        {
+          "name": "protocolState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "mintStablecoin",
+      "discriminator": [
+        196,
+        235,
+        215,
+        70,
+        211,
+        5,
+        214,
+        238
+      ],
+      "accounts": [
+        {
+          "name": "position",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "vaultType"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultType",
+          "writable": true,
+          "relations": [
+            "position"
+          ]
+        },
+        {
+          "name": "protocolState",
+          "writable": true
+        },
+        {
+          "name": "stablecoinMint",
+          "writable": true
+        },
+        {
+          "name": "userStablecoinAccount",
+          "writable": true
+        },
+        {
+          "name": "treasuryStablecoinAccount",
+          "docs": [
+            "Treasury stablecoin account to receive fees"
+          ],
+          "writable": true
+        },
+        {
+          "name": "mintAuthority"
+        },
+        {
+          "name": "oraclePriceAccount"
+        },
+        {
+          "name": "owner",
+          "signer": true,
+          "relations": [
+            "position"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "openPosition",
+      "discriminator": [
+        135,
+        128,
+        47,
+        77,
+        15,
+        152,
+        240,
+        49
+      ],
+      "accounts": [
+        {
+          "name": "position",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "vaultType"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultType"
+        },
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "openStabilityDeposit",
+      "discriminator": [
+        83,
+        185,
+        161,
+        188,
+        209,
+        46,
+        239,
+        18
+      ],
+      "accounts": [
+        {
+          "name": "stabilityDeposit",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  98,
+                  105,
+                  108,
+                  105,
+                  116,
+                  121,
+                  45,
+                  100,
+                  101,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "stabilityPool"
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stabilityPool"
+        },
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "provideToStabilityPool",
+      "discriminator": [
+        148,
+        96,
+        229,
+        63,
+        143,
+        134,
+        174,
+        80
+      ],
+      "accounts": [
+        {
+          "name": "stabilityDeposit",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  98,
+                  105,
+                  108,
+                  105,
+                  116,
+                  121,
+                  45,
+                  100,
+                  101,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "stabilityPool"
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stabilityPool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  98,
+                  105,
+                  108,
+                  105,
+                  116,
+                  121,
+                  45,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultType"
+              }
+            ]
+          },
+          "relations": [
+            "stabilityDeposit"
+          ]
+        },
+        {
+          "name": "vaultType",
+          "relations": [
+            "stabilityPool"
+          ]
+        },
+        {
+          "name": "protocolState"
+        },
+        {
+          "name": "userStablecoinAccount",
+          "writable": true
+        },
+        {
+          "name": "poolStablecoinAccount",
+          "writable": true
+        },
+        {
+          "name": "userCollateralAccount",
+          "docs": [
+            "Receives the collateral gained since the last deposit or withdrawal"
+          ],
+          "writable": true
+        },
+        {
+          "name": "vaultCollateralAccount",
+          "writable": true
+        },
+        {
+          "name": "vaultAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultType"
+              }
+            ]
+          }
+        },
+        {
+          "name": "owner",
+          "signer": true,
+          "relations": [
+            "stabilityDeposit"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "pushPrice",
+      "discriminator": [
+        113,
+        238,
+        232,
+        235,
+        60,
+        71,
+        127,
+        203
+      ],
+      "accounts": [
+        {
+          "name": "priceFeed",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  105,
+                  99,
+                  101,
+                  45,
+                  102,
+                  101,
+                  101,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "price_feed.vault_type",
+                "account": "priceFeed"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "oracleUpdateAuthority",
+          "signer": true,
+          "relations": [
+            "protocolState"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "price",
+          "type": "u64"
+        },
+        {
+          "name": "conf",
+          "type": "u64"
+        },
+        {
+          "name": "publishTime",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "recordPrice",
+      "discriminator": [
+        210,
+        113,
+        46,
+        101,
+        107,
+        218,
+        83,
+        51
+      ],
+      "accounts": [
+        {
+          "name": "priceHistory",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  105,
+                  99,
+                  101,
+                  45,
+                  104,
+                  105,
+                  115,
+                  116,
+                  111,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultType"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultType",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  45,
+                  116,
+                  121,
+                  112,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault_type.collateral_mint",
+                "account": "vaultType"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "oraclePriceAccount"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "redistributePosition",
+      "discriminator": [
+        74,
+        129,
+        76,
+        100,
+        130,
+        244,
+        25,
+        55
+      ],
+      "accounts": [
+        {
+          "name": "position",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "position.owner",
+                "account": "position"
+              },
+              {
+                "kind": "account",
+                "path": "vaultType"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultType",
+          "writable": true,
+          "relations": [
+            "position"
+          ]
+        },
+        {
+          "name": "protocolState",
+          "writable": true
+        },
+        {
+          "name": "stabilityPool",
+          "docs": [
+            "be initialized"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  98,
+                  105,
+                  108,
+                  105,
+                  116,
+                  121,
+                  45,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultType"
+              }
+            ]
+          }
+        },
+        {
+          "name": "oraclePriceAccount"
+        },
+        {
+          "name": "keeper",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "refreshPrice",
+      "discriminator": [
+        253,
+        61,
+        142,
+        248,
+        9,
+        32,
+        158,
+        32
+      ],
+      "accounts": [
+        {
+          "name": "vaultType",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  45,
+                  116,
+                  121,
+                  112,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault_type.collateral_mint",
+                "account": "vaultType"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "oraclePriceAccount"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "removeRole",
+      "discriminator": [
+        74,
+        69,
+        168,
+        163,
+        248,
+        3,
+        130,
+        0
+      ],
+      "accounts": [
+        {
+          "name": "protocolState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "adminPubkey",
+          "signer": true,
+          "relations": [
+            "protocolState"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "roleType",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "repayStablecoin",
+      "discriminator": [
+        111,
+        17,
+        16,
+        248,
+        213,
+        201,
+        133,
+        107
+      ],
+      "accounts": [
+        {
+          "name": "position",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "vaultType"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultType",
+          "writable": true,
+          "relations": [
+            "position"
+          ]
+        },
+        {
+          "name": "protocolState",
+          "writable": true
+        },
+        {
+          "name": "stablecoinMint",
+          "writable": true
+        },
+        {
+          "name": "userStablecoinAccount",
+          "writable": true
+        },
+        {
+          "name": "treasuryStablecoinAccount",
+          "docs": [
+            "Treasury stablecoin account to receive fees"
+          ],
+          "writable": true
+        },
+        {
+          "name": "owner",
+          "signer": true,
+          "relations": [
+            "position"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "resetCircuitBreaker",
+      "discriminator": [
+        225,
+        48,
+        84,
+        136,
+        90,
+        146,
+        26,
+        149
+      ],
+      "accounts": [
+        {
+          "name": "vaultType",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  45,
+                  116,
+                  121,
+                  112,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault_type.collateral_mint",
+                "account": "vaultType"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "guardianPubkey",
+          "signer": true,
+          "relations": [
+            "protocolState"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setAttestationSigners",
+      "discriminator": [
+        149,
+        168,
+        23,
+        9,
+        234,
+        78,
+        174,
+        40
+      ],
+      "accounts": [
+        {
+          "name": "attestationConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  116,
+                  116,
+                  101,
+                  115,
+                  116,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  45,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "attestation_config.vault_type",
+                "account": "attestationConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "signers",
+          "type": {
+            "vec": "pubkey"
+          }
+        },
+        {
+          "name": "threshold",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "setCollateralRatioBps",
+      "discriminator": [
+        143,
+        179,
+        226,
+        207,
+        4,
+        137,
+        191,
+        9
+      ],
+      "accounts": [
+        {
+          "name": "protocolState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "governancePubkey",
+          "signer": true,
+          "relations": [
+            "protocolState"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "newCollateralRatioBps",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setDefaultVaultDebtCeiling",
+      "discriminator": [
+        213,
+        250,
+        103,
+        148,
+        29,
+        220,
+        240,
+        81
+      ],
+      "accounts": [
+        {
+          "name": "protocolState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "governancePubkey",
+          "signer": true,
+          "relations": [
+            "protocolState"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "newCeiling",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setGlobalDebtCeiling",
+      "discriminator": [
+        23,
+        14,
+        56,
+        201,
+        136,
+        38,
+        72,
+        19
+      ],
+      "accounts": [
+        {
+          "name": "protocolState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "governancePubkey",
+          "signer": true,
+          "relations": [
+            "protocolState"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "newGlobalDebtCeiling",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setLiquidationPenaltyBps",
+      "discriminator": [
+        79,
+        20,
+        67,
+        76,
+        67,
+        122,
+        64,
+        217
+      ],
+      "accounts": [
+        {
+          "name": "protocolState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "governancePubkey",
+          "signer": true,
+          "relations": [
+            "protocolState"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "newLiquidationPenaltyBps",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setLiquidationThresholdBps",
+      "discriminator": [
+        240,
+        171,
+        134,
+        1,
+        231,
+        151,
+        240,
+        201
+      ],
+      "accounts": [
+        {
+          "name": "protocolState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "governancePubkey",
+          "signer": true,
+          "relations": [
+            "protocolState"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "newLiquidationThresholdBps",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setMintFeeBps",
+      "discriminator": [
+        75,
+        255,
+        91,
+        143,
+        214,
+        189,
+        40,
+        99
+      ],
+      "accounts": [
+        {
+          "name": "protocolState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "governancePubkey",
+          "signer": true,
+          "relations": [
+            "protocolState"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "newMintFeeBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setOracleTtlSeconds",
+      "discriminator": [
+        78,
+        147,
+        211,
+        13,
+        178,
+        215,
+        149,
+        179
+      ],
+      "accounts": [
+        {
+          "name": "protocolState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "governancePubkey",
+          "signer": true,
+          "relations": [
+            "protocolState"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "newTtlSeconds",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "setPriceFeedMaxMove",
+      "discriminator": [
+        96,
+        196,
+        79,
+        158,
+        230,
+        193,
+        45,
+        178
+      ],
+      "accounts": [
+        {
+          "name": "priceFeed",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  105,
+                  99,
+                  101,
+                  45,
+                  102,
+                  101,
+                  101,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "price_feed.vault_type",
+                "account": "priceFeed"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "maxMoveBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setPriceOverride",
+      "discriminator": [
+        79,
+        49,
+        167,
+        8,
+        176,
+        138,
+        254,
+        222
+      ],
+      "accounts": [
+        {
+          "name": "vaultType",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  45,
+                  116,
+                  121,
+                  112,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault_type.collateral_mint",
+                "account": "vaultType"
+              }
+            ]
+          }
+        },
+        {
+          "name": "priceOverrideLog",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  105,
+                  99,
+                  101,
+                  45,
+                  111,
+                  118,
+                  101,
+                  114,
+                  114,
+                  105,
+                  100,
+                  101,
+                  45,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultType"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "guardianPubkey",
+          "signer": true,
+          "relations": [
+            "protocolState"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "price",
+          "type": "u64"
+        },
+        {
+          "name": "durationSeconds",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "setRedeemFeeBps",
+      "discriminator": [
+        11,
+        181,
+        246,
+        199,
+        69,
+        139,
+        87,
+        153
+      ],
+      "accounts": [
+        {
+          "name": "protocolState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "governancePubkey",
+          "signer": true,
+          "relations": [
+            "protocolState"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "newRedeemFeeBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setStabilityFeeBps",
+      "discriminator": [
+        115,
+        202,
+        86,
+        19,
+        8,
+        114,
+        237,
+        145
+      ],
+      "accounts": [
+        {
+          "name": "protocolState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "governancePubkey",
+          "signer": true,
+          "relations": [
+            "protocolState"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "newStabilityFeeBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setStablecoinMint",
+      "discriminator": [
+        147,
+        26,
+        168,
+        171,
+        29,
+        135,
+        26,
+        92
+      ],
+      "accounts": [
+        {
+          "name": "protocolState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "adminPubkey",
+          "signer": true,
+          "relations": [
+            "protocolState"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "stablecoinMint",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "setSurplusBufferCap",
+      "discriminator": [
+        232,
+        228,
+        22,
+        121,
+        202,
+        111,
+        112,
+        231
+      ],
+      "accounts": [
+        {
+          "name": "protocolState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "governancePubkey",
+          "signer": true,
+          "relations": [
+            "protocolState"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "newCap",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "settleLiquidationAuction",
+      "discriminator": [
+        116,
+        137,
+        231,
+        255,
+        220,
+        245,
+        108,
+        12
+      ],
+      "accounts": [
+        {
+          "name": "auction",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  113,
+                  117,
+                  105,
+                  100,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  45,
+                  97,
+                  117,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction.position",
+                "account": "liquidationAuction"
+              }
+            ]
+          }
+        },
+        {
+          "name": "position",
+          "writable": true,
+          "relations": [
+            "auction"
+          ]
+        },
+        {
+          "name": "vaultType",
+          "writable": true,
+          "relations": [
+            "auction"
+          ]
+        },
+        {
+          "name": "protocolState",
+          "writable": true
+        },
+        {
+          "name": "vaultCollateralAccount",
+          "writable": true
+        },
+        {
+          "name": "treasuryCollateralAccount",
+          "writable": true
+        },
+        {
+          "name": "vaultAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultType"
+              }
+            ]
+          }
+        },
+        {
+          "name": "keeper",
+          "writable": true,
+          "relations": [
+            "auction"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "startLiquidationAuction",
+      "discriminator": [
+        32,
+        210,
+        115,
+        53,
+        58,
+        3,
+        225,
+        120
+      ],
+      "accounts": [
+        {
+          "name": "auction",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  113,
+                  117,
+                  105,
+                  100,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  45,
+                  97,
+                  117,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "position"
+              }
+            ]
+          }
+        },
+        {
+          "name": "position",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "position.owner",
+                "account": "position"
+              },
+              {
+                "kind": "account",
+                "path": "vaultType"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultType",
+          "writable": true,
+          "relations": [
+            "position"
+          ]
+        },
+        {
+          "name": "protocolState",
+          "writable": true
+        },
+        {
+          "name": "oraclePriceAccount"
+        },
+        {
+          "name": "keeper",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "toggleVaultActive",
+      "discriminator": [
+        158,
+        40,
+        6,
+        178,
+        102,
+        199,
+        254,
+        73
+      ],
+      "accounts": [
+        {
+          "name": "vaultType",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  45,
+                  116,
+                  121,
+                  112,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault_type.collateral_mint",
+                "account": "vaultType"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateFeatureFlags",
+      "discriminator": [
+        139,
+        88,
+        184,
+        214,
+        40,
+        6,
+        55,
+        247
+      ],
+      "accounts": [
+        {
+          "name": "protocolState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "guardianPubkey",
+          "signer": true,
+          "relations": [
+            "protocolState"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "updateFeatureFlagsParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateOracleAuthority",
+      "discriminator": [
+        69,
+        65,
+        130,
+        209,
+        32,
+        196,
+        23,
+        43
+      ],
+      "accounts": [
+        {
+          "name": "protocolState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "adminPubkey",
+          "signer": true,
+          "relations": [
+            "protocolState"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "newAuthority",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "updateTreasury",
+      "discriminator": [
+        60,
+        16,
+        243,
+        66,
+        96,
+        59,
+        254,
+        131
+      ],
+      "accounts": [
+        {
+          "name": "protocolState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "governancePubkey",
+          "signer": true,
+          "relations": [
+            "protocolState"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "newTreasury",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "updateVaultType",
+      "discriminator": [
+        143,
+        242,
+        47,
+        136,
+        79,
+        121,
+        83,
+        103
+      ],
+      "accounts": [
+        {
+          "name": "vaultType",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  45,
+                  116,
+                  121,
+                  112,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault_type.collateral_mint",
+                "account": "vaultType"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "updateVaultTypeParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "withdrawCollateral",
+      "discriminator": [
+        115,
+        135,
+        168,
+        106,
+        139,
+        214,
+        138,
+        150
+      ],
+      "accounts": [
+        {
+          "name": "position",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "vaultType"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultType",
+          "writable": true,
+          "relations": [
+            "position"
+          ]
+        },
+        {
+          "name": "protocolState",
+          "writable": true
+        },
+        {
+          "name": "userCollateralAccount",
+          "writable": true
+        },
+        {
+          "name": "vaultCollateralAccount",
+          "writable": true
+        },
+        {
+          "name": "vaultAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultType"
+              }
+            ]
+          }
+        },
+        {
+          "name": "oraclePriceAccount"
+        },
+        {
+          "name": "owner",
+          "signer": true,
+          "relations": [
+            "position"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawFromStabilityPool",
+      "discriminator": [
+        147,
+        0,
+        95,
+        188,
+        90,
+        204,
+        60,
+        68
+      ],
+      "accounts": [
+        {
+          "name": "stabilityDeposit",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  98,
+                  105,
+                  108,
+                  105,
+                  116,
+                  121,
+                  45,
+                  100,
+                  101,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "stabilityPool"
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stabilityPool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  98,
+                  105,
+                  108,
+                  105,
+                  116,
+                  121,
+                  45,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultType"
+              }
+            ]
+          },
+          "relations": [
+            "stabilityDeposit"
+          ]
+        },
+        {
+          "name": "vaultType",
+          "relations": [
+            "stabilityPool"
+          ]
+        },
+        {
+          "name": "protocolState"
+        },
+        {
+          "name": "userStablecoinAccount",
+          "writable": true
+        },
+        {
+          "name": "poolStablecoinAccount",
+          "writable": true
+        },
+        {
+          "name": "userCollateralAccount",
+          "docs": [
+            "Receives the collateral gained since the last deposit or withdrawal"
+          ],
+          "writable": true
+        },
+        {
+          "name": "vaultCollateralAccount",
+          "writable": true
+        },
+        {
+          "name": "vaultAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vaultType"
+              }
+            ]
+          }
+        },
+        {
+          "name": "owner",
+          "signer": true,
+          "relations": [
+            "stabilityDeposit"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "writeOffBadDebt",
+      "discriminator": [
+        166,
+        5,
+        239,
+        3,
+        173,
+        87,
+        155,
+        155
+      ],
+      "accounts": [
+        {
+          "name": "position",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "position.owner",
+                "account": "position"
+              },
+              {
+                "kind": "account",
+                "path": "vaultType"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultType",
+          "writable": true,
+          "relations": [
+            "position"
+          ]
+        },
+        {
+          "name": "protocolState",
+          "writable": true
+        },
+        {
+          "name": "keeper",
+          "signer": true
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "attestationConfig",
+      "discriminator": [
+        216,
+        42,
+        248,
+        85,
+        71,
+        201,
+        191,
+        29
+      ]
+    },
+    {
+      "name": "liquidationAuction",
+      "discriminator": [
+        114,
+        80,
+        77,
+        48,
+        79,
+        174,
+        24,
+        141
+      ]
+    },
     {
       "name": "position",
       "discriminator": [
@@ -1798,189 +4978,1330 @@ export type AegisVault = {
       ]
     },
     {
-      "name": "protocolState",
-      "discriminator": [
-        33,
-        51,
-        173,
-        134,
-        35,
-        140,
-        195,
-        248
-      ]
+      "name": "priceFeed",
+      "discriminator": [
+        189,
+        103,
+        252,
+        23,
+        152,
+        35,
+        243,
+        156
+      ]
+    },
+    {
+      "name": "priceHistory",
+      "discriminator": [
+        38,
+        241,
+        40,
+        19,
+        42,
+        228,
+        93,
+        152
+      ]
+    },
+    {
+      "name": "priceOverrideLog",
+      "discriminator": [
+        54,
+        69,
+        69,
+        98,
+        54,
+        198,
+        122,
+        68
+      ]
+    },
+    {
+      "name": "protocolState",
+      "discriminator": [
+        33,
+        51,
+        173,
+        134,
+        35,
+        140,
+        195,
+        248
+      ]
+    },
+    {
+      "name": "stabilityDeposit",
+      "discriminator": [
+        242,
+        123,
+        35,
+        27,
+        233,
+        123,
+        145,
+        214
+      ]
+    },
+    {
+      "name": "stabilityPool",
+      "discriminator": [
+        186,
+        155,
+        100,
+        88,
+        77,
+        81,
+        226,
+        109
+      ]
+    },
+    {
+      "name": "vaultType",
+      "discriminator": [
+        251,
+        71,
+        249,
+        103,
+        117,
+        71,
+        62,
+        101
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "unauthorized",
+      "msg": "You are not authorized to perform this action."
+    },
+    {
+      "code": 6001,
+      "name": "invalidFee",
+      "msg": "Fee must be less than or equal to 100% (10000 basis points)."
+    },
+    {
+      "code": 6002,
+      "name": "mathOverflow",
+      "msg": "Math operation overflow."
+    },
+    {
+      "code": 6003,
+      "name": "protocolPaused",
+      "msg": "Protocol is currently paused."
+    },
+    {
+      "code": 6004,
+      "name": "protocolShutdown",
+      "msg": "Protocol is shutdown."
+    },
+    {
+      "code": 6005,
+      "name": "oracleStale",
+      "msg": "Oracle price is stale."
+    },
+    {
+      "code": 6006,
+      "name": "invalidAmount",
+      "msg": "Invalid amount specified."
+    },
+    {
+      "code": 6007,
+      "name": "exceedsLtv",
+      "msg": "Exceeds LTV ratio limit."
+    },
+    {
+      "code": 6008,
+      "name": "exceedsDebtCeiling",
+      "msg": "Exceeds debt ceiling."
+    },
+    {
+      "code": 6009,
+      "name": "positionHealthy",
+      "msg": "Position is healthy and cannot be liquidated."
+    },
+    {
+      "code": 6010,
+      "name": "insufficientCollateral",
+      "msg": "Insufficient collateral in position."
+    },
+    {
+      "code": 6011,
+      "name": "insufficientBalance",
+      "msg": "Insufficient token balance."
+    },
+    {
+      "code": 6012,
+      "name": "mintPaused",
+      "msg": "Minting is currently paused."
+    },
+    {
+      "code": 6013,
+      "name": "redeemPaused",
+      "msg": "Redemption is currently paused."
+    },
+    {
+      "code": 6014,
+      "name": "invalidOracleConfig",
+      "msg": "Invalid oracle configuration."
+    },
+    {
+      "code": 6015,
+      "name": "invalidOracleAccount",
+      "msg": "Oracle account does not match the vault type."
+    },
+    {
+      "code": 6016,
+      "name": "oracleConfidenceTooWide",
+      "msg": "Oracle confidence interval is too wide."
+    },
+    {
+      "code": 6017,
+      "name": "invalidCollateralDecimals",
+      "msg": "Collateral decimals do not match the collateral mint."
+    },
+    {
+      "code": 6018,
+      "name": "oracleDeviationTooHigh",
+      "msg": "Primary and secondary oracle prices deviate too much."
+    },
+    {
+      "code": 6019,
+      "name": "priceMoveTooLarge",
+      "msg": "Pushed price moves more than the feed allows."
+    },
+    {
+      "code": 6020,
+      "name": "invalidPublishTime",
+      "msg": "Pushed price publish time is invalid."
+    },
+    {
+      "code": 6021,
+      "name": "circuitBreakerTripped",
+      "msg": "Price moved beyond the circuit breaker limit."
+    },
+    {
+      "code": 6022,
+      "name": "vaultMintPaused",
+      "msg": "Minting is paused for this vault type."
+    },
+    {
+      "code": 6023,
+      "name": "oraclePriceOutOfBand",
+      "msg": "Oracle price is outside the vault's price band."
     },
     {
-      "name": "vaultType",
-      "discriminator": [
-        251,
-        71,
-        249,
-        103,
-        117,
-        71,
-        62,
-        101
-      ]
-    }
-  ],
-  "errors": [
+      "code": 6024,
+      "name": "oracleFeedMismatch",
+      "msg": "Oracle account does not carry the vault type's price feed."
+    },
     {
-      "code": 6000,
-      "name": "unauthorized",
-      "msg": "You are not authorized to perform this action."
+      "code": 6025,
+      "name": "oracleNegativePrice",
+      "msg": "Oracle price is zero or negative."
     },
     {
-      "code": 6001,
-      "name": "invalidFee",
-      "msg": "Fee must be less than or equal to 100% (10000 basis points)."
+      "code": 6026,
+      "name": "oracleVerificationTooLow",
+      "msg": "Oracle price update is not sufficiently verified."
     },
     {
-      "code": 6002,
-      "name": "mathOverflow",
-      "msg": "Math operation overflow."
+      "code": 6027,
+      "name": "priceHistoryInsufficient",
+      "msg": "Price history does not cover the TWAP window."
     },
     {
-      "code": 6003,
-      "name": "protocolPaused",
-      "msg": "Protocol is currently paused."
+      "code": 6028,
+      "name": "priceObservationTooSoon",
+      "msg": "Price observation is too close to the previous one."
+    },
+    {
+      "code": 6029,
+      "name": "invalidAttestationSigners",
+      "msg": "Invalid attestation signer set."
+    },
+    {
+      "code": 6030,
+      "name": "attestationThresholdNotMet",
+      "msg": "Not enough valid price attestations in the transaction."
+    },
+    {
+      "code": 6031,
+      "name": "attestationReplayed",
+      "msg": "Attestation is older than one already consumed from its signer."
+    },
+    {
+      "code": 6032,
+      "name": "priceOverrideOutOfBounds",
+      "msg": "Override price is outside the bounds of the last good price."
+    },
+    {
+      "code": 6033,
+      "name": "invalidOverrideDuration",
+      "msg": "Override duration is invalid."
+    },
+    {
+      "code": 6034,
+      "name": "invalidLiquidationConfig",
+      "msg": "Invalid liquidation configuration."
+    },
+    {
+      "code": 6035,
+      "name": "liquidationModeMismatch",
+      "msg": "Liquidation is not available in the vault type's liquidation mode."
+    },
+    {
+      "code": 6036,
+      "name": "auctionPriceTooHigh",
+      "msg": "Auction price is above the bid limit."
+    },
+    {
+      "code": 6037,
+      "name": "auctionStillRunning",
+      "msg": "Auction is still running and its collateral can still be sold."
+    },
+    {
+      "code": 6038,
+      "name": "stabilityPoolEmpty",
+      "msg": "Stability pool has no deposits to absorb the liquidation."
+    },
+    {
+      "code": 6039,
+      "name": "stabilityPoolHistoryFull",
+      "msg": "Stability pool epoch and scale history is full."
+    },
+    {
+      "code": 6040,
+      "name": "positionNotInsolvent",
+      "msg": "Position still has collateral or no debt to write off."
+    },
+    {
+      "code": 6041,
+      "name": "invalidCallbackProgram",
+      "msg": "Callback program is not an executable program other than Aegis."
+    },
+    {
+      "code": 6042,
+      "name": "flashLiquidationNotRepaid",
+      "msg": "Flash liquidation callback did not return enough AGSUSD to repay the debt."
+    },
+    {
+      "code": 6043,
+      "name": "batchTooLarge",
+      "msg": "Too many positions in one liquidation batch."
+    },
+    {
+      "code": 6044,
+      "name": "duplicateBatchPosition",
+      "msg": "Position appears more than once in the liquidation batch."
+    },
+    {
+      "code": 6045,
+      "name": "positionNotUnderwater",
+      "msg": "Position is not under water, liquidate it instead."
+    },
+    {
+      "code": 6046,
+      "name": "noStakesToRedistribute",
+      "msg": "No other positions to redistribute to."
+    },
+    {
+      "code": 6047,
+      "name": "stabilityPoolCanAbsorb",
+      "msg": "Stability pool can absorb the position, liquidate it through the pool instead."
+    },
+    {
+      "code": 6048,
+      "name": "accountAlreadyMigrated",
+      "msg": "Account already uses the current layout."
+    }
+  ],
+  "types": [
+    {
+      "name": "attestationConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vaultType",
+            "docs": [
+              "Vault type priced by the attestations"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "signers",
+            "docs": [
+              "Keys allowed to sign price attestations (first `signer_count` are set)"
+            ],
+            "type": {
+              "array": [
+                "pubkey",
+                5
+              ]
+            }
+          },
+          {
+            "name": "signerCount",
+            "docs": [
+              "Number of configured signers"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "threshold",
+            "docs": [
+              "Distinct signer attestations required in a transaction"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "Timestamp when the config was created"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "updatedAt",
+            "docs": [
+              "Timestamp when the signer set was last updated"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "lastAttestationTs",
+            "docs": [
+              "Latest attestation timestamp consumed from each signer, by index in `signers`"
+            ],
+            "type": {
+              "array": [
+                "i64",
+                5
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "batchLiquidationResult",
+      "docs": [
+        "Outcome for one position of a `liquidate_batch`, returned as return data."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "position",
+            "type": "pubkey"
+          },
+          {
+            "name": "status",
+            "docs": [
+              "`BATCH_LIQUIDATION_*` status"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "debtRepaid",
+            "type": "u64"
+          },
+          {
+            "name": "collateralSeized",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "createVaultTypeParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "collateralDecimals",
+            "type": "u8"
+          },
+          {
+            "name": "oracleSource",
+            "type": "u8"
+          },
+          {
+            "name": "oraclePriceAccount",
+            "type": "pubkey"
+          },
+          {
+            "name": "oracleFeedId",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "oracleMaxAgeSeconds",
+            "type": "u64"
+          },
+          {
+            "name": "oraclePriceExponent",
+            "type": "i32"
+          },
+          {
+            "name": "oracleVerificationLevel",
+            "type": "u8"
+          },
+          {
+            "name": "oracleMinSignatures",
+            "type": "u8"
+          },
+          {
+            "name": "confMultiplierBps",
+            "type": "u16"
+          },
+          {
+            "name": "maxConfRatioBps",
+            "type": "u16"
+          },
+          {
+            "name": "pricingMode",
+            "type": "u8"
+          },
+          {
+            "name": "stakePoolAccount",
+            "type": "pubkey"
+          },
+          {
+            "name": "isPegged",
+            "type": "bool"
+          },
+          {
+            "name": "minPrice",
+            "type": "u64"
+          },
+          {
+            "name": "maxPrice",
+            "type": "u64"
+          },
+          {
+            "name": "secondaryOracleAccount",
+            "type": "pubkey"
+          },
+          {
+            "name": "secondaryOracleMaxAgeSeconds",
+            "type": "u64"
+          },
+          {
+            "name": "oracleAggregation",
+            "type": "u8"
+          },
+          {
+            "name": "maxOracleDeviationBps",
+            "type": "u16"
+          },
+          {
+            "name": "ltvBps",
+            "type": "u64"
+          },
+          {
+            "name": "liqThresholdBps",
+            "type": "u64"
+          },
+          {
+            "name": "liqPenaltyBps",
+            "type": "u64"
+          },
+          {
+            "name": "stabilityFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "mintFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "redeemFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "vaultDebtCeiling",
+            "type": "u64"
+          },
+          {
+            "name": "closeFactorBps",
+            "type": "u16"
+          },
+          {
+            "name": "targetHealthBps",
+            "type": "u16"
+          },
+          {
+            "name": "fullLiquidationHealthBps",
+            "type": "u16"
+          },
+          {
+            "name": "liquidationDustThreshold",
+            "type": "u64"
+          },
+          {
+            "name": "liquidationMode",
+            "type": "u8"
+          },
+          {
+            "name": "auctionStartPremiumBps",
+            "type": "u16"
+          },
+          {
+            "name": "auctionFloorBps",
+            "type": "u16"
+          },
+          {
+            "name": "auctionDurationSeconds",
+            "type": "u64"
+          },
+          {
+            "name": "liquidationProtocolShareBps",
+            "type": "u16"
+          },
+          {
+            "name": "keeperReward",
+            "type": "u64"
+          },
+          {
+            "name": "keeperRewardMaxDebt",
+            "type": "u64"
+          },
+          {
+            "name": "liquidationBonusMinBps",
+            "type": "u16"
+          },
+          {
+            "name": "liquidationBonusMaxBps",
+            "type": "u16"
+          },
+          {
+            "name": "maxPriceMoveBpsPerMinute",
+            "type": "u16"
+          },
+          {
+            "name": "twapWindowSeconds",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6004,
-      "name": "protocolShutdown",
-      "msg": "Protocol is shutdown."
+      "name": "epochScaleSum",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "epoch",
+            "docs": [
+              "Epoch, incremented each time a liquidation empties the pool"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "scale",
+            "docs": [
+              "Scale, incremented each time P is rescaled within an epoch"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "sum",
+            "docs": [
+              "Collateral gain sum S for this epoch and scale (STABILITY_POOL_PRECISION fixed point)"
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "snapshotCount",
+            "docs": [
+              "Deposits whose snapshot is at this epoch and scale"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6005,
-      "name": "oracleStale",
-      "msg": "Oracle price is stale or invalid."
+      "name": "liquidationAuction",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vaultType",
+            "docs": [
+              "Vault type the collateral belongs to"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "position",
+            "docs": [
+              "Liquidated position, credited with any collateral left when the auction settles"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "keeper",
+            "docs": [
+              "Keeper that started the auction and receives its rent back on settlement"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "collateralAmount",
+            "docs": [
+              "Collateral left for sale (in collateral token decimals)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "debtRemaining",
+            "docs": [
+              "Debt still to be raised in AGSUSD (6 decimals)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "startPrice",
+            "docs": [
+              "Auction price per whole token at the start, in USD (6 decimals)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "floorPrice",
+            "docs": [
+              "Price the auction decays to and stays at, in USD (6 decimals)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "startTime",
+            "docs": [
+              "Timestamp the auction started at"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "durationSeconds",
+            "docs": [
+              "Seconds for the price to decay from start to floor"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6006,
-      "name": "invalidAmount",
-      "msg": "Invalid amount specified."
+      "name": "position",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "docs": [
+              "Owner of this position"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "vaultType",
+            "docs": [
+              "Vault type this position belongs to"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "collateralAmount",
+            "docs": [
+              "Amount of collateral deposited (in collateral token decimals)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "debtAmount",
+            "docs": [
+              "Normalized stablecoin debt (in 6 decimals).",
+              "Amount owed is `debt_amount * vault_type.cumulative_rate / RATE_PRECISION`."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "Timestamp when position was created"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "updatedAt",
+            "docs": [
+              "Timestamp when position was last updated"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "stake",
+            "docs": [
+              "Share of redistributed collateral and debt, see `utils::redistribution`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lCollSnapshot",
+            "docs": [
+              "Vault type's `l_coll` and `l_debt` when redistributions were last applied"
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "lDebtSnapshot",
+            "type": "u128"
+          }
+        ]
+      }
     },
     {
-      "code": 6007,
-      "name": "exceedsLtv",
-      "msg": "Exceeds LTV ratio limit."
+      "name": "priceFeed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vaultType",
+            "docs": [
+              "Vault type this feed prices"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "docs": [
+              "Latest pushed price in USD (6 decimals)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "conf",
+            "docs": [
+              "Confidence interval of the latest price in USD (6 decimals)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "publishTime",
+            "docs": [
+              "Timestamp the latest price was observed at"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "maxMoveBps",
+            "docs": [
+              "Maximum price move between consecutive updates in bps (0 = unbounded)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "Timestamp when the feed was created"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "updatedAt",
+            "docs": [
+              "Timestamp when the feed was last updated"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6008,
-      "name": "exceedsDebtCeiling",
-      "msg": "Exceeds debt ceiling."
+      "name": "priceHistory",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vaultType",
+            "docs": [
+              "Vault type whose prices are recorded"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "minIntervalSeconds",
+            "docs": [
+              "Minimum time between consecutive observations"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "head",
+            "docs": [
+              "Index the next observation is written to"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "len",
+            "docs": [
+              "Number of observations recorded, up to the capacity"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "observations",
+            "docs": [
+              "Ring buffer of observations, oldest overwritten first"
+            ],
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "priceObservation"
+                  }
+                },
+                48
+              ]
+            }
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "Timestamp when the history was created"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6009,
-      "name": "positionHealthy",
-      "msg": "Position is healthy and cannot be liquidated."
+      "name": "priceObservation",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "docs": [
+              "Accepted spot price in USD (6 decimals)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "docs": [
+              "Timestamp the price was recorded at"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6010,
-      "name": "insufficientCollateral",
-      "msg": "Insufficient collateral in position."
+      "name": "priceOverrideEntry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardian",
+            "docs": [
+              "Guardian that set or cleared the override"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "docs": [
+              "Override price in USD (6 decimals), 0 when cleared"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "referencePrice",
+            "docs": [
+              "Last good price the override was bounded against"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "setAt",
+            "docs": [
+              "Timestamp the entry was recorded at"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "expiresAt",
+            "docs": [
+              "Timestamp the override expires at"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6011,
-      "name": "insufficientBalance",
-      "msg": "Insufficient token balance."
+      "name": "priceOverrideLog",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vaultType",
+            "docs": [
+              "Vault type whose overrides are recorded"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "head",
+            "docs": [
+              "Index the next entry is written to"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "len",
+            "docs": [
+              "Number of entries recorded, up to the capacity"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "entries",
+            "docs": [
+              "Ring buffer of entries, oldest overwritten first"
+            ],
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "priceOverrideEntry"
+                  }
+                },
+                16
+              ]
+            }
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6012,
-      "name": "mintPaused",
-      "msg": "Minting is currently paused."
+      "name": "priceView",
+      "docs": [
+        "Oracle price of a vault type as seen by the UI. Prices are in 6 decimals."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "conf",
+            "type": "u64"
+          },
+          {
+            "name": "publishTime",
+            "type": "i64"
+          },
+          {
+            "name": "exponent",
+            "docs": [
+              "Exponent of `price` and `conf`"
+            ],
+            "type": "i32"
+          },
+          {
+            "name": "stalenessSeconds",
+            "docs": [
+              "Seconds since `publish_time`"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "source",
+            "docs": [
+              "`ORACLE_SOURCE_*` the price came from"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "isValid",
+            "docs": [
+              "Whether the price passes the checks used by position instructions"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "errorCode",
+            "docs": [
+              "`AegisError` code of the failed check, 0 when valid"
+            ],
+            "type": "u32"
+          }
+        ]
+      }
     },
     {
-      "code": 6013,
-      "name": "redeemPaused",
-      "msg": "Redemption is currently paused."
-    }
-  ],
-  "types": [
-    {
-      "name": "createVaultTypeParams",
+      "name": "protocolState",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "oraclePriceAccount",
-            "type": "pubkey"
+            "name": "adminPubkey",
+            "type": "pubkey"
+          },
+          {
+            "name": "governancePubkey",
+            "type": "pubkey"
+          },
+          {
+            "name": "guardianPubkey",
+            "type": "pubkey"
+          },
+          {
+            "name": "oracleUpdateAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "treasuryPubkey",
+            "type": "pubkey"
+          },
+          {
+            "name": "treasuryAta",
+            "type": "pubkey"
+          },
+          {
+            "name": "stablecoinMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "mintAuthorityBump",
+            "type": "u8"
+          },
+          {
+            "name": "baseCollateralRatioBps",
+            "type": "u64"
+          },
+          {
+            "name": "baseLiquidationThresholdBps",
+            "type": "u64"
+          },
+          {
+            "name": "baseLiquidationPenaltyBps",
+            "type": "u64"
+          },
+          {
+            "name": "baseStabilityFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "baseMintFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "baseRedeemFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "oracleTtlSeconds",
+            "type": "i64"
+          },
+          {
+            "name": "globalDebtCeiling",
+            "type": "u64"
+          },
+          {
+            "name": "defaultVaultDebtCeiling",
+            "type": "u64"
+          },
+          {
+            "name": "isProtocolPaused",
+            "type": "bool"
+          },
+          {
+            "name": "isMintPaused",
+            "type": "bool"
+          },
+          {
+            "name": "isRedeemPaused",
+            "type": "bool"
+          },
+          {
+            "name": "isShutdown",
+            "type": "bool"
+          },
+          {
+            "name": "totalProtocolDebt",
+            "type": "u64"
           },
           {
-            "name": "ltvBps",
+            "name": "totalProtocolCollateralValue",
             "type": "u64"
           },
           {
-            "name": "liqThresholdBps",
+            "name": "totalMintFeesCollected",
             "type": "u64"
           },
           {
-            "name": "liqPenaltyBps",
+            "name": "totalRedeemFeesCollected",
             "type": "u64"
           },
           {
-            "name": "stabilityFeeBps",
-            "type": "u16"
+            "name": "totalLiquidationFeesCollected",
+            "type": "u64"
           },
           {
-            "name": "mintFeeBps",
-            "type": "u16"
+            "name": "configVersion",
+            "type": "u64"
           },
           {
-            "name": "redeemFeeBps",
-            "type": "u16"
+            "name": "createdAt",
+            "type": "i64"
           },
           {
-            "name": "vaultDebtCeiling",
+            "name": "updatedAt",
+            "type": "i64"
+          },
+          {
+            "name": "totalStabilityFeesCollected",
+            "type": "u64"
+          },
+          {
+            "name": "surplusBuffer",
+            "type": "u64"
+          },
+          {
+            "name": "surplusBufferCap",
+            "type": "u64"
+          },
+          {
+            "name": "deficit",
+            "type": "u64"
+          },
+          {
+            "name": "totalBadDebtWrittenOff",
             "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "position",
+      "name": "stabilityDeposit",
       "type": {
         "kind": "struct",
         "fields": [
           {
             "name": "owner",
             "docs": [
-              "Owner of this position"
+              "Depositor"
             ],
             "type": "pubkey"
           },
           {
-            "name": "vaultType",
+            "name": "stabilityPool",
             "docs": [
-              "Vault type this position belongs to"
+              "Stability pool the deposit belongs to"
             ],
             "type": "pubkey"
           },
           {
-            "name": "collateralAmount",
+            "name": "initialValue",
             "docs": [
-              "Amount of collateral deposited (in collateral token decimals)"
+              "Deposit at the last snapshot (6 decimals)"
             ],
             "type": "u64"
           },
           {
-            "name": "debtAmount",
+            "name": "snapshotProduct",
             "docs": [
-              "Amount of stablecoin debt (in 6 decimals)"
+              "P at the last snapshot"
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "snapshotSum",
+            "docs": [
+              "S of the snapshot epoch and scale at the last snapshot"
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "snapshotEpoch",
+            "docs": [
+              "Epoch at the last snapshot"
             ],
             "type": "u64"
           },
+          {
+            "name": "snapshotScale",
+            "docs": [
+              "Scale at the last snapshot"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          },
           {
             "name": "createdAt",
             "docs": [
-              "Timestamp when position was created"
+              "Timestamp when the deposit was opened"
             ],
             "type": "i64"
           },
           {
             "name": "updatedAt",
             "docs": [
-              "Timestamp when position was last updated"
+              "Timestamp when the deposit was last updated"
             ],
             "type": "i64"
           }
@@ -1988,219 +6309,377 @@ export type AegisVault = {
       }
     },
     {
-      "name": "protocolState",
+      "name": "stabilityPool",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "adminPubkey",
+            "name": "vaultType",
+            "docs": [
+              "Vault type whose liquidations the pool absorbs"
+            ],
             "type": "pubkey"
           },
           {
-            "name": "governancePubkey",
-            "type": "pubkey"
+            "name": "totalDeposits",
+            "docs": [
+              "AGSUSD deposited and not yet used in liquidations (6 decimals)"
+            ],
+            "type": "u64"
           },
           {
-            "name": "guardianPubkey",
-            "type": "pubkey"
+            "name": "totalCollateral",
+            "docs": [
+              "Seized collateral not yet claimed by depositors (in collateral token decimals)"
+            ],
+            "type": "u64"
           },
           {
-            "name": "oracleUpdateAuthority",
-            "type": "pubkey"
+            "name": "product",
+            "docs": [
+              "Running product P of deposit losses (STABILITY_POOL_PRECISION fixed point)"
+            ],
+            "type": "u128"
           },
           {
-            "name": "treasuryPubkey",
-            "type": "pubkey"
+            "name": "currentEpoch",
+            "docs": [
+              "Current epoch"
+            ],
+            "type": "u64"
           },
           {
-            "name": "treasuryAta",
-            "type": "pubkey"
+            "name": "currentScale",
+            "docs": [
+              "Current scale within the epoch"
+            ],
+            "type": "u64"
           },
           {
-            "name": "stablecoinMint",
-            "type": "pubkey"
+            "name": "sumCount",
+            "docs": [
+              "Number of sums recorded; the last one belongs to the current epoch and scale"
+            ],
+            "type": "u8"
           },
           {
-            "name": "mintAuthorityBump",
+            "name": "sums",
+            "docs": [
+              "Collateral gain sums per epoch and scale still read by a deposit, oldest first"
+            ],
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "epochScaleSum"
+                  }
+                },
+                32
+              ]
+            }
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
             "type": "u8"
           },
           {
-            "name": "baseCollateralRatioBps",
-            "type": "u64"
+            "name": "createdAt",
+            "docs": [
+              "Timestamp when the pool was created"
+            ],
+            "type": "i64"
           },
           {
-            "name": "baseLiquidationThresholdBps",
-            "type": "u64"
+            "name": "updatedAt",
+            "docs": [
+              "Timestamp when the pool was last updated"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "updateFeatureFlagsParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "isProtocolPaused",
+            "type": {
+              "option": "bool"
+            }
           },
           {
-            "name": "baseLiquidationPenaltyBps",
-            "type": "u64"
+            "name": "isMintPaused",
+            "type": {
+              "option": "bool"
+            }
           },
           {
-            "name": "baseStabilityFeeBps",
-            "type": "u16"
+            "name": "isRedeemPaused",
+            "type": {
+              "option": "bool"
+            }
           },
           {
-            "name": "baseMintFeeBps",
-            "type": "u16"
+            "name": "isShutdown",
+            "type": {
+              "option": "bool"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "updateVaultTypeParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "oracleSource",
+            "type": {
+              "option": "u8"
+            }
           },
           {
-            "name": "baseRedeemFeeBps",
-            "type": "u16"
+            "name": "oraclePriceAccount",
+            "type": {
+              "option": "pubkey"
+            }
           },
           {
-            "name": "oracleTtlSeconds",
-            "type": "i64"
+            "name": "oracleFeedId",
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
           },
           {
-            "name": "globalDebtCeiling",
-            "type": "u64"
+            "name": "stakePoolAccount",
+            "type": {
+              "option": "pubkey"
+            }
           },
           {
-            "name": "defaultVaultDebtCeiling",
-            "type": "u64"
+            "name": "oracleMaxAgeSeconds",
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "isProtocolPaused",
-            "type": "bool"
+            "name": "oraclePriceExponent",
+            "type": {
+              "option": "i32"
+            }
           },
           {
-            "name": "isMintPaused",
-            "type": "bool"
+            "name": "oracleVerificationLevel",
+            "type": {
+              "option": "u8"
+            }
           },
           {
-            "name": "isRedeemPaused",
-            "type": "bool"
+            "name": "oracleMinSignatures",
+            "type": {
+              "option": "u8"
+            }
           },
           {
-            "name": "isShutdown",
-            "type": "bool"
+            "name": "confMultiplierBps",
+            "type": {
+              "option": "u16"
+            }
           },
           {
-            "name": "totalProtocolDebt",
-            "type": "u64"
+            "name": "maxConfRatioBps",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "pricingMode",
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "name": "isPegged",
+            "type": {
+              "option": "bool"
+            }
+          },
+          {
+            "name": "minPrice",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "maxPrice",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "secondaryOracleAccount",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "secondaryOracleMaxAgeSeconds",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "oracleAggregation",
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "name": "maxOracleDeviationBps",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "ltvBps",
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "totalProtocolCollateralValue",
-            "type": "u64"
+            "name": "liqThresholdBps",
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "totalMintFeesCollected",
-            "type": "u64"
+            "name": "liqPenaltyBps",
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "totalRedeemFeesCollected",
-            "type": "u64"
+            "name": "stabilityFeeBps",
+            "type": {
+              "option": "u16"
+            }
           },
           {
-            "name": "totalLiquidationFeesCollected",
-            "type": "u64"
+            "name": "mintFeeBps",
+            "type": {
+              "option": "u16"
+            }
           },
           {
-            "name": "configVersion",
-            "type": "u64"
+            "name": "redeemFeeBps",
+            "type": {
+              "option": "u16"
+            }
           },
           {
-            "name": "createdAt",
-            "type": "i64"
+            "name": "vaultDebtCeiling",
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "updatedAt",
-            "type": "i64"
+            "name": "closeFactorBps",
+            "type": {
+              "option": "u16"
+            }
           },
           {
-            "name": "reserved",
+            "name": "targetHealthBps",
             "type": {
-              "array": [
-                "u8",
-                40
-              ]
+              "option": "u16"
             }
-          }
-        ]
-      }
-    },
-    {
-      "name": "updateFeatureFlagsParams",
-      "type": {
-        "kind": "struct",
-        "fields": [
+          },
           {
-            "name": "isProtocolPaused",
+            "name": "fullLiquidationHealthBps",
             "type": {
-              "option": "bool"
+              "option": "u16"
             }
           },
           {
-            "name": "isMintPaused",
+            "name": "liquidationDustThreshold",
             "type": {
-              "option": "bool"
+              "option": "u64"
             }
           },
           {
-            "name": "isRedeemPaused",
+            "name": "liquidationMode",
             "type": {
-              "option": "bool"
+              "option": "u8"
             }
           },
           {
-            "name": "isShutdown",
+            "name": "auctionStartPremiumBps",
             "type": {
-              "option": "bool"
+              "option": "u16"
             }
-          }
-        ]
-      }
-    },
-    {
-      "name": "updateVaultTypeParams",
-      "type": {
-        "kind": "struct",
-        "fields": [
+          },
           {
-            "name": "oraclePriceAccount",
+            "name": "auctionFloorBps",
             "type": {
-              "option": "pubkey"
+              "option": "u16"
             }
           },
           {
-            "name": "ltvBps",
+            "name": "auctionDurationSeconds",
             "type": {
               "option": "u64"
             }
           },
           {
-            "name": "liqThresholdBps",
+            "name": "liquidationProtocolShareBps",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "keeperReward",
             "type": {
               "option": "u64"
             }
           },
           {
-            "name": "liqPenaltyBps",
+            "name": "keeperRewardMaxDebt",
             "type": {
               "option": "u64"
             }
           },
           {
-            "name": "stabilityFeeBps",
+            "name": "liquidationBonusMinBps",
             "type": {
               "option": "u16"
             }
           },
           {
-            "name": "mintFeeBps",
+            "name": "liquidationBonusMaxBps",
             "type": {
               "option": "u16"
             }
           },
           {
-            "name": "redeemFeeBps",
+            "name": "maxPriceMoveBpsPerMinute",
             "type": {
               "option": "u16"
             }
           },
           {
-            "name": "vaultDebtCeiling",
+            "name": "twapWindowSeconds",
             "type": {
               "option": "u64"
             }
@@ -2269,12 +6748,234 @@ export type AegisVault = {
             "name": "updatedAt",
             "type": "i64"
           },
+          {
+            "name": "cumulativeRate",
+            "type": "u128"
+          },
+          {
+            "name": "lastAccrualTs",
+            "type": "i64"
+          },
+          {
+            "name": "totalNormalizedDebt",
+            "type": "u64"
+          },
+          {
+            "name": "unrealizedStabilityFees",
+            "type": "u64"
+          },
+          {
+            "name": "oracleFeedId",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "oracleMaxAgeSeconds",
+            "type": "u64"
+          },
+          {
+            "name": "oraclePriceExponent",
+            "type": "i32"
+          },
+          {
+            "name": "oracleVerificationLevel",
+            "type": "u8"
+          },
+          {
+            "name": "oracleMinSignatures",
+            "type": "u8"
+          },
+          {
+            "name": "confMultiplierBps",
+            "type": "u16"
+          },
+          {
+            "name": "maxConfRatioBps",
+            "type": "u16"
+          },
+          {
+            "name": "collateralDecimals",
+            "type": "u8"
+          },
+          {
+            "name": "secondaryOracleAccount",
+            "type": "pubkey"
+          },
+          {
+            "name": "secondaryOracleMaxAgeSeconds",
+            "type": "u64"
+          },
+          {
+            "name": "oracleAggregation",
+            "type": "u8"
+          },
+          {
+            "name": "maxOracleDeviationBps",
+            "type": "u16"
+          },
+          {
+            "name": "oracleSource",
+            "type": "u8"
+          },
+          {
+            "name": "pricingMode",
+            "type": "u8"
+          },
+          {
+            "name": "lastAcceptedPrice",
+            "type": "u64"
+          },
+          {
+            "name": "lastAcceptedPriceTs",
+            "type": "i64"
+          },
+          {
+            "name": "maxPriceMoveBpsPerMinute",
+            "type": "u16"
+          },
+          {
+            "name": "isMintPaused",
+            "type": "bool"
+          },
+          {
+            "name": "circuitBreakerTrippedAt",
+            "type": "i64"
+          },
+          {
+            "name": "circuitBreakerTripPrice",
+            "type": "u64"
+          },
+          {
+            "name": "stakePoolAccount",
+            "type": "pubkey"
+          },
+          {
+            "name": "isPegged",
+            "type": "bool"
+          },
+          {
+            "name": "minPrice",
+            "type": "u64"
+          },
+          {
+            "name": "maxPrice",
+            "type": "u64"
+          },
+          {
+            "name": "priceHistory",
+            "type": "pubkey"
+          },
+          {
+            "name": "twapWindowSeconds",
+            "type": "u64"
+          },
+          {
+            "name": "overridePrice",
+            "type": "u64"
+          },
+          {
+            "name": "overrideSetAt",
+            "type": "i64"
+          },
+          {
+            "name": "overrideExpiresAt",
+            "type": "i64"
+          },
+          {
+            "name": "closeFactorBps",
+            "type": "u16"
+          },
+          {
+            "name": "targetHealthBps",
+            "type": "u16"
+          },
+          {
+            "name": "fullLiquidationHealthBps",
+            "type": "u16"
+          },
+          {
+            "name": "liquidationDustThreshold",
+            "type": "u64"
+          },
+          {
+            "name": "liquidationMode",
+            "type": "u8"
+          },
+          {
+            "name": "auctionStartPremiumBps",
+            "type": "u16"
+          },
+          {
+            "name": "auctionFloorBps",
+            "type": "u16"
+          },
+          {
+            "name": "auctionDurationSeconds",
+            "type": "u64"
+          },
+          {
+            "name": "liquidationProtocolShareBps",
+            "type": "u16"
+          },
+          {
+            "name": "keeperReward",
+            "type": "u64"
+          },
+          {
+            "name": "keeperRewardMaxDebt",
+            "type": "u64"
+          },
+          {
+            "name": "liquidationBonusMinBps",
+            "docs": [
+              "Health-scaled bonus bounds; a max of 0 keeps the fixed `liq_penalty_bps`"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "liquidationBonusMaxBps",
+            "type": "u16"
+          },
+          {
+            "name": "totalCollateral",
+            "docs": [
+              "Collateral of every position, including redistributed collateral not yet applied"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalStakes",
+            "type": "u64"
+          },
+          {
+            "name": "totalStakesSnapshot",
+            "type": "u64"
+          },
+          {
+            "name": "totalCollateralSnapshot",
+            "type": "u64"
+          },
+          {
+            "name": "lColl",
+            "docs": [
+              "Redistributed collateral and normalized debt per unit of stake"
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "lDebt",
+            "type": "u128"
+          },
           {
             "name": "reserved",
             "type": {
               "array": [
                 "u8",
-                96
+                64
               ]
             }
           }
@@ -111,7 +111,7 @@ const runGuardian = async () => {
                     const vaultTypeAccount = await client.program.account.vaultType.fetch(vaultTypePubkey);
 
                     // Fetch Oracle Price
-                    const price = await client.getOraclePrice(vaultTypeAccount.oraclePriceAccount);
+                    const price = await client.getOraclePrice(vaultTypePubkey);
                     if (!price) {
                         logger.warn(`Could not fetch price for vault ${pos.vaultType}`);
                         continue;